@@ -11,7 +11,7 @@
 /// MAILCHIMP_API_KEY=<API KEY>
 ///
 use dotenv::dotenv;
-use mailchimp::MailchimpApi;
+use mailchimp::{MailchimpApi, MailchimpError};
 use std::env;
 
 fn main() {
@@ -20,14 +20,23 @@ fn main() {
     // Filter the env vars to get the Mailchimp Credential
     let mut env_mailchimp = env::vars().filter(|e| e.0.to_string().contains("MAILCHIMP_API_KEY"));
     let apk = env_mailchimp.next().unwrap().1;
-    // Init API Instance
-    let api = MailchimpApi::new(&apk);
+    // Init API Instance, the API key must have the <key>-<dc> format
+    let api = match MailchimpApi::try_new(&apk) {
+        Ok(api) => api,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
     // Ping
     let ping_rs = api.ping();
     match ping_rs {
         Ok(value) => {
             println!("Ping ... {:?}", value);
         }
-        Err(e) => println!("Error Title: {:?} \ndetail {:?}", e.title, e.detail),
+        Err(MailchimpError::Api(e)) => {
+            println!("Error Title: {:?} \ndetail {:?}", e.title, e.detail)
+        }
+        Err(e) => println!("Error: {}", e),
     }
 }
@@ -0,0 +1,60 @@
+///
+/// Dependencies:
+///
+/// # This library is meant to be used on development or testing environments
+/// # in which setting environment variables is not practical.
+/// dotenv = "^0.13"
+/// tokio = { version = "^0.2", features = ["macros", "rt-threaded"] }
+///
+/// Requirements:
+///
+/// To run this example you need to create a archive named ``.env`` in the root of the directory with the following info
+/// MAILCHIMP_API_KEY=<API KEY>
+///
+/// cargo run --example async_lists --features async
+///
+use dotenv::dotenv;
+use std::env;
+
+use mailchimp::nonblocking::{Lists, MailchimpApi};
+use mailchimp::types::ListMembersFilter;
+use mailchimp::ListFilter;
+
+#[tokio::main]
+async fn main() {
+    // Init dotenv
+    dotenv().ok();
+    // Filter the env vars to get the Mailchimp Credential
+    // mailchimp
+    let mut env_mailchimp = env::vars().filter(|e| e.0.to_string().contains("MAILCHIMP_"));
+    let apk = env_mailchimp.next().unwrap().1;
+    // Init API
+    let api = MailchimpApi::new(&apk);
+
+    // Create Instance of Lists
+    let lists = Lists::new(api);
+
+    // Get the first page of lists in the account.
+    let collection = lists.get_lists(Some(&ListFilter::default())).await;
+
+    match collection {
+        Ok(collection) => {
+            for w in collection.lists {
+                let list_id = w.id.unwrap();
+                println!("\n\nList   {:?}", list_id);
+                println!("\tName    {:?}", w.name);
+                println!("\tStats   {:?}", w.stats);
+
+                let members = lists
+                    .get_members(&list_id, Some(ListMembersFilter::default()))
+                    .await;
+                if let Ok(members) = members {
+                    for m in members.members {
+                        println!("\tMember  {:?} {:?}", m.email_address, m.status);
+                    }
+                }
+            }
+        }
+        Err(e) => println!("Error: {:?}", e),
+    }
+}
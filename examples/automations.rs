@@ -13,7 +13,7 @@
 use dotenv::dotenv;
 use std::env;
 
-use mailchimp::types::RecipientType;
+use mailchimp::types::{AutomationStatus, RecipientType};
 use mailchimp::MailchimpApi;
 use mailchimp::{Automations, AutomationsFilter};
 use std::collections::HashMap;
@@ -65,7 +65,7 @@ fn main() {
     filter.insert("status".to_string(), "sending".to_string());
 
     for w in automations.iter(AutomationsFilter {
-        status: Some("sending".to_string()),
+        status: Some(AutomationStatus::Sending),
         fields: None,
         exclude_fields: None,
         count: Some(50),
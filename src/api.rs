@@ -1,11 +1,12 @@
-use crate::internal::api::Api;
-use crate::internal::error_type::MailchimpErrorType;
-use crate::internal::request::MailchimpRequest;
+use crate::api_builder::MailchimpApiBuilder;
+use crate::internal::api::{parse_api_key, split_api_key, Api};
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::{HttpReq, MailchimpRequest};
 use crate::types::Ping;
 use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Mailchimp API
@@ -28,29 +29,145 @@ use std::rc::Rc;
 ///
 #[derive(Debug, Clone)]
 pub struct MailchimpApi {
-    i_api: Box<Api<MailchimpRequest>>,
+    i_api: Box<Api>,
 }
 
 impl MailchimpApi {
     ///
     /// Crea la nueva instancia del API
     ///
+    /// No valida la API Key, si no incluye el datacenter se usa "usX" y las
+    /// peticiones fallan más tarde. Es preferible usar `try_new`.
+    ///
     /// Argumentos
     ///     api_key: Mailchimp API KEY
-    ///     http_transport: Interfaz por donde se harían las peticiones Get y Post al servicio
+    ///
     pub fn new<'a>(api_key: &'a str) -> Self {
-        let mut creds = api_key.split('-').collect::<Vec<&str>>();
-        if creds.len() <= 1 {
-            creds.push("usX");
+        MailchimpApi::with_transport(api_key, MailchimpRequest::new())
+    }
+
+    ///
+    /// Crea la nueva instancia del API validando el formato `<key>-<dc>` de
+    /// la API Key
+    ///
+    /// ```
+    /// use mailchimp::{MailchimpApi, MailchimpError};
+    ///
+    /// let api = MailchimpApi::try_new("aac1e319006883125e18a89e529b5abb73de4c81-us6").unwrap();
+    /// assert_eq!(api.domain(), "https://us6.api.mailchimp.com/");
+    ///
+    /// match MailchimpApi::try_new("aac1e319006883125e18a89e529b5abb73de4c81") {
+    ///     Err(MailchimpError::InvalidApiKey(msg)) => println!("{}", msg),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    ///
+    /// Argumentos
+    ///     api_key: Mailchimp API KEY
+    ///
+    pub fn try_new(api_key: &str) -> Result<Self, MailchimpError> {
+        let (key, dc) = parse_api_key(api_key)?;
+        Ok(MailchimpApi::from_api(Api::new(
+            dc,
+            key,
+            Arc::new(MailchimpRequest::new()),
+        )))
+    }
+
+    ///
+    /// Crea la nueva instancia del API que envía las peticiones mediante el
+    /// transporte indicado, útil para usar otro cliente HTTP o para simular
+    /// las respuestas del servidor en las pruebas
+    ///
+    /// ```
+    /// use mailchimp::transport::{BasicAuth, HeaderMap, HttpReq, MailchimpResult, Url};
+    /// use mailchimp::MailchimpApi;
+    /// use serde_json::Value;
+    ///
+    /// #[derive(Debug)]
+    /// struct PingTransport;
+    ///
+    /// impl HttpReq for PingTransport {
+    ///     fn get(&self, _: Url, _: HeaderMap, _: &Option<BasicAuth>) -> MailchimpResult<String> {
+    ///         Ok(r#"{"health_status": "Everything's Chimpy!"}"#.to_string())
+    ///     }
+    ///     fn post(&self, _: Url, _: HeaderMap, _: Value, _: &Option<BasicAuth>) -> MailchimpResult<String> {
+    ///         Ok("{}".to_string())
+    ///     }
+    ///     fn patch(&self, _: Url, _: HeaderMap, _: Value, _: &Option<BasicAuth>) -> MailchimpResult<String> {
+    ///         Ok("{}".to_string())
+    ///     }
+    ///     fn put(&self, _: Url, _: HeaderMap, _: Value, _: &Option<BasicAuth>) -> MailchimpResult<String> {
+    ///         Ok("{}".to_string())
+    ///     }
+    ///     fn delete(&self, _: Url, _: HeaderMap, _: &Option<BasicAuth>) -> MailchimpResult<String> {
+    ///         Ok("".to_string())
+    ///     }
+    /// }
+    ///
+    /// let api = MailchimpApi::with_transport("<API Key>-us6", PingTransport);
+    /// assert!(api.ping().is_ok());
+    /// ```
+    ///
+    /// Argumentos
+    ///     api_key: Mailchimp API KEY
+    ///     transport: Implementación de `HttpReq` por donde se harán las peticiones
+    ///
+    pub fn with_transport<R>(api_key: &str, transport: R) -> Self
+    where
+        R: HttpReq + 'static,
+    {
+        let (key, dc) = split_api_key(api_key);
+        MailchimpApi {
+            i_api: Box::new(Api::new(dc, key, Arc::new(transport))),
         }
+    }
+    ///
+    /// Crea la nueva instancia del API autenticada con un token OAuth2, el
+    /// datacenter de la cuenta se obtiene con `OAuthClient::metadata`.
+    /// Devuelve `MailchimpError::InvalidApiKey` si el token no es válido en
+    /// una cabecera
+    ///
+    /// Argumentos
+    ///     access_token: Token OAuth2 de la cuenta
+    ///     dc: Datacenter de la cuenta, p.ej. `us6`
+    ///
+    pub fn with_oauth_token(access_token: &str, dc: &str) -> Result<Self, MailchimpError> {
+        let mut api = Api::new(dc, "", Arc::new(MailchimpRequest::new()));
+        api.set_bearer_token(access_token)?;
+        Ok(MailchimpApi::from_api(api))
+    }
+
+    ///
+    /// Devuelve un constructor para configurar el cliente HTTP (timeouts,
+    /// proxy, user agent, TLS) y el servidor al que se envían las peticiones
+    ///
+    /// ```
+    /// use mailchimp::MailchimpApi;
+    /// use std::time::Duration;
+    ///
+    /// let api = MailchimpApi::builder()
+    ///     .api_key("aac1e319006883125e18a89e529b5abb73de4c81-us6")
+    ///     .timeout(Duration::from_secs(10))
+    ///     .user_agent("my-app/1.0")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(api.domain(), "https://us6.api.mailchimp.com/");
+    /// ```
+    ///
+    pub fn builder() -> MailchimpApiBuilder {
+        MailchimpApiBuilder::new()
+    }
+
+    ///
+    /// Crea la instancia a partir del API interno ya configurado
+    ///
+    pub(crate) fn from_api(api: Api) -> Self {
         MailchimpApi {
-            i_api: Box::new(Api::<MailchimpRequest>::new(
-                creds[1],
-                creds[0],
-                Box::new(MailchimpRequest::new()),
-            )),
+            i_api: Box::new(api),
         }
     }
+
     ///
     /// Devuelve el dominio
     ///
@@ -81,7 +198,7 @@ impl MailchimpApi {
     ///                 println!("{:?}", app)
     ///             }
     ///         },
-    ///         Err(e) => println!("Error Title: {:?} \n Error detail {:?}", e.title, e.detail)
+    ///         Err(e) => println!("Error: {}", e)
     ///     }
     /// }
     /// ```
@@ -89,7 +206,7 @@ impl MailchimpApi {
     ///     `endpoint`: Cadena de texto con el endpoint de la API al que se requiere acceder, no debe comenzar por "/"
     ///     `payload`: Dato a enviar al servidor
     ///
-    pub fn post<'a, T, P>(&self, endpoint: &'a str, payload: P) -> Result<T, MailchimpErrorType>
+    pub fn post<T, P>(&self, endpoint: &str, payload: P) -> Result<T, MailchimpError>
     where
         T: DeserializeOwned,
         P: Serialize,
@@ -105,7 +222,7 @@ impl MailchimpApi {
     ///     `endpoint`: Cadena de texto con el endpoint de la API al que se requiere acceder, no debe comenzar por "/"
     ///     `payload`: Dato a enviar al servidor
     ///
-    pub fn patch<'a, T, P>(&self, endpoint: &'a str, payload: P) -> Result<T, MailchimpErrorType>
+    pub fn patch<T, P>(&self, endpoint: &str, payload: P) -> Result<T, MailchimpError>
     where
         T: DeserializeOwned,
         P: Serialize,
@@ -120,7 +237,7 @@ impl MailchimpApi {
     ///     `endpoint`: Cadena de texto con el endpoint de la API al que se requiere acceder, no debe comenzar por "/"
     ///     `payload`: Dato a enviar al servidor
     ///
-    pub fn put<'a, T, P>(&self, endpoint: &'a str, payload: P) -> Result<T, MailchimpErrorType>
+    pub fn put<T, P>(&self, endpoint: &str, payload: P) -> Result<T, MailchimpError>
     where
         T: DeserializeOwned,
         P: Serialize,
@@ -146,7 +263,7 @@ impl MailchimpApi {
     ///         Ok(resp) => {
     ///            println!("{:?}", resp)
     ///         },
-    ///         Err(e) => println!("Error Title: {:?} \n Error detail {:?}", e.title, e.detail)
+    ///         Err(e) => println!("Error: {}", e)
     ///     }
     /// }
     /// ```
@@ -158,7 +275,7 @@ impl MailchimpApi {
         &self,
         endpoint: &'a str,
         payload: HashMap<String, String>,
-    ) -> Result<T, MailchimpErrorType>
+    ) -> Result<T, MailchimpError>
     where
         T: DeserializeOwned,
     {
@@ -183,7 +300,7 @@ impl MailchimpApi {
     ///         Ok(resp) => {
     ///            println!("{:?}", resp)
     ///         },
-    ///         Err(e) => println!("Error Title: {:?} \n Error detail {:?}", e.title, e.detail)
+    ///         Err(e) => println!("Error: {}", e)
     ///     }
     /// }
     /// ```
@@ -195,17 +312,28 @@ impl MailchimpApi {
         &self,
         endpoint: &'a str,
         payload: HashMap<String, String>,
-    ) -> Result<T, MailchimpErrorType>
+    ) -> Result<T, MailchimpError>
     where
         T: DeserializeOwned,
     {
         self.i_api.delete_edge(endpoint, payload)
     }
 
+    ///
+    /// Descarga un archivo fuera del API, p.ej. los resultados de una
+    /// operación batch. La petición se envía sin credenciales.
+    ///
+    /// #Argumentos
+    ///     `url`: Url absoluta del archivo
+    ///
+    pub fn download(&self, url: &str) -> Result<Vec<u8>, MailchimpError> {
+        self.i_api.download(url)
+    }
+
     ///
     /// A health check for the API that won’t return any account-specific information.
     ///
-    pub fn ping(&self) -> Result<Ping, MailchimpErrorType> {
+    pub fn ping(&self) -> Result<Ping, MailchimpError> {
         self.get::<Ping>("ping", HashMap::new())
     }
 }
@@ -213,11 +341,7 @@ impl MailchimpApi {
 impl Default for MailchimpApi {
     fn default() -> Self {
         MailchimpApi {
-            i_api: Box::new(Api::<MailchimpRequest>::new(
-                "",
-                "",
-                Box::new(MailchimpRequest::new()),
-            )),
+            i_api: Box::new(Api::new("", "", Arc::new(MailchimpRequest::new()))),
         }
     }
 }
@@ -226,5 +350,5 @@ pub trait MailchimpApiUpdate {
     /**
      * Update API
      */
-    fn set_api(&mut self, api: Rc<MailchimpApi>);
+    fn set_api(&mut self, api: Arc<MailchimpApi>);
 }
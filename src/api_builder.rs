@@ -0,0 +1,303 @@
+use crate::api::MailchimpApi;
+use crate::internal::api::{parse_api_key, split_api_key, Api};
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::{HttpReq, MailchimpRequest, MailchimpResult};
+use crate::internal::retry::RetryPolicy;
+use reqwest::{Certificate, Proxy, Url};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// User agent enviado por defecto
+const DEFAULT_USER_AGENT: &str = concat!("mailchimp-rs/", env!("CARGO_PKG_VERSION"));
+
+///
+/// Constructor de `MailchimpApi`
+///
+/// Cada instancia construida tiene su propio cliente HTTP, con sus timeouts,
+/// proxy, user agent y opciones TLS, y puede apuntar a otro servidor, p.ej. a
+/// un servidor local que simule el API en las pruebas.
+///
+/// ```
+/// use mailchimp::MailchimpApi;
+/// use std::time::Duration;
+///
+/// let api = MailchimpApi::builder()
+///     .api_key("<API Key>-us6")
+///     .base_url("http://localhost:8080/")
+///     .connect_timeout(Duration::from_secs(2))
+///     .timeout(Duration::from_secs(10))
+///     .build()
+///     .unwrap();
+/// assert_eq!(api.domain(), "http://localhost:8080/");
+/// ```
+///
+#[derive(Debug, Clone, Default)]
+pub struct MailchimpApiBuilder {
+    api_key: String,
+    oauth_token: Option<String>,
+    datacenter: Option<String>,
+    base_url: Option<String>,
+    api_version: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<String>,
+    user_agent: Option<String>,
+    accept_invalid_certs: bool,
+    root_certificates: Vec<Vec<u8>>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl MailchimpApiBuilder {
+    ///
+    /// Constructor sin configurar
+    ///
+    pub fn new() -> Self {
+        MailchimpApiBuilder::default()
+    }
+
+    ///
+    /// Mailchimp API KEY, con el formato `<key>-<dc>`. Al construir se
+    /// devuelve `MailchimpError::InvalidApiKey` si no tiene ese formato.
+    ///
+    pub fn api_key(mut self, api_key: &str) -> Self {
+        self.api_key = api_key.to_string();
+        self
+    }
+
+    ///
+    /// Autentica las peticiones con un token OAuth2 en lugar de la API KEY,
+    /// requiere indicar el datacenter o la url del servidor. Al construir se
+    /// devuelve `MailchimpError::InvalidApiKey` si no es válido en una cabecera
+    ///
+    pub fn oauth_token(mut self, access_token: &str) -> Self {
+        self.oauth_token = Some(access_token.to_string());
+        self
+    }
+
+    ///
+    /// Datacenter de la cuenta, p.ej. `us6`, tiene prioridad sobre el
+    /// datacenter de la API KEY
+    ///
+    pub fn datacenter(mut self, dc: &str) -> Self {
+        self.datacenter = Some(dc.to_string());
+        self
+    }
+
+    ///
+    /// Servidor al que se envían las peticiones, por defecto
+    /// `https://<dc>.api.mailchimp.com/` según el datacenter de la API KEY.
+    /// Debe ser una url `http` o `https` absoluta
+    ///
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.to_string());
+        self
+    }
+
+    ///
+    /// Versión del API, por defecto `3.0`
+    ///
+    pub fn api_version(mut self, api_version: &str) -> Self {
+        self.api_version = Some(api_version.to_string());
+        self
+    }
+
+    ///
+    /// Tiempo máximo de cada petición, incluida la lectura de la respuesta
+    ///
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    ///
+    /// Tiempo máximo para establecer la conexión con el servidor
+    ///
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    ///
+    /// Envía todas las peticiones a través del proxy indicado, p.ej.
+    /// `http://proxy.example.com:3128`
+    ///
+    pub fn proxy(mut self, proxy_url: &str) -> Self {
+        self.proxy = Some(proxy_url.to_string());
+        self
+    }
+
+    ///
+    /// Cabecera `User-Agent` de las peticiones, por defecto `mailchimp-rs/<version>`
+    ///
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    ///
+    /// Acepta certificados no válidos. Solo para servidores de prueba, con
+    /// esta opción cualquiera puede suplantar al servidor.
+    ///
+    pub fn danger_accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    ///
+    /// Adiciona un certificado raíz de confianza en formato PEM
+    ///
+    pub fn add_root_certificate(mut self, pem: &[u8]) -> Self {
+        self.root_certificates.push(pem.to_vec());
+        self
+    }
+
+    ///
+    /// Política de reintentos de las peticiones
+    ///
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    ///
+    /// Construye la instancia del API
+    ///
+    pub fn build(&self) -> MailchimpResult<MailchimpApi> {
+        let mut builder = reqwest::blocking::Client::builder()
+            .user_agent(self.agent())
+            .danger_accept_invalid_certs(self.accept_invalid_certs);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(proxy) = self.build_proxy()? {
+            builder = builder.proxy(proxy);
+        }
+        for cert in self.build_certificates()? {
+            builder = builder.add_root_certificate(cert);
+        }
+        let client = builder.build().map_err(invalid_config)?;
+
+        let mut transport = MailchimpRequest::with_client(client);
+        if let Some(policy) = &self.retry_policy {
+            transport.set_retry_policy(policy.clone());
+        }
+        let (key, base_url) = self.endpoint()?;
+        let mut api = Api::with_base_url(&base_url, &self.version(), key, Arc::new(transport));
+        if let Some(token) = &self.oauth_token {
+            api.set_bearer_token(token)?;
+        }
+        Ok(MailchimpApi::from_api(api))
+    }
+
+    ///
+    /// Construye la instancia del API asíncrono
+    ///
+    #[cfg(feature = "async")]
+    pub fn build_async(&self) -> MailchimpResult<crate::nonblocking::MailchimpApi> {
+        use crate::internal::async_api::AsyncApi;
+        use crate::internal::async_request::{AsyncHttpReq, MailchimpAsyncRequest};
+
+        let mut builder = reqwest::Client::builder()
+            .user_agent(self.agent())
+            .danger_accept_invalid_certs(self.accept_invalid_certs);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(proxy) = self.build_proxy()? {
+            builder = builder.proxy(proxy);
+        }
+        for cert in self.build_certificates()? {
+            builder = builder.add_root_certificate(cert);
+        }
+        let client = builder.build().map_err(invalid_config)?;
+
+        let mut transport = MailchimpAsyncRequest::with_client(client);
+        if let Some(policy) = &self.retry_policy {
+            transport.set_retry_policy(policy.clone());
+        }
+        let (key, base_url) = self.endpoint()?;
+        let mut api = AsyncApi::with_base_url(&base_url, &self.version(), key, Arc::new(transport));
+        if let Some(token) = &self.oauth_token {
+            api.set_bearer_token(token)?;
+        }
+        Ok(crate::nonblocking::MailchimpApi::from_api(api))
+    }
+
+    fn agent(&self) -> String {
+        self.user_agent
+            .clone()
+            .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string())
+    }
+
+    fn version(&self) -> String {
+        self.api_version.clone().unwrap_or_else(|| "3.0".to_string())
+    }
+
+    ///
+    /// Devuelve la llave y el servidor al que se envían las peticiones
+    ///
+    fn endpoint(&self) -> MailchimpResult<(&str, String)> {
+        let (key, key_dc) = match (&self.oauth_token, &self.datacenter, &self.base_url) {
+            (Some(_), _, _) => ("", None),
+            (None, None, None) => {
+                let (key, dc) = parse_api_key(&self.api_key)?;
+                (key, Some(dc))
+            }
+            // El servidor se indica aparte, el datacenter de la llave es opcional
+            (None, _, _) => {
+                let (key, dc) = split_api_key(self.api_key.trim());
+                if key.is_empty() {
+                    return Err(MailchimpError::InvalidApiKey(
+                        "the API key is empty".to_string(),
+                    ));
+                }
+                (key, Some(dc))
+            }
+        };
+        let dc = self.datacenter.as_deref().or(key_dc);
+        let base_url = match (&self.base_url, dc) {
+            (Some(url), _) => url.clone(),
+            (None, Some(dc)) => format!("https://{}.api.mailchimp.com/", dc),
+            (None, None) => {
+                return Err(MailchimpError::InvalidConfig(
+                    "the datacenter is required to use an OAuth2 token".to_string(),
+                ))
+            }
+        };
+        let url = Url::parse(&base_url).map_err(|e| {
+            MailchimpError::InvalidConfig(format!("base url {:?}: {}", base_url, e))
+        })?;
+        // Las rutas del API se resuelven sobre esta url
+        if url.cannot_be_a_base() || !matches!(url.scheme(), "http" | "https") {
+            return Err(MailchimpError::InvalidConfig(format!(
+                "base url {:?}: must be an http or https url",
+                base_url
+            )));
+        }
+        Ok((key, base_url))
+    }
+
+    fn build_proxy(&self) -> MailchimpResult<Option<Proxy>> {
+        match &self.proxy {
+            Some(url) => Proxy::all(url.as_str()).map(Some).map_err(invalid_config),
+            None => Ok(None),
+        }
+    }
+
+    fn build_certificates(&self) -> MailchimpResult<Vec<Certificate>> {
+        self.root_certificates
+            .iter()
+            .map(|pem| Certificate::from_pem(pem).map_err(invalid_config))
+            .collect()
+    }
+}
+
+fn invalid_config(e: reqwest::Error) -> MailchimpError {
+    MailchimpError::InvalidConfig(e.to_string())
+}
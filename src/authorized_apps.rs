@@ -28,11 +28,12 @@ use super::internal::request::MailchimpResult;
 use super::iter::{BuildIter, MalchimpIter, ResourceFilter};
 use crate::types::{AuthorizedAppType, AuthorizedAppsType, CreatedAuthorizedAppType};
 use log::error;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /// Authorized Request Filter
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AuthorizedFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -94,8 +95,12 @@ impl ResourceFilter for AuthorizedFilter {
 ///
 #[derive(Debug, Clone)]
 pub struct AuthorizedApps {
-    api: Rc<MailchimpApi>,
+    api: Arc<MailchimpApi>,
 }
+
+///
+/// AuthorizedAppsBuilder
+///
 #[derive(Debug)]
 pub struct AuthorizedAppsBuilder {}
 
@@ -107,7 +112,7 @@ impl BuildIter for AuthorizedAppsBuilder {
     ///
     /// Create new resource, with the api instance updated
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data
@@ -128,7 +133,7 @@ impl AuthorizedApps {
     ///     api: MailchimpApi
     ///
     pub fn new(api: MailchimpApi) -> Self {
-        AuthorizedApps { api: Rc::new(api) }
+        AuthorizedApps { api: Arc::new(api) }
     }
 
     ///
@@ -222,26 +227,11 @@ impl AuthorizedApps {
     /// Returns a iterator to access all applications
     ///
     pub fn iter(&self, filters: AuthorizedFilter) -> MalchimpIter<AuthorizedAppsBuilder> {
-        if let Some(remote) = self.get_authorized_apps_from_remote(Some(&filters)) {
-            return MalchimpIter {
-                builder: AuthorizedAppsBuilder {},
-                data: remote.apps,
-                cur_filters: filters.clone(),
-                cur_it: 0,
-                total_items: remote.total_items,
-                api: self.api.clone(),
-                endpoint: "authorized-apps".to_string(),
-            };
-        }
-
-        MalchimpIter {
-            builder: AuthorizedAppsBuilder {},
-            data: Vec::new(),
-            cur_filters: filters.clone(),
-            cur_it: 0,
-            total_items: 0,
-            api: self.api.clone(),
-            endpoint: "authorized-apps".to_string(),
-        }
+        MalchimpIter::from_endpoint(
+            AuthorizedAppsBuilder {},
+            self.api.clone(),
+            "authorized-apps",
+            filters,
+        )
     }
 }
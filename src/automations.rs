@@ -30,15 +30,17 @@ use super::api::{MailchimpApi, MailchimpApiUpdate};
 use super::internal::request::MailchimpResult;
 use super::iter::{BuildIter, MalchimpIter, ResourceFilter};
 use super::types::{
-    AutomationCampaignSettingsType, AutomationModifier, AutomationTriggerType,
-    AutomationWorkflowType, CollectionAutomation, RecipientType,
+    format_datetime, AutomationCampaignSettingsType, AutomationModifier, AutomationStatus,
+    AutomationTriggerType, AutomationWorkflowType, CollectionAutomation, MailchimpDateTime,
+    RecipientType,
 };
 use log::error;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /// Automation Request Filter
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AutomationsFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -52,19 +54,19 @@ pub struct AutomationsFilter {
     /// large collections with this parameter can be slow. Default value is 0..
     pub offset: Option<u64>,
     /// The status of the campaign.
-    pub status: Option<String>,
+    pub status: Option<AutomationStatus>,
     /// Restrict the response to automations sent before the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub before_send_time: Option<String>,
+    pub before_send_time: Option<MailchimpDateTime>,
     /// Restrict the response to automations sent after the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub since_send_time: Option<String>,
+    pub since_send_time: Option<MailchimpDateTime>,
     /// Restrict the response to automations created before the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub before_create_time: Option<String>,
+    pub before_create_time: Option<MailchimpDateTime>,
     /// Restrict the response to automations created after the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub since_create_time: Option<String>,
+    pub since_create_time: Option<MailchimpDateTime>,
 }
 
 impl Default for AutomationsFilter {
@@ -108,32 +110,20 @@ impl ResourceFilter for AutomationsFilter {
                 format!("{:}", self.offset.as_ref().unwrap().clone()),
             );
         }
-        if self.status.is_some() {
-            payload.insert("status".to_string(), self.status.as_ref().unwrap().clone());
+        if let Some(v) = &self.status {
+            payload.insert("status".to_string(), v.to_string());
         }
-        if self.before_send_time.is_some() {
-            payload.insert(
-                "before_send_time".to_string(),
-                self.before_send_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.before_send_time {
+            payload.insert("before_send_time".to_string(), format_datetime(v));
         }
-        if self.since_send_time.is_some() {
-            payload.insert(
-                "since_send_time".to_string(),
-                self.since_send_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.since_send_time {
+            payload.insert("since_send_time".to_string(), format_datetime(v));
         }
-        if self.before_create_time.is_some() {
-            payload.insert(
-                "before_create_time".to_string(),
-                self.before_create_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.before_create_time {
+            payload.insert("before_create_time".to_string(), format_datetime(v));
         }
-        if self.since_create_time.is_some() {
-            payload.insert(
-                "since_create_time".to_string(),
-                self.since_create_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.since_create_time {
+            payload.insert("since_create_time".to_string(), format_datetime(v));
         }
         payload
     }
@@ -148,9 +138,12 @@ impl ResourceFilter for AutomationsFilter {
 ///
 #[derive(Debug, Clone)]
 pub struct Automations {
-    api: Rc<MailchimpApi>,
+    api: Arc<MailchimpApi>,
 }
 
+///
+/// AutomationsBuilder
+///
 #[derive(Debug)]
 pub struct AutomationsBuilder {}
 
@@ -162,7 +155,7 @@ impl BuildIter for AutomationsBuilder {
     ///
     /// Create new resource, with the api instance updated
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data
@@ -183,7 +176,7 @@ impl Automations {
     ///     api: MailchimpApi
     ///
     pub fn new(api: MailchimpApi) -> Self {
-        Automations { api: Rc::new(api) }
+        Automations { api: Arc::new(api) }
     }
 
     ///
@@ -295,25 +288,11 @@ impl Automations {
     /// anterior esta función te devuelve un iterador
     ///
     pub fn iter(&self, filters: AutomationsFilter) -> MalchimpIter<AutomationsBuilder> {
-        if let Some(remote) = self.get_automations_from_remote(Some(&filters)) {
-            return MalchimpIter {
-                builder: AutomationsBuilder {},
-                data: remote.automations,
-                cur_filters: filters.clone(),
-                cur_it: 0,
-                total_items: remote.total_items,
-                api: self.api.clone(),
-                endpoint: "automations".to_string(),
-            };
-        }
-        MalchimpIter {
-            builder: AutomationsBuilder {},
-            data: Vec::new(),
-            cur_filters: filters.clone(),
-            cur_it: 0,
-            total_items: 0,
-            api: self.api.clone(),
-            endpoint: "automations".to_string(),
-        }
+        MalchimpIter::from_endpoint(
+            AutomationsBuilder {},
+            self.api.clone(),
+            "automations",
+            filters,
+        )
     }
 }
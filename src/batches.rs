@@ -0,0 +1,175 @@
+use super::api::MailchimpApi;
+use super::internal::error_type::MailchimpError;
+use super::internal::request::MailchimpResult;
+use super::types::{BatchOperation, BatchOperationResult, BatchParam, BatchType, BatchesBuilder};
+use crate::iter::{MalchimpIter, SimpleFilter};
+use flate2::read::GzDecoder;
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+///
+/// Batch Operations
+///
+/// Use batch operations to complete multiple operations with a single call,
+/// e.g. to add or update thousands of list members. The operations run in
+/// the background, poll the batch until it is finished and then download
+/// the results of each operation.
+///
+/// ```no_run
+/// use mailchimp::types::BatchOperation;
+/// use mailchimp::{Batches, MailchimpApi};
+/// use std::time::Duration;
+///
+/// let batches = Batches::new(MailchimpApi::new("<API_KEY>"));
+/// let operations = vec![
+///     BatchOperation::new("GET", "lists/57afe96172").with_operation_id("list"),
+///     BatchOperation::archive_member("57afe96172", "62eeb292278cc15f5817cb78f7790b08"),
+/// ];
+/// let batch = batches.submit(operations).unwrap();
+/// let batch = batches
+///     .wait_for(&batch.id, Duration::from_secs(5), Duration::from_secs(600))
+///     .unwrap();
+/// for result in batches.get_results(&batch).unwrap() {
+///     println!("{:?} {}", result.operation_id, result.status_code);
+/// }
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct Batches {
+    api: Arc<MailchimpApi>,
+}
+
+impl Batches {
+    ///
+    /// Argumentos:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        Batches { api: Arc::new(api) }
+    }
+
+    ///
+    /// Begin processing a batch operations request
+    ///
+    pub fn submit(&self, operations: Vec<BatchOperation>) -> MailchimpResult<BatchType> {
+        // POST /batches
+        let param = BatchParam { operations };
+        let mut batch = self.api.post::<BatchType, BatchParam>("batches", param)?;
+        batch.set_api(self.api.clone());
+        Ok(batch)
+    }
+
+    ///
+    /// Get a summary of batch requests that have been made
+    ///
+    pub fn get_batches(&self, filter: Option<SimpleFilter>) -> MalchimpIter<BatchesBuilder> {
+        // GET /batches
+        MalchimpIter::from_endpoint(
+            BatchesBuilder {},
+            self.api.clone(),
+            "batches",
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get the status of a batch request
+    ///
+    pub fn get_batch(&self, batch_id: &str) -> MailchimpResult<BatchType> {
+        // GET /batches/{batch_id}
+        let endpoint = format!("batches/{}", batch_id);
+        let mut batch = self.api.get::<BatchType>(&endpoint, HashMap::new())?;
+        batch.set_api(self.api.clone());
+        Ok(batch)
+    }
+
+    ///
+    /// Poll the status of the batch until it is finished
+    ///
+    /// Argumentos:
+    ///     batch_id: Id of the batch
+    ///     interval: Time between each request
+    ///     timeout: Maximum time to wait, then `MailchimpError::BatchNotFinished`
+    ///         is returned with the last status of the batch
+    ///
+    pub fn wait_for(
+        &self,
+        batch_id: &str,
+        interval: Duration,
+        timeout: Duration,
+    ) -> MailchimpResult<BatchType> {
+        let start = Instant::now();
+        loop {
+            let batch = self.get_batch(batch_id)?;
+            if batch.is_finished() {
+                return Ok(batch);
+            }
+            if start.elapsed() + interval > timeout {
+                return Err(MailchimpError::BatchNotFinished {
+                    batch_id: batch.id,
+                    status: batch.status,
+                });
+            }
+            thread::sleep(interval);
+        }
+    }
+
+    ///
+    /// Download the results of a finished batch, one for each operation
+    ///
+    pub fn get_results(&self, batch: &BatchType) -> MailchimpResult<Vec<BatchOperationResult>> {
+        if !batch.is_finished() || batch.response_body_url.is_empty() {
+            return Err(MailchimpError::BatchNotFinished {
+                batch_id: batch.id.clone(),
+                status: batch.status.clone(),
+            });
+        }
+        let archive = self.api.download(&batch.response_body_url)?;
+        parse_batch_results(archive.as_slice())
+    }
+
+    ///
+    /// Download the results of a finished batch, indexed by `operation_id`.
+    /// The operations sent without id are not included.
+    ///
+    pub fn get_results_by_id(
+        &self,
+        batch: &BatchType,
+    ) -> MailchimpResult<HashMap<String, BatchOperationResult>> {
+        Ok(self
+            .get_results(batch)?
+            .into_iter()
+            .filter_map(|r| r.operation_id.clone().map(|id| (id, r)))
+            .collect())
+    }
+}
+
+///
+/// Parse the gzipped tar archive with the results of a batch request. Each
+/// JSON file of the archive holds an array with the results of the operations.
+///
+pub fn parse_batch_results<R: Read>(reader: R) -> MailchimpResult<Vec<BatchOperationResult>> {
+    let mut archive = tar::Archive::new(GzDecoder::new(reader));
+    let mut results = Vec::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.display().to_string();
+        if !entry.header().entry_type().is_file() || !path.ends_with(".json") {
+            continue;
+        }
+        let mut body = String::new();
+        entry.read_to_string(&mut body)?;
+        let items = serde_json::from_str::<Vec<BatchOperationResult>>(&body).map_err(|e| {
+            MailchimpError::Deserialize {
+                endpoint: path.clone(),
+                body: body.clone(),
+                source: e,
+            }
+        })?;
+        results.extend(items);
+    }
+    Ok(results)
+}
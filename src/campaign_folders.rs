@@ -0,0 +1,91 @@
+use super::api::MailchimpApi;
+use super::internal::request::MailchimpResult;
+use super::types::{CampaignFolderParam, CampaignFolderType, CampaignFoldersBuilder};
+use crate::iter::{MalchimpIter, SimpleFilter};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Campaign Folders
+///
+/// Organize your campaigns using folders.
+///
+/// ```no_run
+/// use mailchimp::types::CampaignFolderParam;
+/// use mailchimp::{CampaignFilter, CampaignFolders, Campaigns, MailchimpApi};
+///
+/// let api = MailchimpApi::new("<API_KEY>");
+/// let folders = CampaignFolders::new(api.clone());
+/// let campaigns = Campaigns::new(api);
+/// let param = CampaignFolderParam {
+///     name: "Q3 Newsletters".to_string(),
+/// };
+/// let folder = folders.create_folder(param).unwrap();
+/// for campaign in campaigns.iter(CampaignFilter::default()) {
+///     campaign.move_to_folder(&folder.id).unwrap();
+/// }
+/// let filter = CampaignFilter {
+///     folder_id: Some(folder.id.clone()),
+///     ..CampaignFilter::default()
+/// };
+/// println!("{} campaigns", campaigns.iter(filter).count());
+/// for f in folders.get_folders(None) {
+///     println!("{} {} campaigns", f.name, f.count);
+/// }
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct CampaignFolders {
+    api: Arc<MailchimpApi>,
+}
+
+impl CampaignFolders {
+    ///
+    /// Argumentos:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        CampaignFolders { api: Arc::new(api) }
+    }
+
+    ///
+    /// Get all folders used to organize campaigns
+    ///
+    pub fn get_folders(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<CampaignFoldersBuilder> {
+        // GET /campaign-folders
+        MalchimpIter::from_endpoint(
+            CampaignFoldersBuilder {},
+            self.api.clone(),
+            "campaign-folders",
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific folder used to organize campaigns
+    ///
+    pub fn get_folder(&self, folder_id: &str) -> MailchimpResult<CampaignFolderType> {
+        // GET /campaign-folders/{folder_id}
+        let endpoint = format!("campaign-folders/{}", folder_id);
+        let mut folder = self
+            .api
+            .get::<CampaignFolderType>(&endpoint, HashMap::new())?;
+        folder.set_api(self.api.clone());
+        Ok(folder)
+    }
+
+    ///
+    /// Create a new campaign folder
+    ///
+    pub fn create_folder(&self, param: CampaignFolderParam) -> MailchimpResult<CampaignFolderType> {
+        // POST /campaign-folders
+        let mut folder = self
+            .api
+            .post::<CampaignFolderType, CampaignFolderParam>("campaign-folders", param)?;
+        folder.set_api(self.api.clone());
+        Ok(folder)
+    }
+}
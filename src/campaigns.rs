@@ -28,15 +28,19 @@
 use super::api::{MailchimpApi, MailchimpApiUpdate};
 use super::internal::request::MailchimpResult;
 use super::iter::{BuildIter, MalchimpIter, ResourceFilter};
-use super::types::{CampaignType, CampaignsType};
+use super::types::{
+    format_datetime, CampaignKind, CampaignStatus, CampaignType, CampaignsType, MailchimpDateTime,
+    SortDir,
+};
 use log::error;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Campaign Request Filter
 ///
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CampaignFilter {
     /// A comma-separated list of fields to return.
     /// Reference parameters of sub-objects with dot notation.
@@ -51,21 +55,21 @@ pub struct CampaignFilter {
     pub offset: Option<u64>,
     /// The campaign type.
     /// regular plaintext absplit rss variate
-    pub campaign_type: Option<String>,
+    pub campaign_type: Option<CampaignKind>,
     /// The status of the campaign.
-    pub status: Option<String>,
+    pub status: Option<CampaignStatus>,
     /// Restrict the response to campaigns sent before the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub before_send_time: Option<String>,
+    pub before_send_time: Option<MailchimpDateTime>,
     /// Restrict the response to campaigns sent after the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub since_send_time: Option<String>,
+    pub since_send_time: Option<MailchimpDateTime>,
     /// Restrict the response to campaigns created before the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub before_create_time: Option<String>,
+    pub before_create_time: Option<MailchimpDateTime>,
     /// Restrict the response to campaigns created after the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub since_create_time: Option<String>,
+    pub since_create_time: Option<MailchimpDateTime>,
     /// The unique id for the list.
     pub list_id: Option<String>,
     /// The unique folder id.
@@ -75,7 +79,7 @@ pub struct CampaignFilter {
     pub sort_field: Option<String>,
     /// Determines the order direction for sorted results.
     /// ASC DESC
-    pub sort_dir: Option<String>,
+    pub sort_dir: Option<SortDir>,
 }
 
 impl Default for CampaignFilter {
@@ -124,38 +128,23 @@ impl ResourceFilter for CampaignFilter {
                 format!("{:}", self.offset.as_ref().unwrap().clone()),
             );
         }
-        if self.campaign_type.is_some() {
-            payload.insert(
-                "campaign_type".to_string(),
-                self.campaign_type.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.campaign_type {
+            payload.insert("campaign_type".to_string(), v.to_string());
         }
-        if self.status.is_some() {
-            payload.insert("status".to_string(), self.status.as_ref().unwrap().clone());
+        if let Some(v) = &self.status {
+            payload.insert("status".to_string(), v.to_string());
         }
-        if self.before_send_time.is_some() {
-            payload.insert(
-                "before_send_time".to_string(),
-                self.before_send_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.before_send_time {
+            payload.insert("before_send_time".to_string(), format_datetime(v));
         }
-        if self.since_send_time.is_some() {
-            payload.insert(
-                "since_send_time".to_string(),
-                self.since_send_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.since_send_time {
+            payload.insert("since_send_time".to_string(), format_datetime(v));
         }
-        if self.before_create_time.is_some() {
-            payload.insert(
-                "before_create_time".to_string(),
-                self.before_create_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.before_create_time {
+            payload.insert("before_create_time".to_string(), format_datetime(v));
         }
-        if self.since_create_time.is_some() {
-            payload.insert(
-                "since_create_time".to_string(),
-                self.since_create_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.since_create_time {
+            payload.insert("since_create_time".to_string(), format_datetime(v));
         }
         if self.list_id.is_some() {
             payload.insert(
@@ -175,11 +164,8 @@ impl ResourceFilter for CampaignFilter {
                 self.sort_field.as_ref().unwrap().clone(),
             );
         }
-        if self.sort_dir.is_some() {
-            payload.insert(
-                "sort_dir".to_string(),
-                self.sort_dir.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.sort_dir {
+            payload.insert("sort_dir".to_string(), v.to_string());
         }
         payload
     }
@@ -193,9 +179,12 @@ impl ResourceFilter for CampaignFilter {
 ///
 #[derive(Debug, Clone)]
 pub struct Campaigns {
-    api: Rc<MailchimpApi>,
+    api: Arc<MailchimpApi>,
 }
 
+///
+/// CampaignsBuilder
+///
 #[derive(Debug)]
 pub struct CampaignsBuilder {}
 
@@ -207,7 +196,7 @@ impl BuildIter for CampaignsBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data
@@ -228,7 +217,7 @@ impl Campaigns {
     ///     api: MailchimpApi
     ///
     pub fn new(api: MailchimpApi) -> Self {
-        Campaigns { api: Rc::new(api) }
+        Campaigns { api: Arc::new(api) }
     }
 
     ///
@@ -303,25 +292,6 @@ impl Campaigns {
     /// anterior esta función te devuelve un iterador
     ///
     pub fn iter(&self, filters: CampaignFilter) -> MalchimpIter<CampaignsBuilder> {
-        if let Some(remote) = self.get_campaigns(Some(&filters)) {
-            return MalchimpIter {
-                builder: CampaignsBuilder {},
-                data: remote.campaigns,
-                cur_filters: filters.clone(),
-                cur_it: 0,
-                total_items: remote.total_items,
-                api: self.api.clone(),
-                endpoint: "campaigns".to_string(),
-            };
-        }
-        MalchimpIter {
-            builder: CampaignsBuilder {},
-            data: Vec::new(),
-            cur_filters: filters.clone(),
-            cur_it: 0,
-            total_items: 0,
-            api: self.api.clone(),
-            endpoint: "campaigns".to_string(),
-        }
+        MalchimpIter::from_endpoint(CampaignsBuilder {}, self.api.clone(), "campaigns", filters)
     }
 }
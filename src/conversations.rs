@@ -1,12 +1,9 @@
 use super::api::MailchimpApi;
 use super::internal::request::MailchimpResult;
-use super::types::{
-    CollectionConversations, Conversation, ConversationBuilder, ConversationsFilter,
-};
-use crate::iter::{MalchimpIter, ResourceFilter};
-use log::error;
+use super::types::{Conversation, ConversationBuilder, ConversationsFilter};
+use crate::iter::MalchimpIter;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Conversations
@@ -16,7 +13,7 @@ use std::rc::Rc;
 ///
 #[derive(Debug, Clone)]
 pub struct Conversations {
-    api: Rc<MailchimpApi>,
+    api: Arc<MailchimpApi>,
 }
 
 impl Conversations {
@@ -25,7 +22,7 @@ impl Conversations {
     ///     api: MailchimpApi
     ///
     pub fn new(api: MailchimpApi) -> Self {
-        Conversations { api: Rc::new(api) }
+        Conversations { api: Arc::new(api) }
     }
 
     ///
@@ -43,32 +40,12 @@ impl Conversations {
             filter_params = f;
         }
 
-        match self
-            .api
-            .get::<CollectionConversations>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ConversationBuilder {},
-                data: collection.conversations,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self.api.clone(),
-                endpoint: endpoint.to_string(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Activities: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ConversationBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self.api.clone(),
-                    endpoint: endpoint.to_string(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            ConversationBuilder {},
+            self.api.clone(),
+            endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -0,0 +1,86 @@
+use super::api::MailchimpApi;
+use super::internal::api::path_segment;
+use super::internal::request::MailchimpResult;
+use super::types::{EcommerceStore, EcommerceStoreParam, EcommerceStoresBuilder};
+use crate::iter::{MalchimpIter, SimpleFilter};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// E-commerce Stores
+///
+/// Connect your E-commerce Store to Mailchimp to take advantage of powerful
+/// reporting and personalization features and to learn more about your
+/// customers. Each store gives access to its customers, products (with their
+/// variants and images), orders and carts.
+///
+/// ```no_run
+/// use mailchimp::types::EcommerceProductParam;
+/// use mailchimp::{Ecommerce, MailchimpApi};
+///
+/// let ecommerce = Ecommerce::new(MailchimpApi::new("<API_KEY>"));
+/// for store in ecommerce.get_stores(None) {
+///     println!("{} {}", store.id, store.name);
+/// }
+/// let store = ecommerce.get_store("store_001").unwrap();
+/// for product in store.get_products(None) {
+///     println!("{} {} variants", product.title, product.variants.len());
+/// }
+/// let param = EcommerceProductParam {
+///     id: Some("product_001".to_string()),
+///     title: Some("Cat Hat".to_string()),
+///     ..Default::default()
+/// };
+/// let product = store.create_product(param).unwrap();
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct Ecommerce {
+    api: Arc<MailchimpApi>,
+}
+
+impl Ecommerce {
+    ///
+    /// Argumentos:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        Ecommerce { api: Arc::new(api) }
+    }
+
+    ///
+    /// Get information about all stores in the account
+    ///
+    pub fn get_stores(&self, filter: Option<SimpleFilter>) -> MalchimpIter<EcommerceStoresBuilder> {
+        // GET /ecommerce/stores
+        MalchimpIter::from_endpoint(
+            EcommerceStoresBuilder {},
+            self.api.clone(),
+            "ecommerce/stores",
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific store
+    ///
+    pub fn get_store(&self, store_id: &str) -> MailchimpResult<EcommerceStore> {
+        // GET /ecommerce/stores/{store_id}
+        let endpoint = format!("ecommerce/stores/{}", path_segment(store_id));
+        let mut store = self.api.get::<EcommerceStore>(&endpoint, HashMap::new())?;
+        store.set_api(self.api.clone());
+        Ok(store)
+    }
+
+    ///
+    /// Add a new store to your Mailchimp account
+    ///
+    pub fn create_store(&self, param: EcommerceStoreParam) -> MailchimpResult<EcommerceStore> {
+        // POST /ecommerce/stores
+        let mut store = self
+            .api
+            .post::<EcommerceStore, EcommerceStoreParam>("ecommerce/stores", param)?;
+        store.set_api(self.api.clone());
+        Ok(store)
+    }
+}
@@ -0,0 +1,319 @@
+//! Implement Mailchimp File Manager Endpoint
+//!
+//! Manage the files and images that you store in the Mailchimp File Manager,
+//! e.g. the images used in the campaigns.
+//!
+//! ```no_run
+//!     use mailchimp::MailchimpApi;
+//!     use mailchimp::{FileManager, FileManagerFilter};
+//!
+//!     fn main() {
+//!         let api = MailchimpApi::new("<API_KEY>");
+//!         let file_manager = FileManager::new(api);
+//!
+//!         // Upload an image into the root folder
+//!         let image = file_manager.upload_file("header.png", None).unwrap();
+//!         println!("\t Image URL {}", image.full_size_url);
+//!
+//!         // Images uploaded since October
+//!         let filter = FileManagerFilter {
+//!             file_type: Some("image".to_string()),
+//!             since_created_at: Some("2020-10-01T00:00:00+00:00".parse().unwrap()),
+//!             ..FileManagerFilter::default()
+//!         };
+//!         for f in file_manager.iter(filter) {
+//!             println!("\t {} {} bytes", f.name, f.size);
+//!         }
+//!     }
+//! ```
+//!
+
+use super::api::MailchimpApi;
+use super::internal::error_type::MailchimpError;
+use super::internal::request::MailchimpResult;
+use super::iter::{BuildIter, MalchimpIter, ResourceFilter, SimpleFilter};
+use super::types::{
+    format_datetime, CollectionFileManagerFiles, FileManagerFileParam, FileManagerFileType,
+    FileManagerFolderParam, FileManagerFolderType, FileManagerFoldersBuilder, MailchimpDateTime,
+    SortDir,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+///
+/// File Manager Request Filter
+///
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FileManagerFilter {
+    /// A comma-separated list of fields to return.
+    /// Reference parameters of sub-objects with dot notation.
+    pub fields: Option<String>,
+    /// A comma-separated list of fields to exclude. Reference
+    /// parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<String>,
+    /// The number of records to return. Default value is 10.
+    pub count: Option<u64>,
+    /// The number of records from a collection to skip. Iterating over
+    /// large collections with this parameter can be slow. Default value is 0..
+    pub offset: Option<u64>,
+    /// The file type for the File Manager file.
+    /// image file
+    pub file_type: Option<String>,
+    /// Only return the files of this folder
+    pub folder_id: Option<u64>,
+    /// The Mailchimp account user who created the File Manager file.
+    pub created_by: Option<String>,
+    /// Restrict the response to files created before the set date. We recommend
+    /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_created_at: Option<MailchimpDateTime>,
+    /// Restrict the response to files created after the set date. We recommend
+    /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_created_at: Option<MailchimpDateTime>,
+    /// Returns files sorted by the specified field.
+    /// added_date
+    pub sort_field: Option<String>,
+    /// Determines the order direction for sorted results.
+    /// ASC DESC
+    pub sort_dir: Option<SortDir>,
+}
+
+impl Default for FileManagerFilter {
+    fn default() -> Self {
+        FileManagerFilter {
+            fields: None,
+            exclude_fields: None,
+            count: Some(50),
+            offset: Some(0),
+            file_type: None,
+            folder_id: None,
+            created_by: None,
+            before_created_at: None,
+            since_created_at: None,
+            sort_field: None,
+            sort_dir: None,
+        }
+    }
+}
+
+impl ResourceFilter for FileManagerFilter {
+    fn build_payload(&self) -> HashMap<String, String> {
+        let mut payload = HashMap::new();
+
+        if let Some(v) = &self.fields {
+            payload.insert("fields".to_string(), v.clone());
+        }
+        if let Some(v) = &self.exclude_fields {
+            payload.insert("exclude_fields".to_string(), v.clone());
+        }
+        if let Some(v) = self.count {
+            payload.insert("count".to_string(), format!("{:}", v));
+        }
+        if let Some(v) = self.offset {
+            payload.insert("offset".to_string(), format!("{:}", v));
+        }
+        if let Some(v) = &self.file_type {
+            payload.insert("type".to_string(), v.clone());
+        }
+        if let Some(v) = &self.created_by {
+            payload.insert("created_by".to_string(), v.clone());
+        }
+        if let Some(v) = &self.before_created_at {
+            payload.insert("before_created_at".to_string(), format_datetime(v));
+        }
+        if let Some(v) = &self.since_created_at {
+            payload.insert("since_created_at".to_string(), format_datetime(v));
+        }
+        if let Some(v) = &self.sort_field {
+            payload.insert("sort_field".to_string(), v.clone());
+        }
+        if let Some(v) = &self.sort_dir {
+            payload.insert("sort_dir".to_string(), v.to_string());
+        }
+        payload
+    }
+}
+
+///
+/// FileManagerFilesBuilder
+///
+#[derive(Debug)]
+pub struct FileManagerFilesBuilder {}
+
+impl BuildIter for FileManagerFilesBuilder {
+    type Item = FileManagerFileType;
+    type FilterItem = FileManagerFilter;
+    type Collection = CollectionFileManagerFiles;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// Implement Mailchimp File Manager Endpoint
+///
+/// Manage the files and images that you store in the Mailchimp File Manager,
+/// and the folders used to organize them.
+///
+#[derive(Debug, Clone)]
+pub struct FileManager {
+    api: Arc<MailchimpApi>,
+}
+
+impl FileManager {
+    ///
+    /// Argumentos:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        FileManager { api: Arc::new(api) }
+    }
+
+    // ============== Files ==============
+
+    ///
+    /// Get a list of available images and files stored in the File Manager.
+    /// When `folder_id` is set only the files of that folder are returned.
+    ///
+    pub fn iter(&self, filters: FileManagerFilter) -> MalchimpIter<FileManagerFilesBuilder> {
+        // GET /file-manager/files
+        // GET /file-manager/folders/{folder_id}/files
+        let endpoint = match filters.folder_id {
+            Some(folder_id) => format!("file-manager/folders/{}/files", folder_id),
+            None => "file-manager/files".to_string(),
+        };
+        MalchimpIter::from_endpoint(
+            FileManagerFilesBuilder {},
+            self.api.clone(),
+            &endpoint,
+            filters,
+        )
+    }
+
+    ///
+    /// Get information about a specific file in the File Manager
+    ///
+    pub fn get_file(&self, file_id: u64) -> MailchimpResult<FileManagerFileType> {
+        // GET /file-manager/files/{file_id}
+        let endpoint = format!("file-manager/files/{}", file_id);
+        let mut file = self
+            .api
+            .get::<FileManagerFileType>(&endpoint, HashMap::new())?;
+        file.set_api(self.api.clone());
+        Ok(file)
+    }
+
+    ///
+    /// Upload the content of a file, it is base64-encoded before it is sent
+    ///
+    /// Argumentos:
+    ///     name: Name of the file in the File Manager
+    ///     content: Content of the file
+    ///     folder_id: Folder of the file, None to upload into the root folder
+    ///
+    pub fn upload(
+        &self,
+        name: &str,
+        content: &[u8],
+        folder_id: Option<u64>,
+    ) -> MailchimpResult<FileManagerFileType> {
+        // POST /file-manager/files
+        let param = FileManagerFileParam {
+            folder_id,
+            name: Some(name.to_string()),
+            file_data: Some(base64::encode(content)),
+        };
+        let mut file = self
+            .api
+            .post::<FileManagerFileType, FileManagerFileParam>("file-manager/files", param)?;
+        file.set_api(self.api.clone());
+        Ok(file)
+    }
+
+    ///
+    /// Upload a local file, the name of the file is used as name in the File Manager
+    ///
+    pub fn upload_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        folder_id: Option<u64>,
+    ) -> MailchimpResult<FileManagerFileType> {
+        let path = path.as_ref();
+        let name = match path.file_name() {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => {
+                return Err(MailchimpError::Io(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{} is not a file", path.display()),
+                )))
+            }
+        };
+        let content = fs::read(path).map_err(|e| {
+            io::Error::new(e.kind(), format!("cannot read {}: {}", path.display(), e))
+        })?;
+        self.upload(&name, &content, folder_id)
+    }
+
+    // ============== Folders ==============
+
+    ///
+    /// Get a list of all folders in the File Manager
+    ///
+    pub fn get_folders(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<FileManagerFoldersBuilder> {
+        // GET /file-manager/folders
+        MalchimpIter::from_endpoint(
+            FileManagerFoldersBuilder {},
+            self.api.clone(),
+            "file-manager/folders",
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific folder in the File Manager
+    ///
+    pub fn get_folder(&self, folder_id: u64) -> MailchimpResult<FileManagerFolderType> {
+        // GET /file-manager/folders/{folder_id}
+        let endpoint = format!("file-manager/folders/{}", folder_id);
+        let mut folder = self
+            .api
+            .get::<FileManagerFolderType>(&endpoint, HashMap::new())?;
+        folder.set_api(self.api.clone());
+        Ok(folder)
+    }
+
+    ///
+    /// Create a new folder in the File Manager
+    ///
+    pub fn create_folder(
+        &self,
+        param: FileManagerFolderParam,
+    ) -> MailchimpResult<FileManagerFolderType> {
+        // POST /file-manager/folders
+        let mut folder = self
+            .api
+            .post::<FileManagerFolderType, FileManagerFolderParam>("file-manager/folders", param)?;
+        folder.set_api(self.api.clone());
+        Ok(folder)
+    }
+}
@@ -1,32 +1,38 @@
-use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
 use reqwest::Url;
 use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
-use serde_json;
+use serde_json::{self, Value};
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 
-use super::error_type::MailchimpErrorType;
+use super::error_type::MailchimpError;
 use super::request::{BasicAuth, HttpReq, MailchimpResult};
 
 ///
 /// Definición del API Interno
 ///
-#[derive(Debug, Clone)]
-pub struct Api<R>
-where
-    R: HttpReq,
-{
+#[derive(Clone)]
+pub struct Api {
     domain: String,
     api_version: String,
-    api_key: String,
-    req: Box<R>,
+    req: Arc<dyn HttpReq>,
     basic_auth: Option<BasicAuth>,
+    bearer_token: Option<HeaderValue>,
 }
 
-impl<R> Api<R>
-where
-    R: HttpReq,
-{
+impl fmt::Debug for Api {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Api")
+            .field("domain", &self.domain)
+            .field("api_version", &self.api_version)
+            .finish()
+    }
+}
+
+impl Api {
     ///
     /// Devuelve la instancia del API para el acceso mediante el ACCESS_TOKEN
     ///
@@ -35,17 +41,58 @@ where
     ///     api_key: Mailchimp Access Token
     ///     http_transport: Interfaz por donde se harían las peticiones Get y Post al servicio
     ///
-    pub fn new<'a>(dc: &'a str, api_key: &'a str, http_transport: Box<R>) -> Self {
-        Api {
-            domain: format!("https://{}.api.mailchimp.com/", dc),
-            api_version: "3.0".to_string(),
-            api_key: api_key.to_string(),
+    pub fn new<'a>(dc: &'a str, api_key: &'a str, http_transport: Arc<dyn HttpReq>) -> Self {
+        Api::with_base_url(
+            &format!("https://{}.api.mailchimp.com/", dc),
+            "3.0",
+            api_key,
+            http_transport,
+        )
+    }
+    ///
+    /// Devuelve la instancia del API que accede al servidor indicado
+    ///
+    /// Argumentos
+    ///     base_url: Url del servidor, p.ej. `https://us6.api.mailchimp.com/`
+    ///     api_version: Versión del API
+    ///     api_key: Mailchimp Access Token
+    ///     http_transport: Interfaz por donde se harían las peticiones Get y Post al servicio
+    ///
+    pub fn with_base_url(
+        base_url: &str,
+        api_version: &str,
+        api_key: &str,
+        http_transport: Arc<dyn HttpReq>,
+    ) -> Self {
+        let api = Api {
+            domain: format!("{}/", base_url.trim_end_matches('/')),
+            api_version: api_version.trim_matches('/').to_string(),
             req: http_transport,
             basic_auth: Some(BasicAuth {
                 username: "".to_string(),
                 api_token: api_key.to_string(),
             }),
+            bearer_token: None,
+        };
+        if let Ok(api_base) = api.build_url("", &HashMap::new()) {
+            api.req.set_api_base(&api_base);
         }
+        api
+    }
+    ///
+    /// Autentica las peticiones con el token OAuth2 en la cabecera
+    /// `Authorization: Bearer` en lugar de la API Key. Devuelve
+    /// `MailchimpError::InvalidApiKey` si el token no es válido en una cabecera
+    ///
+    pub fn set_bearer_token(&mut self, access_token: &str) -> MailchimpResult<()> {
+        let value = HeaderValue::from_str(&format!("Bearer {}", access_token)).map_err(|_| {
+            MailchimpError::InvalidApiKey(
+                "the OAuth2 token is not a valid header value".to_string(),
+            )
+        })?;
+        self.basic_auth = None;
+        self.bearer_token = Some(value);
+        Ok(())
     }
     ///
     /// Devuelve una cadena de texto con el dominio
@@ -60,6 +107,18 @@ where
         self.api_version.clone()
     }
 
+    ///
+    /// Descarga el archivo de la url indicada, sin credenciales
+    ///
+    /// Argumentos
+    ///     url: Url absoluta del archivo
+    ///
+    pub fn download(&self, url: &str) -> MailchimpResult<Vec<u8>> {
+        let url = Url::parse(url)
+            .map_err(|e| MailchimpError::InvalidConfig(format!("url {:?}: {}", url, e)))?;
+        self.req.download(url)
+    }
+
     ///
     /// Función para darle forma a la url
     ///
@@ -67,19 +126,25 @@ where
     ///     endpoint: Endpoint desde donde se va a extraer los datos
     ///     params: Parámetros de la url
     ///
-    pub fn build_url<'a>(&self, endpoint: &'a str, params: &HashMap<String, String>) -> Url {
-        let mut api_url = Url::parse(&self.domain).unwrap();
+    pub fn build_url(
+        &self,
+        endpoint: &str,
+        params: &HashMap<String, String>,
+    ) -> MailchimpResult<Url> {
+        let invalid_url =
+            |e| MailchimpError::InvalidConfig(format!("url for {:?}: {}", endpoint, e));
+        let mut api_url = Url::parse(&self.domain).map_err(invalid_url)?;
         let data = self.api_version.clone() + "/";
         // Adiciona la versión del API
-        api_url = api_url.join(data.as_str()).unwrap();
+        api_url = api_url.join(data.as_str()).map_err(invalid_url)?;
         // Adiciona Endpoint
-        api_url = api_url.join(endpoint).unwrap();
+        api_url = api_url.join(endpoint).map_err(invalid_url)?;
         for (key, value) in params {
             api_url
                 .query_pairs_mut()
                 .append_pair(key.as_str(), value.as_str());
         }
-        api_url
+        Ok(api_url)
     }
 
     ///
@@ -91,6 +156,9 @@ where
             CONTENT_TYPE,
             HeaderValue::from_str("application/json").unwrap(),
         );
+        if let Some(value) = &self.bearer_token {
+            headers.insert(AUTHORIZATION, value.clone());
+        }
         headers
     }
 
@@ -109,19 +177,10 @@ where
     where
         T: DeserializeOwned,
     {
-        let api_url = self.build_url(endpoint, &params);
+        let api_url = self.build_url(endpoint, &params)?;
         let headers = self.build_headers();
-        let mut result = self.req.get(api_url, headers, &self.basic_auth)?;
-        if result.len() == 0 {
-            result = "{}".to_string();
-        }
-        match serde_json::from_str(&result) {
-            Ok(sr) => Ok(sr),
-            Err(e) => {
-                println!("{:?}", e);
-                Err(MailchimpErrorType::default())
-            }
-        }
+        let result = self.req.get(api_url, headers, &self.basic_auth)?;
+        decode_response(endpoint, result)
     }
     ///
     ///
@@ -138,19 +197,10 @@ where
     where
         T: DeserializeOwned,
     {
-        let api_url = self.build_url(endpoint, &params);
+        let api_url = self.build_url(endpoint, &params)?;
         let headers = self.build_headers();
-        let mut result = self.req.delete(api_url, headers, &self.basic_auth)?;
-        if result.len() == 0 {
-            result = "{}".to_string();
-        }
-        match serde_json::from_str(&result) {
-            Ok(sr) => Ok(sr),
-            Err(e) => {
-                println!("{:?}", e);
-                Err(MailchimpErrorType::default())
-            }
-        }
+        let result = self.req.delete(api_url, headers, &self.basic_auth)?;
+        decode_response(endpoint, result)
     }
 
     ///
@@ -165,21 +215,11 @@ where
         T: DeserializeOwned,
         P: Serialize,
     {
-        let api_url = self.build_url(endpoint, &HashMap::new());
+        let api_url = self.build_url(endpoint, &HashMap::new())?;
         let headers = self.build_headers();
-        let mut result = self
-            .req
-            .post::<P>(api_url, headers, payload, &self.basic_auth)?;
-        if result.len() == 0 {
-            result = "{}".to_string();
-        }
-        match serde_json::from_str(&result) {
-            Ok(sr) => Ok(sr),
-            Err(e) => {
-                println!("Post Edge {:?}", e);
-                Err(MailchimpErrorType::default())
-            }
-        }
+        let payload = encode_payload(payload)?;
+        let result = self.req.post(api_url, headers, payload, &self.basic_auth)?;
+        decode_response(endpoint, result)
     }
     ///
     ///
@@ -193,21 +233,11 @@ where
         T: DeserializeOwned,
         P: Serialize,
     {
-        let api_url = self.build_url(endpoint, &HashMap::new());
+        let api_url = self.build_url(endpoint, &HashMap::new())?;
         let headers = self.build_headers();
-        let mut result = self
-            .req
-            .patch::<P>(api_url, headers, payload, &self.basic_auth)?;
-        if result.len() == 0 {
-            result = "{}".to_string();
-        }
-        match serde_json::from_str(&result) {
-            Ok(sr) => Ok(sr),
-            Err(e) => {
-                println!("Post Edge {:?}", e);
-                Err(MailchimpErrorType::default())
-            }
-        }
+        let payload = encode_payload(payload)?;
+        let result = self.req.patch(api_url, headers, payload, &self.basic_auth)?;
+        decode_response(endpoint, result)
     }
     ///
     ///
@@ -221,20 +251,120 @@ where
         T: DeserializeOwned,
         P: Serialize,
     {
-        let api_url = self.build_url(endpoint, &HashMap::new());
+        let api_url = self.build_url(endpoint, &HashMap::new())?;
         let headers = self.build_headers();
-        let mut result = self
-            .req
-            .put::<P>(api_url, headers, payload, &self.basic_auth)?;
-        if result.len() == 0 {
-            result = "{}".to_string();
-        }
-        match serde_json::from_str(&result) {
-            Ok(sr) => Ok(sr),
-            Err(e) => {
-                println!("Post Edge {:?}", e);
-                Err(MailchimpErrorType::default())
-            }
+        let payload = encode_payload(payload)?;
+        let result = self.req.put(api_url, headers, payload, &self.basic_auth)?;
+        decode_response(endpoint, result)
+    }
+}
+
+///
+/// Caracteres que se codifican en un segmento de la ruta
+///
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+///
+/// Codifica un identificador para usarlo como un segmento de la ruta, de
+/// modo que `/`, `?` o `#` no cambien el endpoint al que se envía la petición
+///
+/// Argumentos
+///     id: Identificador del recurso
+///
+pub fn path_segment(id: &str) -> String {
+    utf8_percent_encode(id, PATH_SEGMENT).to_string()
+}
+
+///
+/// Deserializa el cuerpo de la respuesta, una respuesta vacía equivale a `{}`
+///
+/// Argumentos
+///     endpoint: Endpoint desde donde se obtuvo la respuesta
+///     body: Cuerpo de la respuesta
+///
+pub fn decode_response<T>(endpoint: &str, body: String) -> MailchimpResult<T>
+where
+    T: DeserializeOwned,
+{
+    let text = if body.trim().is_empty() { "{}" } else { body.as_str() };
+    serde_json::from_str(text).map_err(|e| MailchimpError::Deserialize {
+        endpoint: endpoint.to_string(),
+        body: body.clone(),
+        source: e,
+    })
+}
+
+///
+/// Separa la API Key en la llave y el datacenter, si no incluye el
+/// datacenter se usa "usX"
+///
+pub fn split_api_key(api_key: &str) -> (&str, &str) {
+    let mut creds = api_key.split('-');
+    let key = creds.next().unwrap_or("");
+    let dc = creds.next().unwrap_or("usX");
+    (key, dc)
+}
+
+///
+/// Valida el formato `<key>-<dc>` de la API Key y la separa en la llave y el
+/// datacenter
+///
+pub fn parse_api_key(api_key: &str) -> MailchimpResult<(&str, &str)> {
+    let api_key = api_key.trim();
+    if api_key.is_empty() {
+        return Err(MailchimpError::InvalidApiKey(
+            "the API key is empty".to_string(),
+        ));
+    }
+    let mut creds = api_key.splitn(2, '-');
+    let key = creds.next().unwrap_or("");
+    let dc = match creds.next() {
+        Some(dc) => dc,
+        None => {
+            return Err(MailchimpError::InvalidApiKey(
+                "the API key has no datacenter, expected <key>-<dc>".to_string(),
+            ))
         }
+    };
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(MailchimpError::InvalidApiKey(
+            "the key must be alphanumeric, expected <key>-<dc>".to_string(),
+        ));
+    }
+    if !is_datacenter(dc) {
+        return Err(MailchimpError::InvalidApiKey(format!(
+            "unknown datacenter {:?}, expected something like \"us6\"",
+            dc
+        )));
     }
+    Ok((key, dc))
+}
+
+///
+/// Los datacenter se forman con letras minúsculas seguidas de números, p.ej. "us6"
+///
+fn is_datacenter(dc: &str) -> bool {
+    let region = dc.trim_end_matches(|c: char| c.is_ascii_digit());
+    !region.is_empty() && region.len() < dc.len() && region.chars().all(|c| c.is_ascii_lowercase())
+}
+
+///
+/// Serializa el cuerpo de la petición
+///
+pub fn encode_payload<P>(payload: P) -> MailchimpResult<Value>
+where
+    P: Serialize,
+{
+    serde_json::to_value(payload).map_err(MailchimpError::InvalidPayload)
 }
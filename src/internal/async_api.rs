@@ -0,0 +1,252 @@
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use serde::ser::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use super::api::{decode_response, encode_payload};
+use super::async_request::AsyncHttpReq;
+use super::error_type::MailchimpError;
+use super::request::{BasicAuth, MailchimpResult};
+
+///
+/// Definición del API Interno asíncrono
+///
+#[derive(Clone)]
+pub struct AsyncApi {
+    domain: String,
+    api_version: String,
+    req: Arc<dyn AsyncHttpReq>,
+    basic_auth: Option<BasicAuth>,
+    bearer_token: Option<HeaderValue>,
+}
+
+impl fmt::Debug for AsyncApi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AsyncApi")
+            .field("domain", &self.domain)
+            .field("api_version", &self.api_version)
+            .finish()
+    }
+}
+
+impl AsyncApi {
+    ///
+    /// Devuelve la instancia del API para el acceso mediante el ACCESS_TOKEN
+    ///
+    /// Argumentos
+    ///     dc: Mailchimp Datacenter
+    ///     api_key: Mailchimp Access Token
+    ///     http_transport: Interfaz por donde se harían las peticiones Get y Post al servicio
+    ///
+    pub fn new(dc: &str, api_key: &str, http_transport: Arc<dyn AsyncHttpReq>) -> Self {
+        AsyncApi::with_base_url(
+            &format!("https://{}.api.mailchimp.com/", dc),
+            "3.0",
+            api_key,
+            http_transport,
+        )
+    }
+    ///
+    /// Devuelve la instancia del API que accede al servidor indicado
+    ///
+    /// Argumentos
+    ///     base_url: Url del servidor, p.ej. `https://us6.api.mailchimp.com/`
+    ///     api_version: Versión del API
+    ///     api_key: Mailchimp Access Token
+    ///     http_transport: Interfaz por donde se harían las peticiones Get y Post al servicio
+    ///
+    pub fn with_base_url(
+        base_url: &str,
+        api_version: &str,
+        api_key: &str,
+        http_transport: Arc<dyn AsyncHttpReq>,
+    ) -> Self {
+        let api = AsyncApi {
+            domain: format!("{}/", base_url.trim_end_matches('/')),
+            api_version: api_version.trim_matches('/').to_string(),
+            req: http_transport,
+            basic_auth: Some(BasicAuth {
+                username: "".to_string(),
+                api_token: api_key.to_string(),
+            }),
+            bearer_token: None,
+        };
+        if let Ok(api_base) = api.build_url("", &HashMap::new()) {
+            api.req.set_api_base(&api_base);
+        }
+        api
+    }
+    ///
+    /// Autentica las peticiones con el token OAuth2 en la cabecera
+    /// `Authorization: Bearer` en lugar de la API Key. Devuelve
+    /// `MailchimpError::InvalidApiKey` si el token no es válido en una cabecera
+    ///
+    pub fn set_bearer_token(&mut self, access_token: &str) -> MailchimpResult<()> {
+        let value = HeaderValue::from_str(&format!("Bearer {}", access_token)).map_err(|_| {
+            MailchimpError::InvalidApiKey(
+                "the OAuth2 token is not a valid header value".to_string(),
+            )
+        })?;
+        self.basic_auth = None;
+        self.bearer_token = Some(value);
+        Ok(())
+    }
+    ///
+    /// Devuelve una cadena de texto con el dominio
+    ///
+    pub fn domain(&self) -> String {
+        self.domain.clone()
+    }
+    ///
+    /// Devuelve una cadena de texto la versión del API
+    ///
+    pub fn api_version(&self) -> String {
+        self.api_version.clone()
+    }
+
+    ///
+    /// Función para darle forma a la url
+    ///
+    /// Argumentos
+    ///     endpoint: Endpoint desde donde se va a extraer los datos
+    ///     params: Parámetros de la url
+    ///
+    pub fn build_url(
+        &self,
+        endpoint: &str,
+        params: &HashMap<String, String>,
+    ) -> MailchimpResult<Url> {
+        let invalid_url =
+            |e| MailchimpError::InvalidConfig(format!("url for {:?}: {}", endpoint, e));
+        let mut api_url = Url::parse(&self.domain).map_err(invalid_url)?;
+        let data = self.api_version.clone() + "/";
+        // Adiciona la versión del API
+        api_url = api_url.join(data.as_str()).map_err(invalid_url)?;
+        // Adiciona Endpoint
+        api_url = api_url.join(endpoint).map_err(invalid_url)?;
+        for (key, value) in params {
+            api_url
+                .query_pairs_mut()
+                .append_pair(key.as_str(), value.as_str());
+        }
+        Ok(api_url)
+    }
+
+    ///
+    /// Conforma los headers para realizar la petición al servidor
+    ///
+    pub fn build_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_str("application/json").unwrap(),
+        );
+        if let Some(value) = &self.bearer_token {
+            headers.insert(AUTHORIZATION, value.clone());
+        }
+        headers
+    }
+
+    ///
+    ///
+    /// Argumentos
+    ///     endpoint: Endpoint desde donde se va a extraer los datos
+    ///     params: Parámetros de la url
+    ///
+    pub async fn get_edge<T>(
+        &self,
+        endpoint: &str,
+        params: HashMap<String, String>,
+    ) -> MailchimpResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        let api_url = self.build_url(endpoint, &params)?;
+        let headers = self.build_headers();
+        let result = self.req.get(api_url, headers, &self.basic_auth).await?;
+        decode_response(endpoint, result)
+    }
+    ///
+    ///
+    /// Argumentos
+    ///     endpoint: Endpoint desde donde se va a extraer los datos
+    ///     params: Parámetros de la url
+    ///
+    pub async fn delete_edge<T>(
+        &self,
+        endpoint: &str,
+        params: HashMap<String, String>,
+    ) -> MailchimpResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        let api_url = self.build_url(endpoint, &params)?;
+        let headers = self.build_headers();
+        let result = self.req.delete(api_url, headers, &self.basic_auth).await?;
+        decode_response(endpoint, result)
+    }
+
+    ///
+    ///
+    /// Argumentos
+    ///     endpoint: Endpoint hacia donde se van a enviar los datos
+    ///     payload: Dato a enviar
+    ///
+    pub async fn post_edge<T, P>(&self, endpoint: &str, payload: P) -> MailchimpResult<T>
+    where
+        T: DeserializeOwned,
+        P: Serialize,
+    {
+        let api_url = self.build_url(endpoint, &HashMap::new())?;
+        let headers = self.build_headers();
+        let payload = encode_payload(payload)?;
+        let result = self
+            .req
+            .post(api_url, headers, payload, &self.basic_auth)
+            .await?;
+        decode_response(endpoint, result)
+    }
+    ///
+    ///
+    /// Argumentos
+    ///     endpoint: Endpoint hacia donde se van a enviar los datos
+    ///     payload: Dato a enviar
+    ///
+    pub async fn patch_edge<T, P>(&self, endpoint: &str, payload: P) -> MailchimpResult<T>
+    where
+        T: DeserializeOwned,
+        P: Serialize,
+    {
+        let api_url = self.build_url(endpoint, &HashMap::new())?;
+        let headers = self.build_headers();
+        let payload = encode_payload(payload)?;
+        let result = self
+            .req
+            .patch(api_url, headers, payload, &self.basic_auth)
+            .await?;
+        decode_response(endpoint, result)
+    }
+    ///
+    ///
+    /// Argumentos
+    ///     endpoint: Endpoint hacia donde se van a enviar los datos
+    ///     payload: Dato a enviar
+    ///
+    pub async fn put_edge<T, P>(&self, endpoint: &str, payload: P) -> MailchimpResult<T>
+    where
+        T: DeserializeOwned,
+        P: Serialize,
+    {
+        let api_url = self.build_url(endpoint, &HashMap::new())?;
+        let headers = self.build_headers();
+        let payload = encode_payload(payload)?;
+        let result = self
+            .req
+            .put(api_url, headers, payload, &self.basic_auth)
+            .await?;
+        decode_response(endpoint, result)
+    }
+}
@@ -0,0 +1,320 @@
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Error, Method, RequestBuilder, Response, Url};
+use serde_json::Value;
+use tokio::time::delay_for;
+
+// import macro error
+use log::{error, warn};
+
+use super::error_type::MailchimpError;
+use super::request::{check_response, BasicAuth, MailchimpResult};
+use super::retry::{retry_after, RetryPolicy};
+
+///
+/// Versión asíncrona de `HttpReq`, definición que deben cumplir para poder
+/// extraer datos mediante HTTP desde un runtime asíncrono
+///
+/// Implement it with `#[async_trait]` to plug your own transport into
+/// `nonblocking::MailchimpApi::with_transport`.
+///
+#[async_trait]
+pub trait AsyncHttpReq: Send + Sync {
+    ///
+    /// Función para leer los recursos desde el servidor
+    ///
+    ///  Argumentos:
+    ///     url: Url
+    ///     headers: HeaderMap
+    ///
+    async fn get(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+        basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String>;
+    ///
+    /// Función para crear algún recurso en el servidor
+    ///
+    ///  Argumentos:
+    ///     url: Url
+    ///     headers: Headers
+    ///     payload: Datos a enviar a la URL especificada
+    ///
+    async fn post(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+        payload: Value,
+        basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String>;
+    ///
+    /// Función para Actualizar algún recurso en el servidor
+    ///
+    ///  Argumentos:
+    ///     url: Url
+    ///     headers: Headers
+    ///     payload: Datos a enviar a la URL especificada
+    ///
+    async fn patch(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+        payload: Value,
+        basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String>;
+    ///
+    /// Función para Actualizar algún recurso en el servidor
+    ///
+    ///  Argumentos:
+    ///     url: Url
+    ///     headers: Headers
+    ///     payload: Datos a enviar a la URL especificada
+    ///
+    async fn put(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+        payload: Value,
+        basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String>;
+    ///
+    /// Función para eliminar algun recursos en el servidor
+    ///
+    ///  Argumentos:
+    ///     url: Url
+    ///     headers: HeaderMap
+    ///
+    async fn delete(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+        basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String>;
+    ///
+    /// Cambia la política de reintentos, los transportes que no reintentan
+    /// las peticiones la ignoran
+    ///
+    fn set_retry_policy(&mut self, _policy: RetryPolicy) {}
+    ///
+    /// Recibe la url base del API, p.ej. `https://us6.api.mailchimp.com/3.0/`,
+    /// sobre la que se resuelven los endpoints. Por defecto no hace nada
+    ///
+    fn set_api_base(&self, _api_base: &Url) {}
+}
+
+///
+/// MailchimpAsyncRequest
+///
+/// Transporte HTTP basado en el cliente asíncrono de reqwest. Requiere
+/// ejecutarse dentro de un runtime de tokio.
+///
+#[derive(Debug, Clone)]
+pub struct MailchimpAsyncRequest {
+    client: Client,
+    retry: RetryPolicy,
+}
+
+impl MailchimpAsyncRequest {
+    ///
+    /// Devuelve una instancia nueva
+    ///
+    pub fn new() -> Self {
+        MailchimpAsyncRequest::with_client(Client::new())
+    }
+
+    ///
+    /// Devuelve una instancia nueva que envía las peticiones con el cliente
+    /// indicado, p.ej. con timeouts o proxy
+    ///
+    pub fn with_client(client: Client) -> Self {
+        MailchimpAsyncRequest {
+            client,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    ///
+    /// Devuelve una instancia nueva con la política de reintentos indicada
+    ///
+    pub fn with_retry_policy(retry: RetryPolicy) -> Self {
+        MailchimpAsyncRequest {
+            client: Client::new(),
+            retry,
+        }
+    }
+
+    ///
+    /// Envía la petición, repitiéndola mientras la política de reintentos lo permita
+    ///
+    /// Argumentos:
+    ///     method: Método HTTP de la petición
+    ///     build: Función que construye la petición para cada intento
+    ///
+    async fn execute<F>(&self, method: Method, build: F) -> MailchimpResult<String>
+    where
+        F: Fn() -> RequestBuilder + Send,
+    {
+        let mut attempt = 0;
+        loop {
+            let result = build().send().await;
+            let wait = match &result {
+                Ok(resp)
+                    if self
+                        .retry
+                        .should_retry_status(&method, resp.status(), attempt) =>
+                {
+                    Some(self.retry.delay(attempt, retry_after(resp.headers())))
+                }
+                Err(e) if self.retry.should_retry_error(&method, e, attempt) => {
+                    Some(self.retry.delay(attempt, None))
+                }
+                _ => None,
+            };
+            match wait {
+                Some(delay) => {
+                    warn!(
+                        target: "mailchimp",
+                        "{:?}: Retrying request in {:?} (attempt {})", method, delay, attempt + 1);
+                    delay_for(delay).await;
+                    attempt += 1;
+                }
+                None => return process_response(result, method.as_str()).await,
+            }
+        }
+    }
+}
+
+impl Default for MailchimpAsyncRequest {
+    fn default() -> Self {
+        MailchimpAsyncRequest::new()
+    }
+}
+
+#[async_trait]
+impl AsyncHttpReq for MailchimpAsyncRequest {
+    fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry = policy;
+    }
+
+    ///
+    ///  Argumentos:
+    ///     url: Url
+    ///     headers: HeaderMap
+    ///
+    async fn get(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+        basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.execute(Method::GET, || {
+            with_auth(self.client.get(url.clone()), basic_auth).headers(headers.clone())
+        })
+        .await
+    }
+    ///
+    ///  Argumentos:
+    ///     url: Url
+    ///     headers: HeaderMap
+    ///     payload: Datos a enviar a la URL especificada
+    ///
+    async fn post(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+        payload: Value,
+        basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.execute(Method::POST, || {
+            with_auth(self.client.post(url.clone()), basic_auth)
+                .headers(headers.clone())
+                .json(&payload)
+        })
+        .await
+    }
+    ///
+    ///  Argumentos:
+    ///     url: Url
+    ///     headers: HeaderMap
+    ///     payload: Datos a enviar a la URL especificada
+    ///
+    async fn patch(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+        payload: Value,
+        basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.execute(Method::PATCH, || {
+            with_auth(self.client.patch(url.clone()), basic_auth)
+                .headers(headers.clone())
+                .json(&payload)
+        })
+        .await
+    }
+    ///
+    ///  Argumentos:
+    ///     url: Url
+    ///     headers: HeaderMap
+    ///     payload: Datos a enviar a la URL especificada
+    ///
+    async fn put(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+        payload: Value,
+        basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.execute(Method::PUT, || {
+            with_auth(self.client.put(url.clone()), basic_auth)
+                .headers(headers.clone())
+                .json(&payload)
+        })
+        .await
+    }
+    ///
+    ///  Argumentos:
+    ///     url: Url
+    ///     headers: HeaderMap
+    ///
+    async fn delete(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+        basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.execute(Method::DELETE, || {
+            with_auth(self.client.delete(url.clone()), basic_auth).headers(headers.clone())
+        })
+        .await
+    }
+}
+
+///
+/// Adiciona las credenciales a la petición si existen
+///
+fn with_auth(builder: RequestBuilder, basic_auth: &Option<BasicAuth>) -> RequestBuilder {
+    match basic_auth {
+        Some(auth) => builder.basic_auth(auth.username.clone(), Some(auth.api_token.clone())),
+        None => builder,
+    }
+}
+
+async fn process_response(
+    response: Result<Response, Error>,
+    method: &str,
+) -> MailchimpResult<String> {
+    let resp = response.map_err(|e| {
+        error!(target: "mailchimp", "{:?} {:?}", method, e);
+        MailchimpError::from(e)
+    })?;
+    let status = resp.status();
+    let body = resp.text().await.map_err(|e| {
+        error!(
+            target: "mailchimp",
+            "{:?}: Response Error details: {:?} status {:?}",  method, e, status);
+        MailchimpError::from(e)
+    })?;
+    check_response(status, body)
+}
@@ -17,21 +17,6 @@ pub struct MailchimpErrorType {
     pub instance: String,
 }
 
-impl Default for MailchimpErrorType {
-    fn default() -> Self {
-        MailchimpErrorType {
-            error_type:
-                "http://developer.mailchimp.com/documentation/mailchimp/guides/error-glossary/"
-                    .to_string(),
-            title: "Resource Not Found".to_string(),
-            status: 404,
-            detail: "could not find resource for requested class_path:AuthorizedAppType_Collection"
-                .to_string(),
-            instance: "3345556-334-434-3444-34343f4f4434f".to_string(),
-        }
-    }
-}
-
 impl fmt::Display for MailchimpErrorType {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         write!(
@@ -43,3 +28,148 @@ impl fmt::Display for MailchimpErrorType {
 }
 
 impl Error for MailchimpErrorType {}
+
+///
+/// Errors returned by the library
+///
+#[derive(Debug)]
+pub enum MailchimpError {
+    /// The request could not be sent or the response could not be read
+    /// (connection refused, DNS, TLS, ...)
+    Transport(Box<dyn Error + Send + Sync>),
+    /// The request did not complete in time
+    Timeout(String),
+    /// Mailchimp answered with a problem detail document
+    Api(MailchimpErrorType),
+    /// The server answered with an error status and a body that is not a
+    /// problem detail document
+    Http {
+        /// HTTP status code
+        status: u16,
+        /// Raw response body
+        body: String,
+    },
+    /// The response body could not be decoded into the expected type
+    Deserialize {
+        /// Endpoint that returned the body
+        endpoint: String,
+        /// Raw response body
+        body: String,
+        /// Decoder error, it includes the line and column of the problem
+        source: serde_json::Error,
+    },
+    /// The API key does not have the `<key>-<dc>` format
+    InvalidApiKey(String),
+    /// The payload of the request could not be encoded as JSON
+    InvalidPayload(serde_json::Error),
+    /// The configuration of the client is not valid (base URL, proxy,
+    /// certificates, ...)
+    InvalidConfig(String),
+    /// A local file could not be read or written, e.g. the file to upload or
+    /// the archive with the results of a batch
+    Io(std::io::Error),
+    /// The batch request is not finished, so its results are not available yet
+    BatchNotFinished {
+        /// Id of the batch
+        batch_id: String,
+        /// Last status of the batch, e.g. `started`
+        status: String,
+    },
+    /// A merge field value does not match the definition of the field in the
+    /// list (unknown tag, wrong type, missing required value, ...)
+    InvalidMergeField {
+        /// Tag of the merge field
+        tag: String,
+        /// Why the value is not valid
+        reason: String,
+    },
+}
+
+impl MailchimpError {
+    ///
+    /// HTTP status code of the error, if the server answered
+    ///
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            MailchimpError::Api(e) => Some(e.status as u16),
+            MailchimpError::Http { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns true if the requested resource does not exist
+    ///
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404)
+    }
+
+    ///
+    /// Problem detail document sent by Mailchimp, if any
+    ///
+    pub fn api_error(&self) -> Option<&MailchimpErrorType> {
+        match self {
+            MailchimpError::Api(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MailchimpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            MailchimpError::Transport(e) => write!(f, "Transport error: {}", e),
+            MailchimpError::Timeout(msg) => write!(f, "Request timed out: {}", msg),
+            MailchimpError::Api(e) => write!(f, "{}", e),
+            MailchimpError::Http { status, body } => write!(f, "HTTP {}: {}", status, body),
+            MailchimpError::Deserialize {
+                endpoint, source, ..
+            } => write!(f, "Invalid response from \"{}\": {}", endpoint, source),
+            MailchimpError::InvalidApiKey(msg) => write!(f, "Invalid API key: {}", msg),
+            MailchimpError::InvalidPayload(e) => write!(f, "Invalid payload: {}", e),
+            MailchimpError::InvalidConfig(msg) => write!(f, "Invalid configuration: {}", msg),
+            MailchimpError::Io(e) => write!(f, "I/O error: {}", e),
+            MailchimpError::BatchNotFinished { batch_id, status } => {
+                write!(f, "Batch {} is not finished: {}", batch_id, status)
+            }
+            MailchimpError::InvalidMergeField { tag, reason } => {
+                write!(f, "Invalid merge field {}: {}", tag, reason)
+            }
+        }
+    }
+}
+
+impl Error for MailchimpError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MailchimpError::Transport(e) => Some(e.as_ref()),
+            MailchimpError::Api(e) => Some(e),
+            MailchimpError::Deserialize { source, .. } => Some(source),
+            MailchimpError::InvalidPayload(e) => Some(e),
+            MailchimpError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<MailchimpErrorType> for MailchimpError {
+    fn from(e: MailchimpErrorType) -> Self {
+        MailchimpError::Api(e)
+    }
+}
+
+impl From<std::io::Error> for MailchimpError {
+    fn from(e: std::io::Error) -> Self {
+        MailchimpError::Io(e)
+    }
+}
+
+impl From<reqwest::Error> for MailchimpError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            MailchimpError::Timeout(e.to_string())
+        } else {
+            MailchimpError::Transport(Box::new(e))
+        }
+    }
+}
@@ -1,17 +1,28 @@
 pub mod api;
+#[cfg(feature = "async")]
+pub mod async_api;
+#[cfg(feature = "async")]
+pub mod async_request;
 pub mod error_type;
 pub mod request;
+pub mod retry;
 
 #[cfg(test)]
 mod tests {
-    use reqwest::header::{HeaderMap, CONTENT_TYPE};
-    use reqwest::Url;
-    use serde::ser::Serialize;
-    use serde_json::json;
+    use reqwest::header::{HeaderMap, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER};
+    use reqwest::{Method, StatusCode, Url};
+    use serde_json::{json, Value};
     use std::collections::HashMap;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
 
-    use super::api::Api;
-    use super::request::{BasicAuth, HttpReq, MailchimpResult};
+    use super::api::{parse_api_key, Api};
+    use super::error_type::MailchimpError;
+    use super::request::{check_response, BasicAuth, HttpReq, MailchimpResult};
+    use super::retry::{retry_after, RetryPolicy};
     use crate::types::*;
 
     ///
@@ -55,16 +66,13 @@ mod tests {
         ///     headers: HeaderMap
         ///     payload: Datos a enviar a la URL especificada
         ///
-        fn post<P>(
+        fn post(
             &self,
             _url: Url,
             _headers: HeaderMap,
-            _payload: P,
+            _payload: Value,
             _basic_auth: &Option<BasicAuth>,
-        ) -> MailchimpResult<String>
-        where
-            P: Serialize,
-        {
+        ) -> MailchimpResult<String> {
             Ok(self.resp_for_post.clone())
         }
         ///
@@ -73,16 +81,13 @@ mod tests {
         ///     headers: HeaderMap
         ///     payload: Datos a enviar a la URL especificada
         ///
-        fn put<P>(
+        fn put(
             &self,
             _url: Url,
             _headers: HeaderMap,
-            _payload: P,
+            _payload: Value,
             _basic_auth: &Option<BasicAuth>,
-        ) -> MailchimpResult<String>
-        where
-            P: Serialize,
-        {
+        ) -> MailchimpResult<String> {
             Ok(self.resp_for_post.clone())
         }
         ///
@@ -91,16 +96,13 @@ mod tests {
         ///     headers: HeaderMap
         ///     payload: Datos a enviar a la URL especificada
         ///
-        fn patch<P>(
+        fn patch(
             &self,
             _url: Url,
             _headers: HeaderMap,
-            _payload: P,
+            _payload: Value,
             _basic_auth: &Option<BasicAuth>,
-        ) -> MailchimpResult<String>
-        where
-            P: Serialize,
-        {
+        ) -> MailchimpResult<String> {
             Ok(self.resp_for_post.clone())
         }
         ///
@@ -123,8 +125,8 @@ mod tests {
     /// Configura la instancia de API para los diferentes Test que no requieren de
     /// una respuesta
     ///
-    fn setup_test_with_access_token() -> Api<MockRequest> {
-        Api::<MockRequest>::new("us6", "access_token", Box::new(MockRequest::new("", "")))
+    fn setup_test_with_access_token() -> Api {
+        Api::new("us6", "access_token", Arc::new(MockRequest::new("", "")))
     }
 
     #[test]
@@ -142,7 +144,7 @@ mod tests {
     fn build_url_without_http_params() {
         let api = setup_test_with_access_token();
         assert_eq!(
-            api.build_url("lists", &HashMap::new()).as_str(),
+            api.build_url("lists", &HashMap::new()).unwrap().as_str(),
             "https://us6.api.mailchimp.com/3.0/lists"
         )
     }
@@ -152,11 +154,94 @@ mod tests {
         let mut params = HashMap::new();
         params.insert("option1".to_string(), "foo".to_string());
         assert_eq!(
-            api.build_url("campaigns", &params).as_str(),
+            api.build_url("campaigns", &params).unwrap().as_str(),
             "https://us6.api.mailchimp.com/3.0/campaigns?option1=foo"
         )
     }
     #[test]
+    fn build_url_with_custom_base_url() {
+        let api = Api::with_base_url(
+            "http://127.0.0.1:8080",
+            "3.0",
+            "access_token",
+            Arc::new(MockRequest::new("", "")),
+        );
+        assert_eq!(
+            api.build_url("lists", &HashMap::new()).unwrap().as_str(),
+            "http://127.0.0.1:8080/3.0/lists"
+        )
+    }
+    #[test]
+    fn builder_rejects_invalid_config() {
+        let api = crate::MailchimpApi::builder()
+            .api_key("key-us6")
+            .base_url("not a url")
+            .build();
+        match api {
+            Err(MailchimpError::InvalidConfig(_)) => {}
+            other => panic!("Se esperaba un error de configuración: {:?}", other),
+        }
+        for base_url in &["localhost:8080", "mailto:x", "ftp://127.0.0.1/"] {
+            let api = crate::MailchimpApi::builder()
+                .api_key("key-us6")
+                .base_url(*base_url)
+                .build();
+            match api {
+                Err(MailchimpError::InvalidConfig(_)) => {}
+                other => panic!("{} no es una url base: {:?}", base_url, other),
+            }
+        }
+        let api = crate::MailchimpApi::builder()
+            .api_key("key-us6")
+            .add_root_certificate(b"no es un certificado")
+            .build();
+        assert!(api.is_err());
+    }
+    #[test]
+    fn builder_uses_api_key_datacenter() {
+        let api = crate::MailchimpApi::builder()
+            .api_key("key-us19")
+            .proxy("http://127.0.0.1:3128")
+            .build()
+            .unwrap();
+        assert_eq!(api.domain(), "https://us19.api.mailchimp.com/");
+        assert_eq!(api.version(), "3.0");
+    }
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn resources_are_send_and_sync() {
+        assert_send_sync::<crate::MailchimpApi>();
+        assert_send_sync::<crate::Lists>();
+        assert_send_sync::<crate::Campaigns>();
+        assert_send_sync::<crate::Reports>();
+        assert_send_sync::<ListType>();
+        assert_send_sync::<ListMember>();
+        assert_send_sync::<CampaignType>();
+        assert_send_sync::<crate::iter::MalchimpIter<crate::list::ListsBuilder>>();
+        assert_send_sync::<crate::iter::MalchimpIter<ListMembersBuilder>>();
+    }
+    #[test]
+    fn parse_api_key_validates_format() {
+        assert_eq!(
+            parse_api_key("aac1e319006883125e18a89e529b5abb73de4c81-us19").unwrap(),
+            ("aac1e319006883125e18a89e529b5abb73de4c81", "us19")
+        );
+        for key in &["", "   ", "aac1e319", "-us6", "aac1-e319-us6", "aac1e319-usX", "aac1e319-6"] {
+            match parse_api_key(key) {
+                Err(MailchimpError::InvalidApiKey(_)) => {}
+                other => panic!("Se esperaba una API Key no válida para {:?}: {:?}", key, other),
+            }
+        }
+    }
+    #[test]
+    fn try_new_rejects_invalid_api_key() {
+        assert!(crate::MailchimpApi::try_new("aac1e319").is_err());
+        let api = crate::MailchimpApi::try_new("aac1e319-us6").unwrap();
+        assert_eq!(api.domain(), "https://us6.api.mailchimp.com/");
+        assert!(crate::MailchimpApi::builder().api_key("").build().is_err());
+    }
+    #[test]
     fn build_headers_contain_authorization_header() {
         let api = setup_test_with_access_token();
         let headers = api.build_headers();
@@ -170,7 +255,7 @@ mod tests {
             "{ \"apps\": [{\"id\": 2486822,\"name\": \"Mailchimp for Shopify\",\"description\": \"Mailchimp for Shopify is a free application that connects your Shopify store with your Mailchimp account.\",\"users\": [ \"freddiesjokes\"],\"_links\": [ {\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/authorized-apps/2486822\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Instance.json\" }, {\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/authorized-apps\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Collection.json\" }]},{\"id\": 26925,\"name\": \"Integrations Directory Reviews\",\"description\": \"Allow customers to leave feedback on the connect.mailchimp.com site.\",\"users\": [ \"freddiesjokes\"],\"_links\": [ {\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/authorized-apps/26925\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Instance.json\" }, {\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/authorized-apps\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Collection.json\" }]},{\"id\": 27498,\"name\": \"Goooal\",\"description\": \"Segment your Mailchimp list based on subscribers' activity on your website.\",\"users\": [ \"freddiesjokes\"],\"_links\": [ {\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/authorized-apps/27498\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Instance.json\" }, {\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/authorized-apps\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Collection.json\" }]} ], \"total_items\": 3, \"_links\": [{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/authorized-apps\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Collection.json\"},{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Root.json\"},{\"rel\": \"create\",\"href\": \"https://usX.api.mailchimp.com/3.0/authorized-apps\",\"method\": \"POST\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/AuthorizedAppType/ClientAccessTokens.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Client.json\"} ]}",
             "",
         );
-        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
 
         let expected = json!({ "apps": [{"id": 2486822,"name": "Mailchimp for Shopify","description": "Mailchimp for Shopify is a free application that connects your Shopify store with your Mailchimp account.","users": [ "freddiesjokes"],"_links": [ {"rel": "self","href": "https://usX.api.mailchimp.com/3.0/authorized-apps/2486822","method": "GET","targetSchema": "https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Instance.json" }, {"rel": "parent","href": "https://usX.api.mailchimp.com/3.0/authorized-apps","method": "GET","targetSchema": "https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Collection.json" }]},{"id": 26925,"name": "Integrations Directory Reviews","description": "Allow customers to leave feedback on the connect.mailchimp.com site.","users": [ "freddiesjokes"],"_links": [ {"rel": "self","href": "https://usX.api.mailchimp.com/3.0/authorized-apps/26925","method": "GET","targetSchema": "https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Instance.json" }, {"rel": "parent","href": "https://usX.api.mailchimp.com/3.0/authorized-apps","method": "GET","targetSchema": "https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Collection.json" }]},{"id": 27498,"name": "Goooal","description": "Segment your Mailchimp list based on subscribers' activity on your website.","users": [ "freddiesjokes"],"_links": [ {"rel": "self","href": "https://usX.api.mailchimp.com/3.0/authorized-apps/27498","method": "GET","targetSchema": "https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Instance.json" }, {"rel": "parent","href": "https://usX.api.mailchimp.com/3.0/authorized-apps","method": "GET","targetSchema": "https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Collection.json" }]} ], "total_items": 3, "_links": [{"rel": "self","href": "https://usX.api.mailchimp.com/3.0/authorized-apps","method": "GET","targetSchema": "https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Collection.json"},{"rel": "parent","href": "https://usX.api.mailchimp.com/3.0/","method": "GET","targetSchema": "https://api.mailchimp.com/schema/3.0/Root.json"},{"rel": "create","href": "https://usX.api.mailchimp.com/3.0/authorized-apps","method": "POST","targetSchema": "https://api.mailchimp.com/schema/3.0/AuthorizedAppType/ClientAccessTokens.json","schema": "https://api.mailchimp.com/schema/3.0/AuthorizedAppType/Client.json"} ]});
 
@@ -198,7 +283,7 @@ mod tests {
             "{\"id\": \"b0a1c24f1a\",\"create_time\": \"2015-09-15T14:31:54+00:00\",\"start_time\": \"\",\"status\": \"save\",\"emails_sent\": 0,\"recipients\": {\"list_id\": \"1a2df69511\"},\"settings\": {\"title\": \"Freddie's best new jokes\",\"from_name\": \"Freddie\",\"reply_to\": \"freddie@freddiesjokes.com\",\"use_conversation\": false,\"to_name\": \"*|FNAME|*\",\"authenticate\": true,\"auto_footer\": false,\"inline_css\": false},\"tracking\": {\"opens\": true,\"html_clicks\": true,\"text_clicks\": false,\"goal_tracking\": false,\"ecomm360\": true,\"google_analytics\": \"true\",\"clicktale\": \"false\"},\"trigger_settings\": {\"workflow_type\": \"categoryFollowup\",\"send_immediately\": false,\"category_name\": \"Jokes\",\"runtime\": {\"days\": [\"sunday\",\"monday\",\"tuesday\",\"wednesday\",\"thursday\",\"friday\",\"saturday\"],\"hours\": {\"send_asap\": true}},\"workflow_emails_count\": 3},\"_links\": [{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Automations.json\"},{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/Instance.json\"},{\"rel\": \"start-all-emails\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/actions/start-all-emails\",\"method\": \"POST\"},{\"rel\": \"pause-all-emails\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/actions/pause-all-emails\",\"method\": \"POST\"},{\"rel\": \"emails\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/emails\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/Emails/Collection.json\"}]}",
             "",
         );
-        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
 
         let expected = json!({"id": "b0a1c24f1a","create_time": "2015-09-15T14:31:54+00:00","start_time": "","status": "save","emails_sent": 0,"recipients": {"list_id": "1a2df69511"},"settings": {"title": "Freddie's best new jokes","from_name": "Freddie","reply_to": "freddie@freddiesjokes.com","use_conversation": false,"to_name": "*|FNAME|*","authenticate": true,"auto_footer": false,"inline_css": false},"tracking": {"opens": true,"html_clicks": true,"text_clicks": false,"goal_tracking": false,"ecomm360": true,"google_analytics": true,"clicktale": false},"trigger_settings": {"workflow_type": "categoryFollowup","send_immediately": false,"category_name": "Jokes","runtime": {"days": ["sunday","monday","tuesday","wednesday","thursday","friday","saturday"],"hours": {"send_asap": true}},"workflow_emails_count": 3},"_links": [{"rel": "parent","href": "https://usX.api.mailchimp.com/3.0/automations","method": "GET","targetSchema": "https://api.mailchimp.com/schema/3.0/Automations/Collection.json","schema": "https://api.mailchimp.com/schema/3.0/CollectionLinks/Automations.json"},{"rel": "self","href": "https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a","method": "GET","targetSchema": "https://api.mailchimp.com/schema/3.0/Automations/Instance.json"},{"rel": "start-all-emails","href": "https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/actions/start-all-emails","method": "POST"},{"rel": "pause-all-emails","href": "https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/actions/pause-all-emails","method": "POST"},{"rel": "emails","href": "https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/emails","method": "GET","targetSchema": "https://api.mailchimp.com/schema/3.0/Automations/Emails/Collection.json"}]});
 
@@ -218,7 +303,7 @@ mod tests {
             "{\"automations\": [{\"id\": \"b0a1c24f1a\",\"create_time\": \"2015-09-15T14:31:54+00:00\",\"start_time\": \"2015-09-15T15:45:32+00:00\",\"status\": \"paused\",\"emails_sent\": 1,\"recipients\": {\"list_id\": \"57afe96172\"},\"settings\": {\"title\": \"Freddie's Best Jokes\",\"from_name\": \"Freddie\",\"reply_to\": \"freddie@freddiesjokes.com\",\"use_conversation\": false,\"to_name\": \"*|FNAME|*\",\"authenticate\": true,\"auto_footer\": false,\"inline_css\": false},\"tracking\": {\"opens\": true,\"html_clicks\": true,\"text_clicks\": true,\"goal_tracking\": true,\"ecomm360\": true,\"google_analytics\": \"Freddie_s_Best_Jokes9_15_2015\",\"clicktale\": \"\"},\"trigger_settings\": {\"workflow_type\": \"emailSeries\",\"send_immediately\": false,\"trigger_on_import\": false,\"runtime\": {\"days\": [\"sunday\",\"monday\",\"tuesday\",\"wednesday\",\"thursday\",\"friday\",\"saturday\"],\"hours\": {\"send_at\": \"12:00am\"}},\"workflow_emails_count\": 1},\"report_summary\": {\"opens\": 1,\"unique_opens\": 1,\"open_rate\": 1,\"clicks\": 0,\"subscriber_clicks\": 0,\"click_rate\": 0},\"_links\": [{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Automations.json\"},{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/Instance.json\"},{\"rel\": \"start-all-emails\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/actions/start-all-emails\",\"method\": \"POST\"},{\"rel\": \"pause-all-emails\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/actions/pause-all-emails\",\"method\": \"POST\"},{\"rel\": \"emails\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/emails\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/Emails/Collection.json\"},{\"rel\": \"removed-subscribers\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/removed-subscribers\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/RemovedSubscribers/Collection.json\"}]}],\"total_items\": 1,\"_links\": [{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Root.json\"},{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Automations.json\"}]}",
             "",
         );
-        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
         let resp = api
             .get_edge::<CollectionAutomation>("/automations", HashMap::new())
             .unwrap();
@@ -233,7 +318,7 @@ mod tests {
     #[test]
     fn test_automations_pause_all_emails() {
         let mock_transport = MockRequest::new("", "");
-        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
         let resp = api.post_edge::<EmptyType, HashMap<String, String>>(
             "/automations/fd9d304eb7/actions/pause-all-emails",
             HashMap::new(),
@@ -255,7 +340,7 @@ mod tests {
             "{\"account_id\": \"8d3a3db4d97663a9074efcc16\",\"account_name\": \"Freddie's Jokes\",\"email\": \"freddie@mailchimp.com\",\"role\": \"owner\",\"contact\": {\"company\": \"Freddie's Jokes\",\"addr1\": \"675 Ponce De Leon Ave NE\",\"addr2\": \"Suite 5000\",\"city\": \"Atlanta\",\"state\": \"GA\",\"zip\": \"30308\",\"country\": \"US\"},\"last_login\": \"2015-09-15 14:25:37\",\"total_subscribers\": 413,\"_links\": [{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Root.json\"},{\"rel\": \"lists\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists.json\"},{\"rel\": \"reports\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Reports.json\"},{\"rel\": \"conversations\",\"href\": \"https://usX.api.mailchimp.com/3.0/conversations\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Conversations/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Conversations.json\"},{\"rel\": \"campaigns\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Campaigns/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Campaigns.json\"},{\"rel\": \"automations\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Automations.json\"},{\"rel\": \"templates\",\"href\": \"https://usX.api.mailchimp.com/3.0/templates\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Templates/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Templates.json\"},{\"rel\": \"file-manager\",\"href\": \"https://usX.api.mailchimp.com/3.0/file-manager\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/FileManager/Namespace.json\"},{\"rel\": \"authorized-apps\",\"href\": \"https://usX.api.mailchimp.com/3.0/authorized-apps\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/AuthorizedApps/Collection.json\"}]}",
             "",
         );
-        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
         let resp = api.get_edge::<ApiRootType>("", HashMap::new()).unwrap();
 
         assert_eq!(
@@ -270,7 +355,7 @@ mod tests {
             "{\"id\": \"491fec26f1\",\"workflow_id\": \"b0a1c24f1a\",\"position\": 1,\"delay\": {\"amount\": 1,\"type\": \"day\",\"direction\": \"after\",\"action\": \"signup\"},\"create_time\": \"2015-09-15T14:33:20+00:00\",\"start_time\": \"2015-09-15T15:45:32+00:00\",\"archive_url\": \"http://eepurl.com/xxxx\",\"status\": \"paused\",\"emails_sent\": 0,\"send_time\": \"2015-09-15T15:48:05+00:00\",\"content_type\": \"template\",\"recipients\": {\"list_id\": \"57afe96172\"},\"settings\": {\"subject_line\": \"Your first joke from Freddie!\",\"title\": \"Freddie Likes Jokes\",\"from_name\": \"Freddie\",\"reply_to\": \"freddie@freddiesjokes.com\",\"authenticate\": false,\"auto_footer\": false,\"inline_css\": false,\"auto_tweet\": false,\"fb_comments\": true,\"template_id\": 2000020,\"drag_and_drop\": true},\"tracking\": {\"opens\": true,\"html_clicks\": true,\"text_clicks\": true,\"goal_tracking\": true,\"ecomm360\": true,\"google_analytics\": \"true\",\"clicktale\": \"\"},\"report_summary\": {\"opens\": 0,\"unique_opens\": 0,\"open_rate\": 0,\"clicks\": 0,\"subscriber_clicks\": 0,\"click_rate\": 0},\"_links\": [{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/emails\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/Emails/Collection.json\"},{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/emails/491fec26f1\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/Emails/Instance.json\"},{\"rel\": \"start\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/emails/491fec26f1/actions/start\",\"method\": \"POST\"},{\"rel\": \"pause\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/emails/491fec26f1/actions/pause\",\"method\": \"POST\"},{\"rel\": \"queue\",\"href\": \"https://usX.api.mailchimp.com/3.0/automations/b0a1c24f1a/emails/491fec26f1/queue\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Automations/Emails/Queue/Collection.json\"}]}",
             "",
         );
-        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
         let resp = api
             .get_edge::<WorkflowEmailType>("", HashMap::new())
             .unwrap();
@@ -287,7 +372,7 @@ mod tests {
             "{\"lists\": [{\"id\": \"57afe96172\",\"name\": \"Freddie's Jokes\",\"contact\": {\"company\": \"Mailchimp\",\"address1\": \"675 Ponce De Leon Ave NE\",\"address2\": \"Suite 5000\",\"city\": \"Atlanta\",\"state\": \"GA\",\"zip\": \"30308\",\"country\": \"US\",\"phone\": \"\"},\"permission_reminder\": \"You're receiving this email because you just can't get enough of Freddie's jokes.\",\"use_archive_bar\": false,\"campaign_defaults\": {\"from_name\": \"Freddie\",\"from_email\": \"freddie@freddiesjokes.com\",\"subject\": \"\",\"language\": \"en\"},\"notify_on_subscribe\": \"\",\"notify_on_unsubscribe\": \"\",\"date_created\": \"2015-09-15T14:38:16+00:00\",\"list_rating\": 3,\"email_type_option\": false,\"subscribe_url_short\": \"http://eepurl.com/xxxx\",\"subscribe_url_long\": \"http://freddiesjokes.usX.list-manage.com/subscribe?u=8d3a3db4d97663a9074efcc16&id=xxxx\",\"beamer_address\": \"usX-xxxx-xxxx@inbound.mailchimp.com\",\"visibility\": \"prv\",\"modules\": [],\"stats\": {\"member_count\": 203,\"unsubscribe_count\": 0,\"cleaned_count\": 0,\"member_count_since_send\": 0,\"unsubscribe_count_since_send\": 0,\"cleaned_count_since_send\": 0,\"campaign_count\": 3,\"campaign_last_sent\": \"\",\"merge_field_count\": 2,\"avg_sub_rate\": 15,\"avg_unsub_rate\": 0,\"target_sub_rate\": 0,\"open_rate\": 0,\"click_rate\": 0,\"last_sub_date\": \"2015-09-15T17:27:16+00:00\",\"last_unsub_date\": \"\"},\"_links\": [{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists/57afe96172\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/Instance.json\"},{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists.json\"},{\"rel\": \"update\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists/57afe96172\",\"method\": \"PATCH\",\"schema\": \"https://api.mailchimp.com/schema/3.0/Lists/Instance.json\"},{\"rel\": \"delete\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists/57afe96172\",\"method\": \"DELETE\"},{\"rel\": \"abuse-reports\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists/57afe96172/abuse-reports\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/Abuse/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists/Abuse.json\"},{\"rel\": \"activity\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists/57afe96172/activity\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/Activity/Collection.json\"},{\"rel\": \"clients\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists/57afe96172/clients\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/Clients/Collection.json\"},{\"rel\": \"growth-history\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists/57afe96172/growth-history\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/Growth/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists/Growth.json\"},{\"rel\": \"interest-categories\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists/57afe96172/interest-categories\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/InterestCategories/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists/InterestCategories.json\"},{\"rel\": \"members\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/Members/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists/Members.json\"},{\"rel\": \"merge-fields\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists/57afe96172/merge-fields\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/MergeFields/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists/MergeFields.json\"},{\"rel\": \"segments\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists/57afe96172/segments\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/Segments/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists/Segments.json\"}]}],\"_links\": [{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Lists/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists.json\"},{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Root.json\"},{\"rel\": \"create\",\"href\": \"https://usX.api.mailchimp.com/3.0/lists\",\"method\": \"POST\",\"schema\": \"https://api.mailchimp.com/schema/3.0/Lists/Instance.json\"}],\"total_items\": 1}",
             "",
         );
-        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
         let resp = api.get_edge::<ListsType>("lists", HashMap::new()).unwrap();
 
         assert_eq!(
@@ -302,7 +387,7 @@ mod tests {
             "{\"campaigns\": [{\"id\": \"42694e9e57\",\"type\": \"regular\",\"create_time\": \"2015-09-15T14:40:36+00:00\",\"archive_url\": \"http://eepurl.com/xxxx\",\"status\": \"save\",\"emails_sent\": 0,\"send_time\": \"\",\"content_type\": \"template\",\"recipients\": {\"list_id\": \"57afe96172\",\"segment_text\": \"\"},\"settings\": {\"subject_line\": \"I have a rice crispy treat watermelon farm.\",\"title\": \"Freddie's Jokes Vol. 1\",\"from_name\": \"Freddie\",\"reply_to\": \"freddie@freddiesjokes.com\",\"use_conversation\": false,\"to_name\": \"\",\"folder_id\": \"0\",\"authenticate\": true,\"auto_footer\": false,\"inline_css\": false,\"auto_tweet\": false,\"fb_comments\": false,\"timewarp\": false,\"template_id\": 100,\"drag_and_drop\": true},\"tracking\": {\"opens\": true,\"html_clicks\": true,\"text_clicks\": false,\"goal_tracking\": true,\"ecomm360\": true,\"google_analytics\": \"true\", \"clicktale\": \"\"},\"delivery_status\": {\"enabled\": false},\"_links\": [{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Campaigns/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Campaigns.json\"},{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns/42694e9e57\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Campaigns/Instance.json\"},{\"rel\": \"delete\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns/42694e9e57\",\"method\": \"DELETE\"},{\"rel\": \"cancel_send\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns/42694e9e57/actions/cancel-send\",\"method\": \"POST\"},{\"rel\": \"feedback\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns/42694e9e57/feedback\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Campaigns/Feedback/Collection.json\"}]},{\"id\": \"f6276207cc\",\"type\": \"regular\",\"create_time\": \"2015-07-20T15:40:41+00:00\",\"archive_url\": \"http://eepurl.com/xxxx\",\"status\": \"sent\",\"emails_sent\": 1,\"send_time\": \"2015-07-20T15:42:48+00:00\",\"content_type\": \"template\",\"recipients\": {\"list_id\": \"1a2df69511\",\"segment_text\": \"<p class='nomargin'>Subscribers match <strong>any</strong> of the following conditions:</p><ol id='conditions' class='conditions'><li class='mar-lv1 mar-lr0'>Static Segments member is part of <strong>Campaign Pasted Segment - 20 Jul 2015 11:41:09 am</strong></li></ol><span>For a total of <strong>1</strong> emails sent.</span>\",\"segment_opts\": {\"saved_segment_id\": 48501,\"match\": \"any\",\"conditions\": [{\"field\": \"static_segment\",\"op\": \"static_is\",\"value\": 48501}]}},\"settings\": {\"subject_line\": \"Take my poll!\",\"title\": \"Poll test\",\"from_name\": \"Freddie\",\"reply_to\": \"freddie@freddiesjokes.com\",\"use_conversation\": false,\"to_name\": \"*|FNAME|*\",\"folder_id\": \"0\",\"authenticate\": true,\"auto_footer\": false,\"inline_css\": false,\"auto_tweet\": false,\"fb_comments\": false,\"timewarp\": false,\"template_id\": 91,\"drag_and_drop\": true},\"tracking\": {\"opens\": true,\"html_clicks\": true,\"text_clicks\": false,\"goal_tracking\": false,\"ecomm360\": false,\"google_analytics\": \"true\",\"clicktale\": \"\"},\"report_summary\": {\"opens\": 1,\"unique_opens\": 1,\"open_rate\": 1,\"clicks\": 0,\"subscriber_clicks\": 0,\"click_rate\": 0},\"delivery_status\": {\"enabled\": false},\"_links\": [{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Campaigns/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Campaigns.json\"},{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns/f6276207cc\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Campaigns/Instance.json\"},{\"rel\": \"delete\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns/f6276207cc\",\"method\": \"DELETE\"},{\"rel\": \"cancel_send\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns/f6276207cc/actions/cancel-send\",\"method\": \"POST\"},{\"rel\": \"feedback\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns/f6276207cc/feedback\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Campaigns/Feedback/Collection.json\"}]}],\"total_items\": 2,\"_links\": [{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Root.json\"},{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Campaigns/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Campaigns.json\"}]}",
             "",
         );
-        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
         let resp = api
             .get_edge::<CampaignsType>("campaigns", HashMap::new())
             .unwrap();
@@ -319,7 +404,7 @@ mod tests {
             "{\"members\":[{\"id\":\"f777bbffab8d1ceca8b757df63c47cb8\",\"email_address\":\"urist.mcvankab+1@freddiesjokes.co\",\"unique_email_id\":\"882e9bec19\",\"email_type\":\"html\",\"status\":\"subscribed\",\"status_if_new\":\"\",\"merge_fields\":{\"FNAME\":\"\",\"LNAME\":\"\"},\"interests\":{\"9143cf3bd1\":\"true\",\"3a2a927344\":\"true\",\"f9c8f5f0ff\":\"true\",\"f231b09abc\":\"true\",\"bd6e66465f\":\"true\"},\"stats\":{\"avg_open_rate\":1,\"avg_click_rate\":0},\"ip_signup\":\"198.2.191.34\",\"timestamp_signup\":\"2015-09-1517:24:43\",\"ip_opt\":\"66.249.85.180\",\"timestamp_opt\":\"2015-09-1517:27:16\",\"member_rating\":2,\"last_changed\":\"2015-09-1517:27:16\",\"language\":\"en\",\"vip\":false,\"email_client\":\"\",\"location\":{\"latitude\":32.5805,\"longitude\":-97.1389,\"gmtoff\":-6,\"dstoff\":-5,\"country_code\":\"US\",\"timezone\":\"America/Chicago\"},\"list_id\":\"57afe96172\",\"_links\":[{\"rel\":\"self\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Instance.json\"},{\"rel\":\"parent\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Collection.json\",\"schema\":\"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists/Members.json\"},{\"rel\":\"update\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8\",\"method\":\"PATCH\",\"schema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Instance.json\"},{\"rel\":\"upsert\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8\",\"method\":\"PUT\",\"schema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Instance.json\"},{\"rel\":\"delete\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8\",\"method\":\"DELETE\"},{\"rel\":\"activity\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8/activity\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Activity/Collection.json\"},{\"rel\":\"goals\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8/goals\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Goals/Collection.json\"},{\"rel\":\"notes\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8/notes\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Notes/Collection.json\"}]},{\"id\":\"796b7017ce40a94ba27df7a19ff95811\",\"email_address\":\"urist.mcvankab+2@freddiesjokes.com\",\"unique_email_id\":\"083ae0451e\",\"email_type\":\"html\",\"status\":\"subscribed\",\"status_if_new\":\"\",\"merge_fields\":{\"FNAME\":\"\",\"LNAME\":\"\"},\"interests\":{\"9143cf3bd1\":\"true\",\"3a2a927344\":\"false\",\"f9c8f5f0ff\":\"false\",\"f231b09abc\":\"true\",\"bd6e66465f\":\"false\"},\"stats\":{\"avg_open_rate\":1,\"avg_click_rate\":0},\"ip_signup\":\"\",\"timestamp_signup\":\"\",\"ip_opt\":\"198.2.191.34\",\"timestamp_opt\":\"2015-09-1515:37:03\",\"member_rating\":3,\"last_changed\":\"2015-09-1515:37:03\",\"language\":\"\",\"vip\":false,\"email_client\":\"\",\"location\":{\"latitude\":0,\"longitude\":0,\"gmtoff\":0,\"dstoff\":0,\"country_code\":\"\",\"timezone\":\"\"},\"list_id\":\"57afe96172\",\"_links\":[{\"rel\":\"self\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Instance.json\"},{\"rel\":\"parent\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Collection.json\",\"schema\":\"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists/Members.json\"},{\"rel\":\"update\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8\",\"method\":\"PATCH\",\"schema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Instance.json\"},{\"rel\":\"upsert\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8\",\"method\":\"PUT\",\"schema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Instance.json\"},{\"rel\":\"delete\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8\",\"method\":\"DELETE\"},{\"rel\":\"activity\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8/activity\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Activity/Collection.json\"},{\"rel\":\"goals\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8/goals\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Goals/Collection.json\"},{\"rel\":\"notes\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/f777bbffab8d1ceca8b757df63c47cb8/notes\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Notes/Collection.json\"}]},{\"id\":\"62eeb292278cc15f5817cb78f7790b08\",\"email_address\":\"urist.mcvankab@freddiesjokes.com\",\"unique_email_id\":\"6ad2993d47\",\"email_type\":\"html\",\"status\":\"subscribed\",\"status_if_new\":\"\",\"merge_fields\":{\"FNAME\":\"Urist\",\"LNAME\":\"McVankab\"},\"interests\":{\"9143cf3bd1\":\"true\",\"3a2a927344\":\"false\",\"f9c8f5f0ff\":\"false\",\"f231b09abc\":\"true\",\"bd6e66465f\":\"false\"},\"stats\":{\"avg_open_rate\":0,\"avg_click_rate\":0},\"ip_signup\":\"\",\"timestamp_signup\":\"\",\"ip_opt\":\"198.2.191.34\",\"timestamp_opt\":\"2015-09-1514:40:01\",\"member_rating\":2,\"last_changed\":\"2015-09-1514:40:01\",\"language\":\"\",\"vip\":true,\"email_client\":\"\",\"location\":{\"latitude\":0,\"longitude\":0,\"gmtoff\":0,\"dstoff\":0,\"country_code\":\"\",\"timezone\":\"\"},\"last_note\":{\"note_id\":10505,\"created_at\":\"2015-09-1514:44:14\",\"created_by\":\"2945082\",\"note\":\"Urist'sfavoriteFreddiejoketodateis'\"},\"list_id\":\"57afe96172\",\"_links\":[{\"rel\":\"self\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Instance.json\"},{\"rel\":\"parent\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Collection.json\",\"schema\":\"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists/Members.json\"},{\"rel\":\"update\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08\",\"method\":\"PATCH\",\"schema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Instance.json\"},{\"rel\":\"upsert\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08\",\"method\":\"PUT\",\"schema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Instance.json\"},{\"rel\":\"delete\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08\",\"method\":\"DELETE\"},{\"rel\":\"activity\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08/activity\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Activity/Collection.json\"},{\"rel\":\"goals\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08/goals\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Goals/Collection.json\"},{\"rel\":\"notes\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08/notes\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Notes/Collection.json\"}]}],\"list_id\":\"57afe96172\",\"_links\":[{\"rel\":\"self\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Collection.json\",\"schema\":\"https://api.mailchimp.com/schema/3.0/CollectionLinks/Lists/Members.json\"},{\"rel\":\"parent\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Instance.json\"},{\"rel\":\"create\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members\",\"method\":\"POST\",\"schema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Instance.json\"}],\"total_items\":204}",
             "",
         );
-        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
         let resp = api
             .get_edge::<CollectionListMembers>("/lists/{list_id}/members", HashMap::new())
             .unwrap();
@@ -336,7 +421,7 @@ mod tests {
             "{\"activity\":[{\"action\":\"open\",\"timestamp\":\"2015-09-15T19:15:47+00:00\",\"campaign_id\":\"42694e9e57\",\"title\":\"Freddie'sJokesVol.1\"},{\"action\":\"sent\",\"timestamp\":\"2015-09-15T19:05:51+00:00\",\"type\":\"regular\",\"campaign_id\":\"42694e9e57\",\"title\":\"Freddie'sJokesVol.1\"},{\"action\":\"mandrill_send\",\"timestamp\":\"2015-09-02T17:16:41+00:00\",\"campaign_id\":\"\",\"title\":\"Freddie'sJokesVol.3\"},{\"action\":\"mandrill_open\",\"timestamp\":\"2015-07-13T18:14:09+00:00\",\"campaign_id\":\"\",\"title\":\"Freddie'sJokesVol.3\"}],\"email_id\":\"62eeb292278cc15f5817cb78f7790b08\",\"list_id\":\"57afe96172\",\"_links\":[{\"rel\":\"self\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08/activity\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Activity/Collection.json\"},{\"rel\":\"parent\",\"href\":\"https://usX.api.mailchimp.com/3.0/lists/57afe96172/members/62eeb292278cc15f5817cb78f7790b08\",\"method\":\"GET\",\"targetSchema\":\"https://api.mailchimp.com/schema/3.0/Lists/Members/Instance.json\"}],\"total_items\":4}",
             "",
         );
-        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
         let resp = api
             .get_edge::<CollectionListMemberActivity>(
                 "/lists/{list_id}/members/{subscriber_hash}/activity",
@@ -356,7 +441,7 @@ mod tests {
             "{\"reports\": [{\"id\": \"42694e9e57\",\"campaign_title\": \"Freddie's Jokes Vol. 1\",\"type\": \"regular\",\"emails_sent\": 200,\"abuse_reports\": 0,\"unsubscribed\": 2,\"send_time\": \"2015-09-15T19:05:51+00:00\",\"bounces\": {\"hard_bounces\": 0,\"soft_bounces\": 2,\"syntax_errors\": 0},\"forwards\": {\"forwards_count\": 0,\"forwards_opens\": 0},\"opens\": {\"opens_total\": 186,\"unique_opens\": 100,\"open_rate\": 42,\"last_open\": \"2015-09-15T19:15:47+00:00\"},\"clicks\": {\"clicks_total\": 42,\"unique_clicks\": 400,\"unique_subscriber_clicks\": 42,\"click_rate\": 42,\"last_click\": \"2015-09-15T19:15:47+00:00\"},\"facebook_likes\": {\"recipient_likes\": 5,\"unique_likes\": 8,\"facebook_likes\": 42},\"industry_stats\": {\"type\": \"Social Networks and Online Communities\",\"open_rate\": 0.17076777144396,\"click_rate\": 0.027431311866951,\"bounce_rate\": 0.0063767751251474,\"unopen_rate\": 0.82285545343089,\"unsub_rate\": 0.001436957032815,\"abuse_rate\": 0.00021111996110887},\"list_stats\": {\"sub_rate\": 10,\"unsub_rate\": 20,\"open_rate\": 42,\"click_rate\": 42},\"timeseries\": [{\"timestamp\": \"2015-09-15T19:00:00+00:00\",\"emails_sent\": 198,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-15T20:00:00+00:00\",\"emails_sent\": 2,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-15T21:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-15T22:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-15T23:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T00:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T01:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T02:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T03:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T04:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T05:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T06:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T07:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T08:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T09:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T10:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T11:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T12:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T13:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T14:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T15:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T16:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T17:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0},{\"timestamp\": \"2015-09-16T18:00:00+00:00\",\"emails_sent\": 0,\"unique_opens\": 0,\"recipients_clicks\": 0}],\"share_report\": {\"share_url\": \"http://usX.vip-reports.net/reports/summary?u=xxxx&id=xxxx\",\"share_password\": \"freddielikesjokes\"},\"delivery_status\": {\"enabled\": false},\"_links\": [{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Reports.json\"},{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports/42694e9e57\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/Instance.json\"},{\"rel\": \"campaign\",\"href\": \"https://usX.api.mailchimp.com/3.0/campaigns/42694e9e57\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Campaigns/Instance.json\"},{\"rel\": \"sub-reports\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports/42694e9e57/sub-reports\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/Sub/Collection.json\"},{\"rel\": \"abuse-reports\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports/42694e9e57/abuse-reports\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/Abuse/Collection.json\"},{\"rel\": \"advice\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports/42694e9e57/advice\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/Advice/Collection.json\"},{\"rel\": \"click-details\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports/42694e9e57/click-details\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/ClickDetails/Collection.json\"},{\"rel\": \"domain-performance\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports/42694e9e57/domain-performance\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/DomainPerformance/Collection.json\"},{\"rel\": \"eepurl\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports/42694e9e57/eepurl\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/Eepurl/Collection.json\"},{\"rel\": \"email-activity\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports/42694e9e57/email-activity\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/EmailActivity/Collection.json\"},{\"rel\": \"locations\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports/42694e9e57/locations\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/Locations/Collection.json\"},{\"rel\": \"sent-to\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports/42694e9e57/sent-to\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/SentTo/Collection.json\"},{\"rel\": \"unsubscribed\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports/42694e9e57/unsubscribed\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/Unsubs/Collection.json\"}]}],\"_links\": [{\"rel\": \"parent\",\"href\": \"https://usX.api.mailchimp.com/3.0/\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Root.json\"},{\"rel\": \"self\",\"href\": \"https://usX.api.mailchimp.com/3.0/reports\",\"method\": \"GET\",\"targetSchema\": \"https://api.mailchimp.com/schema/3.0/Reports/Collection.json\",\"schema\": \"https://api.mailchimp.com/schema/3.0/CollectionLinks/Reports.json\"}],\"total_items\": 1}",
             "",
         );
-        let api = Api::<MockRequest>::new("us6", "access_token", Box::new(mock_transport));
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
         let resp = api
             .get_edge::<CollectionReports>("/reports", HashMap::new())
             .unwrap();
@@ -368,4 +453,1780 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_check_response_with_problem_detail() {
+        let body = "{\"type\": \"https://mailchimp.com/developer/marketing/docs/errors/\",\"title\": \"Resource Not Found\",\"status\": 404,\"detail\": \"The requested resource could not be found.\",\"instance\": \"995c5cb0-3280-4a6e-808b-3b096d0bb219\"}";
+        let resp = check_response(StatusCode::NOT_FOUND, body.to_string());
+
+        match resp {
+            Err(MailchimpError::Api(e)) => assert_eq!(e.title, "Resource Not Found"),
+            other => panic!("Se esperaba un error del API: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_check_response_without_problem_detail() {
+        let resp = check_response(StatusCode::BAD_GATEWAY, "<html>Bad Gateway</html>".to_string());
+
+        match resp {
+            Err(e @ MailchimpError::Http { .. }) => {
+                assert_eq!(e.status(), Some(502));
+                assert!(!e.is_not_found());
+            }
+            other => panic!("Se esperaba un error HTTP: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_check_response_accepts_no_content() {
+        let resp = check_response(StatusCode::NO_CONTENT, "".to_string());
+        assert_eq!(resp.unwrap(), "");
+    }
+
+    #[test]
+    fn test_decode_error_keeps_body_and_endpoint() {
+        let mock_transport = MockRequest::new("{\"apps\": 3}", "");
+        let api = Api::new("us6", "access_token", Arc::new(mock_transport));
+        let resp = api.get_edge::<AuthorizedAppsType>("authorized-apps", HashMap::new());
+
+        match resp {
+            Err(MailchimpError::Deserialize { endpoint, body, .. }) => {
+                assert_eq!(endpoint, "authorized-apps");
+                assert_eq!(body, "{\"apps\": 3}");
+            }
+            other => panic!("Se esperaba un error de deserialización: {:?}", other),
+        }
+    }
+
+    ///
+    /// Transporte que guarda el cuerpo de la última petición
+    ///
+    #[derive(Default)]
+    struct RecordingRequest {
+        last_payload: Mutex<Option<Value>>,
+    }
+
+    impl HttpReq for RecordingRequest {
+        fn get(
+            &self,
+            _url: Url,
+            _headers: HeaderMap,
+            _basic_auth: &Option<BasicAuth>,
+        ) -> MailchimpResult<String> {
+            Ok("{\"health_status\": \"Everything's Chimpy!\"}".to_string())
+        }
+        fn post(
+            &self,
+            _url: Url,
+            _headers: HeaderMap,
+            payload: Value,
+            _basic_auth: &Option<BasicAuth>,
+        ) -> MailchimpResult<String> {
+            *self.last_payload.lock().unwrap() = Some(payload);
+            Ok("{}".to_string())
+        }
+        fn patch(
+            &self,
+            url: Url,
+            headers: HeaderMap,
+            payload: Value,
+            basic_auth: &Option<BasicAuth>,
+        ) -> MailchimpResult<String> {
+            self.post(url, headers, payload, basic_auth)
+        }
+        fn put(
+            &self,
+            url: Url,
+            headers: HeaderMap,
+            payload: Value,
+            basic_auth: &Option<BasicAuth>,
+        ) -> MailchimpResult<String> {
+            self.post(url, headers, payload, basic_auth)
+        }
+        fn delete(
+            &self,
+            _url: Url,
+            _headers: HeaderMap,
+            _basic_auth: &Option<BasicAuth>,
+        ) -> MailchimpResult<String> {
+            Ok("".to_string())
+        }
+    }
+
+    #[test]
+    fn test_custom_transport_receives_encoded_payload() {
+        let transport = Arc::new(RecordingRequest::default());
+        let api = Api::new("us6", "access_token", transport.clone());
+        let mut payload = HashMap::new();
+        payload.insert("name", "Freddie");
+        let resp = api.post_edge::<EmptyType, _>("lists", payload);
+
+        assert!(resp.is_ok(), "{:?}", resp);
+        assert_eq!(
+            *transport.last_payload.lock().unwrap(),
+            Some(json!({"name": "Freddie"}))
+        );
+    }
+
+    #[test]
+    fn test_mailchimp_api_with_transport() {
+        let api = crate::MailchimpApi::with_transport("key-us6", RecordingRequest::default());
+        assert_eq!(api.domain(), "https://us6.api.mailchimp.com/");
+        assert!(api.ping().is_ok());
+    }
+
+    #[test]
+    fn build_headers_with_bearer_token() {
+        let mut api = setup_test_with_access_token();
+        api.set_bearer_token("oauth-token").unwrap();
+        let headers = api.build_headers();
+        assert_eq!(headers.get(AUTHORIZATION).unwrap(), "Bearer oauth-token");
+    }
+
+    #[test]
+    fn invalid_bearer_token_is_rejected() {
+        let mut api = setup_test_with_access_token();
+        match api.set_bearer_token("oauth\ntoken") {
+            Err(MailchimpError::InvalidApiKey(_)) => {}
+            other => panic!("Se esperaba un token no válido: {:?}", other),
+        }
+        assert!(crate::MailchimpApi::with_oauth_token("oauth\ntoken", "us6").is_err());
+        let api = crate::MailchimpApi::builder()
+            .oauth_token("oauth\ntoken")
+            .datacenter("us6")
+            .build();
+        match api {
+            Err(MailchimpError::InvalidApiKey(_)) => {}
+            other => panic!("Se esperaba un token no válido: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn builder_requires_datacenter_with_oauth_token() {
+        let api = crate::MailchimpApi::builder().oauth_token("token").build();
+        assert!(api.is_err());
+        let api = crate::MailchimpApi::builder()
+            .oauth_token("token")
+            .datacenter("us19")
+            .build()
+            .unwrap();
+        assert_eq!(api.domain(), "https://us19.api.mailchimp.com/");
+    }
+
+    ///
+    /// Servidor local que contesta las peticiones con las respuestas dadas y
+    /// devuelve las peticiones recibidas
+    ///
+    fn serve(responses: Vec<&'static str>) -> (String, thread::JoinHandle<Vec<String>>) {
+        serve_status(responses.into_iter().map(|body| (200, "", body)).collect())
+    }
+
+    ///
+    /// Igual que `serve`, pero cada respuesta indica el estado HTTP y los
+    /// headers adicionales, p.ej. `"Retry-After: 0\r\n"`
+    ///
+    fn serve_status(
+        responses: Vec<(u16, &'static str, &'static str)>,
+    ) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, headers, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut data = Vec::new();
+                let mut buf = [0; 4096];
+                loop {
+                    let n = stream.read(&mut buf).unwrap();
+                    data.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&data).to_string();
+                    if let Some(end) = text.find("\r\n\r\n") {
+                        let length = text
+                            .lines()
+                            .find(|l| l.to_lowercase().starts_with("content-length:"))
+                            .and_then(|l| l[15..].trim().parse::<usize>().ok())
+                            .unwrap_or(0);
+                        if data.len() >= end + 4 + length {
+                            requests.push(text);
+                            break;
+                        }
+                    }
+                }
+                let reason = StatusCode::from_u16(status)
+                    .ok()
+                    .and_then(|s| s.canonical_reason())
+                    .unwrap_or("");
+                write!(
+                    stream,
+                    "HTTP/1.1 {} {}\r\n{}Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    reason,
+                    headers,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn oauth_client_connects_with_metadata_datacenter() {
+        let (url, server) = serve(vec![
+            "{\"access_token\": \"abc123\", \"expires_in\": 0, \"scope\": null}",
+            "{\"dc\": \"us19\", \"accountname\": \"Freddie's Jokes\", \"api_endpoint\": \"https://us19.api.mailchimp.com\"}",
+        ]);
+        let oauth = crate::OAuthClient::new("client", "secret", "https://example.com/cb")
+            .with_login_url(&url);
+
+        assert!(oauth
+            .authorize_url(Some("xyz"))
+            .unwrap()
+            .as_str()
+            .starts_with(&format!("{}oauth2/authorize?response_type=code&client_id=client", url)));
+
+        let api = oauth.connect("the-code").unwrap();
+        assert_eq!(api.domain(), "https://us19.api.mailchimp.com/");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /oauth2/token"));
+        assert!(requests[0].contains("grant_type=authorization_code"));
+        assert!(requests[0].contains("code=the-code"));
+        assert!(requests[1].starts_with("GET /oauth2/metadata"));
+        assert!(requests[1].to_lowercase().contains("authorization: oauth abc123"));
+    }
+
+    #[test]
+    fn oauth_client_rejects_an_invalid_login_url() {
+        let oauth = crate::OAuthClient::new("client", "secret", "https://example.com/cb")
+            .with_login_url("not a url");
+        match oauth.authorize_url(None) {
+            Err(MailchimpError::InvalidConfig(_)) => {}
+            other => panic!("{:?}", other),
+        }
+        match oauth.connect("the-code") {
+            Err(MailchimpError::InvalidConfig(_)) => {}
+            other => panic!("{:?}", other.map(|api| api.domain())),
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_backoff_is_capped() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(350),
+            jitter: false,
+            ..RetryPolicy::default()
+        };
+        assert_eq!(policy.delay(0, None), Duration::from_millis(100));
+        assert_eq!(policy.delay(1, None), Duration::from_millis(200));
+        assert_eq!(policy.delay(2, None), Duration::from_millis(350));
+        assert_eq!(policy.delay(40, None), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_retry_policy_jitter_stays_in_range() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(1000),
+            ..RetryPolicy::default()
+        };
+        for _ in 0..20 {
+            let delay = policy.delay(0, None);
+            assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_millis(1000));
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_honors_retry_after() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "7".parse().unwrap());
+        let policy = RetryPolicy::default();
+        assert_eq!(
+            policy.delay(0, retry_after(&headers)),
+            Duration::from_secs(7)
+        );
+    }
+
+    #[test]
+    fn test_retry_policy_retry_after_is_capped() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "86400".parse().unwrap());
+        let policy = RetryPolicy {
+            max_delay: Duration::from_secs(30),
+            ..RetryPolicy::default()
+        };
+        assert_eq!(
+            policy.delay(0, retry_after(&headers)),
+            Duration::from_secs(30)
+        );
+    }
+
+    #[test]
+    fn test_retry_policy_only_idempotent_methods() {
+        let policy = RetryPolicy::default();
+        let error = StatusCode::INTERNAL_SERVER_ERROR;
+        assert!(policy.should_retry_status(&Method::GET, error, 0));
+        assert!(policy.should_retry_status(&Method::PUT, error, 0));
+        assert!(!policy.should_retry_status(&Method::POST, error, 0));
+        assert!(!policy.should_retry_status(&Method::PATCH, error, 0));
+        // Las peticiones limitadas no se procesaron, se pueden repetir
+        assert!(policy.should_retry_status(&Method::POST, StatusCode::TOO_MANY_REQUESTS, 0));
+        assert!(!policy.should_retry_status(&Method::GET, StatusCode::NOT_FOUND, 0));
+        assert!(!policy.should_retry_status(&Method::GET, error, 3));
+        assert!(!RetryPolicy::none().should_retry_status(&Method::GET, error, 0));
+    }
+
+    ///
+    /// API que envía las peticiones al servidor local con la política indicada
+    ///
+    fn retry_api(url: &str, policy: RetryPolicy) -> Api {
+        let transport = super::request::MailchimpRequest::with_retry_policy(policy);
+        Api::with_base_url(url, "3.0", "access_token", Arc::new(transport))
+    }
+
+    #[test]
+    fn test_retry_after_rate_limit_then_success() {
+        let (url, server) = serve_status(vec![
+            (429, "Retry-After: 0\r\n", "{}"),
+            (200, "", "{\"total_items\": 1}"),
+        ]);
+        let api = retry_api(&url, RetryPolicy::default());
+        let resp = api.get_edge::<Value>("lists", HashMap::new());
+
+        assert_eq!(resp.unwrap()["total_items"], 1);
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_retry_skips_server_errors_on_post() {
+        let (url, server) = serve_status(vec![(503, "", "Service Unavailable")]);
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        };
+        let api = retry_api(&url, policy);
+        let resp = api.post_edge::<Value, _>("lists", json!({"name": "Freddie"}));
+
+        // Un reintento no encontraría el servidor y fallaría como Transport
+        assert_eq!(resp.unwrap_err().status(), Some(503));
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_retry_policy_none_does_not_retry() {
+        let (url, server) = serve_status(vec![(429, "Retry-After: 0\r\n", "Too Many Requests")]);
+        let api = retry_api(&url, RetryPolicy::none());
+        let resp = api.get_edge::<Value>("lists", HashMap::new());
+
+        assert_eq!(resp.unwrap_err().status(), Some(429));
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_async_retry_after_rate_limit_then_success() {
+        use super::async_api::AsyncApi;
+        use super::async_request::MailchimpAsyncRequest;
+
+        let (url, server) = serve_status(vec![
+            (429, "Retry-After: 0\r\n", "{}"),
+            (200, "", "{\"total_items\": 1}"),
+        ]);
+        let transport = MailchimpAsyncRequest::with_retry_policy(RetryPolicy::default());
+        let api = AsyncApi::with_base_url(&url, "3.0", "access_token", Arc::new(transport));
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let resp = runtime.block_on(api.get_edge::<Value>("lists", HashMap::new()));
+
+        assert_eq!(resp.unwrap()["total_items"], 1);
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    ///
+    /// Archivo tar.gz con los resultados de un batch, como el que devuelve Mailchimp
+    ///
+    pub(super) fn batch_archive(files: &[(&str, &str)]) -> Vec<u8> {
+        let encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        let mut archive = tar::Builder::new(encoder);
+        for (name, body) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(body.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            archive.append_data(&mut header, name, body.as_bytes()).unwrap();
+        }
+        archive.into_inner().unwrap().finish().unwrap()
+    }
+
+    #[test]
+    fn test_parse_batch_results() {
+        let archive = batch_archive(&[
+            (
+                "a1b2c3/1.json",
+                r#"[{"status_code": 200, "operation_id": "freddie", "response": "{\"id\": \"62eeb292\"}"}]"#,
+            ),
+            (
+                "a1b2c3/2.json",
+                r#"[{"status_code": 400, "operation_id": "invalid", "response": "{\"type\": \"\", \"title\": \"Invalid Resource\", \"status\": 400, \"detail\": \"\", \"instance\": \"\"}"}]"#,
+            ),
+            ("a1b2c3/README", "ignorado"),
+        ]);
+        let results = crate::parse_batch_results(archive.as_slice()).unwrap();
+        assert_eq!(results.len(), 2);
+
+        let member = results[0].response_as::<ListMember>().unwrap();
+        assert_eq!(member.id, "62eeb292");
+        assert_eq!(results[1].operation_id, Some("invalid".to_string()));
+        let err = results[1].response_as::<ListMember>().unwrap_err();
+        assert_eq!(err.api_error().unwrap().title, "Invalid Resource");
+
+        match crate::parse_batch_results(&b"no es un archivo"[..]) {
+            Err(MailchimpError::Io(_)) => {}
+            other => panic!("Se esperaba un error de lectura: {:?}", other),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use async_trait::async_trait;
+    use futures::executor::block_on;
+    use reqwest::header::HeaderMap;
+    use reqwest::Url;
+    use serde_json::Value;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use super::async_api::AsyncApi;
+    use super::async_request::AsyncHttpReq;
+    use super::request::{BasicAuth, MailchimpResult};
+    use crate::types::*;
+
+    ///
+    /// Pruebas de respuestas para el API asíncrono
+    ///
+    struct MockAsyncRequest {
+        resp_for_get: String,
+        resp_for_post: String,
+    }
+
+    impl MockAsyncRequest {
+        pub fn new(resp_for_get: &str, resp_for_post: &str) -> Self {
+            MockAsyncRequest {
+                resp_for_get: resp_for_get.to_string(),
+                resp_for_post: resp_for_post.to_string(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl AsyncHttpReq for MockAsyncRequest {
+        async fn get(
+            &self,
+            _url: Url,
+            _headers: HeaderMap,
+            _basic_auth: &Option<BasicAuth>,
+        ) -> MailchimpResult<String> {
+            Ok(self.resp_for_get.clone())
+        }
+        async fn post(
+            &self,
+            _url: Url,
+            _headers: HeaderMap,
+            _payload: Value,
+            _basic_auth: &Option<BasicAuth>,
+        ) -> MailchimpResult<String> {
+            Ok(self.resp_for_post.clone())
+        }
+        async fn patch(
+            &self,
+            _url: Url,
+            _headers: HeaderMap,
+            _payload: Value,
+            _basic_auth: &Option<BasicAuth>,
+        ) -> MailchimpResult<String> {
+            Ok(self.resp_for_post.clone())
+        }
+        async fn put(
+            &self,
+            _url: Url,
+            _headers: HeaderMap,
+            _payload: Value,
+            _basic_auth: &Option<BasicAuth>,
+        ) -> MailchimpResult<String> {
+            Ok(self.resp_for_post.clone())
+        }
+        async fn delete(
+            &self,
+            _url: Url,
+            _headers: HeaderMap,
+            _basic_auth: &Option<BasicAuth>,
+        ) -> MailchimpResult<String> {
+            Ok(self.resp_for_get.clone())
+        }
+    }
+
+    #[test]
+    fn async_build_url_with_http_params() {
+        let api = AsyncApi::new("us6", "access_token", Arc::new(MockAsyncRequest::new("", "")));
+        let mut params = HashMap::new();
+        params.insert("option1".to_string(), "foo".to_string());
+        assert_eq!(
+            api.build_url("campaigns", &params).unwrap().as_str(),
+            "https://us6.api.mailchimp.com/3.0/campaigns?option1=foo"
+        )
+    }
+
+    #[test]
+    fn async_test_api_root() {
+        let mock_transport = MockAsyncRequest::new(
+            "{\"account_id\": \"8d3a3db4d97663a9074efcc16\",\"account_name\": \"Freddie's Jokes\"}",
+            "",
+        );
+        let api = AsyncApi::new("us6", "access_token", Arc::new(mock_transport));
+        let resp = block_on(api.get_edge::<ApiRootType>("", HashMap::new())).unwrap();
+
+        assert_eq!(resp.account_id, "8d3a3db4d97663a9074efcc16");
+    }
+
+    #[test]
+    fn async_test_post_with_empty_response() {
+        let api = AsyncApi::new("us6", "access_token", Arc::new(MockAsyncRequest::new("", "")));
+        let resp = block_on(api.post_edge::<EmptyType, HashMap<String, String>>(
+            "automations/fd9d304eb7/actions/pause-all-emails",
+            HashMap::new(),
+        ));
+
+        assert!(resp.is_ok(), "{:?}", resp);
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod testing_tests {
+    use crate::testing::{FakeTransport, Method, Route};
+    use crate::types::*;
+    use crate::{MailchimpApi, MailchimpError};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn fake_transport_matches_query_and_last_route() {
+        let fake = FakeTransport::new();
+        fake.route(Route::get("lists").respond("{\"total_items\": 1}"));
+        fake.route(Route::get("lists").query("offset", "50").respond("{\"total_items\": 2}"));
+        let api = MailchimpApi::with_transport("key-us6", fake.clone());
+
+        let mut params = HashMap::new();
+        params.insert("offset".to_string(), "50".to_string());
+        let resp = api.get::<ListsType>("lists", params).unwrap();
+        assert_eq!(resp.total_items, 2);
+        let resp = api.get::<ListsType>("lists", HashMap::new()).unwrap();
+        assert_eq!(resp.total_items, 1);
+        assert_eq!(fake.requests_to(Method::GET, "lists").len(), 2);
+    }
+
+    #[test]
+    fn fake_transport_records_payloads() {
+        let fake = FakeTransport::new();
+        fake.route(Route::post("lists/1/members"));
+        let api = MailchimpApi::with_transport("key-us6", fake.clone());
+
+        let mut payload = HashMap::new();
+        payload.insert("email_address", "freddie@example.com");
+        api.post::<EmptyType, _>("lists/1/members", payload).unwrap();
+
+        let request = fake.assert_called(Method::POST, "lists/1/members");
+        assert_eq!(
+            request.payload,
+            Some(json!({"email_address": "freddie@example.com"}))
+        );
+    }
+
+    #[test]
+    fn fake_transport_simulates_errors() {
+        let fake = FakeTransport::new();
+        fake.route(Route::get("ping").fail_timeout().times(1));
+        let api = MailchimpApi::with_transport("key-us6", fake.clone());
+
+        match api.ping() {
+            Err(MailchimpError::Timeout(_)) => {}
+            other => panic!("Se esperaba un timeout: {:?}", other),
+        }
+        // La ruta se elimina tras la primera respuesta
+        assert!(api.ping().unwrap_err().is_not_found());
+
+        fake.route(Route::get("ping").respond_status(
+            401,
+            "{\"type\": \"\", \"title\": \"API Key Invalid\", \"status\": 401, \"detail\": \"\", \"instance\": \"\"}",
+        ));
+        let err = api.ping().unwrap_err();
+        assert_eq!(err.api_error().unwrap().title, "API Key Invalid");
+    }
+
+    #[test]
+    fn batches_submit_poll_and_download() {
+        use crate::Batches;
+        use std::time::Duration;
+
+        let url = "https://batches.example.com/a1b2c3-response.tar.gz";
+        let archive = super::tests::batch_archive(&[(
+            "a1b2c3.json",
+            r#"[{"status_code": 200, "operation_id": "freddie", "response": "{}"}]"#,
+        )]);
+        let fake = FakeTransport::new();
+        fake.route(Route::post("batches").respond(r#"{"id": "a1b2c3", "status": "pending"}"#));
+        fake.route(Route::get("batches/a1b2c3").respond_json(&json!({
+            "id": "a1b2c3",
+            "status": "finished",
+            "total_operations": 1,
+            "finished_operations": 1,
+            "response_body_url": url
+        })));
+        fake.route(
+            Route::get("batches/a1b2c3")
+                .respond(r#"{"id": "a1b2c3", "status": "started"}"#)
+                .times(1),
+        );
+        fake.route(Route::get("a1b2c3-response.tar.gz").respond_bytes(&archive));
+        let batches = Batches::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+
+        let param = ListMemberTagType {
+            name: "VIP".to_string(),
+            status: "active".to_string(),
+        };
+        let op = BatchOperation::update_member_tags("57afe96172", "62eeb292", vec![param])
+            .unwrap()
+            .with_operation_id("freddie");
+        let batch = batches.submit(vec![op]).unwrap();
+        assert_eq!(
+            fake.assert_called(Method::POST, "batches").payload,
+            Some(json!({"operations": [{
+                "method": "POST",
+                "path": "/lists/57afe96172/members/62eeb292/tags",
+                "body": "{\"tags\":[{\"name\":\"VIP\",\"status\":\"active\"}]}",
+                "operation_id": "freddie"
+            }]}))
+        );
+        match batches.get_results(&batch) {
+            Err(MailchimpError::BatchNotFinished { batch_id, status }) => {
+                assert_eq!(batch_id, "a1b2c3");
+                assert_eq!(status, "pending");
+            }
+            other => panic!("El batch no ha terminado: {:?}", other),
+        }
+
+        let batch = batches
+            .wait_for(&batch.id, Duration::from_millis(1), Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(fake.requests_to(Method::GET, "batches/a1b2c3").len(), 2);
+        let results = batches.get_results_by_id(&batch).unwrap();
+        assert_eq!(results["freddie"].status_code, 200);
+        assert!(results["freddie"].is_success());
+    }
+
+    #[test]
+    fn batches_wait_for_returns_the_last_status_at_the_deadline() {
+        use crate::Batches;
+        use std::time::Duration;
+
+        let fake = FakeTransport::new();
+        fake.route(Route::get("batches/a1b2c3").respond(r#"{"id": "a1b2c3", "status": "started"}"#));
+        let batches = Batches::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+
+        match batches.wait_for("a1b2c3", Duration::from_millis(1), Duration::from_millis(0)) {
+            Err(MailchimpError::BatchNotFinished { batch_id, status }) => {
+                assert_eq!(batch_id, "a1b2c3");
+                assert_eq!(status, "started");
+            }
+            other => panic!("El batch no ha terminado: {:?}", other),
+        }
+        assert_eq!(fake.requests_to(Method::GET, "batches/a1b2c3").len(), 1);
+    }
+
+    #[test]
+    fn try_iter_surfaces_and_resumes_page_errors() {
+        use crate::{ListFilter, Lists};
+
+        let fake = FakeTransport::new();
+        fake.route(Route::get("lists").query("offset", "0").respond_json(&json!({
+            "lists": [{"id": "1"}, {"id": "2"}],
+            "total_items": 3
+        })));
+        fake.route(Route::get("lists").query("offset", "2").respond_json(&json!({
+            "lists": [{"id": "3"}],
+            "total_items": 3
+        })));
+        fake.route(Route::get("lists").query("offset", "2").fail_timeout().times(1));
+        fake.route(Route::get("lists").respond_status(500, "").times(1));
+        let lists = Lists::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+        let filter = ListFilter {
+            count: Some(2),
+            ..ListFilter::default()
+        };
+
+        // La primera página falla al crear el iterador y se pide de nuevo
+        let mut iter = lists.iter(filter).try_iter();
+        let ids = iter
+            .by_ref()
+            .map(|l| l.map(|l| l.id.unwrap_or_default()))
+            .collect::<Vec<_>>();
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids[0].as_ref().unwrap(), "1");
+        assert_eq!(ids[1].as_ref().unwrap(), "2");
+        match &ids[2] {
+            Err(MailchimpError::Timeout(_)) => {}
+            other => panic!("Se esperaba un timeout: {:?}", other),
+        }
+        assert!(iter.is_failed());
+        assert_eq!(iter.position(), 2);
+
+        iter.resume();
+        let rest = iter.map(|l| l.unwrap().id.unwrap_or_default()).collect::<Vec<String>>();
+        assert_eq!(rest, vec!["3".to_string()]);
+        assert_eq!(fake.requests_to(Method::GET, "lists").len(), 4);
+    }
+
+    #[test]
+    fn iter_cursor_resumes_from_checkpoint() {
+        use crate::iter::{IterCursor, TryMalchimpIter};
+        use crate::{ListFilter, Lists, ListsBuilder};
+        use std::sync::Arc;
+
+        let fake = FakeTransport::new();
+        for (offset, ids) in &[("0", vec!["1", "2"]), ("2", vec!["3", "4"]), ("4", vec!["5"])] {
+            let lists = ids.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>();
+            fake.route(
+                Route::get("lists")
+                    .query("offset", offset)
+                    .respond_json(&json!({"lists": lists, "total_items": 5})),
+            );
+        }
+        let api = MailchimpApi::with_transport("key-us6", fake.clone());
+        let lists = Lists::new(api.clone());
+        let filter = ListFilter {
+            count: Some(2),
+            ..ListFilter::default()
+        };
+
+        let mut iter = lists.iter(filter);
+        let first = iter.by_ref().take(3).filter_map(|l| l.id).collect::<Vec<String>>();
+        assert_eq!(first, vec!["1", "2", "3"]);
+        let checkpoint = serde_json::to_string(&iter.cursor()).unwrap();
+
+        fake.reset();
+        fake.route(
+            Route::get("lists")
+                .query("offset", "2")
+                .respond(r#"{"lists": [{"id": "3"}, {"id": "4"}], "total_items": 5}"#),
+        );
+        fake.route(
+            Route::get("lists")
+                .query("offset", "4")
+                .respond(r#"{"lists": [{"id": "5"}], "total_items": 5}"#),
+        );
+        let cursor: IterCursor<ListFilter> = serde_json::from_str(&checkpoint).unwrap();
+        assert_eq!(cursor.offset, 3);
+        assert_eq!(cursor.page_offset, 1);
+        let iter = TryMalchimpIter::from_cursor(ListsBuilder {}, Arc::new(api), cursor);
+        let rest = iter
+            .map(|l| l.unwrap().id.unwrap_or_default())
+            .collect::<Vec<String>>();
+        assert_eq!(rest, vec!["4", "5"]);
+        assert_eq!(fake.requests().len(), 2);
+    }
+    #[test]
+    fn pages_drop_consumed_items_and_prefetch() {
+        use crate::{ListFilter, Lists};
+
+        let fake = FakeTransport::new();
+        for (offset, ids) in &[("0", vec!["1", "2"]), ("2", vec!["3", "4"]), ("4", vec!["5"])] {
+            let lists = ids.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>();
+            fake.route(
+                Route::get("lists")
+                    .query("offset", offset)
+                    .respond_json(&json!({"lists": lists, "total_items": 5})),
+            );
+        }
+        let lists = Lists::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+        let filter = ListFilter {
+            count: Some(2),
+            ..ListFilter::default()
+        };
+
+        // Solo se mantiene la página actual
+        let mut iter = lists.iter(filter.clone());
+        let ids = iter.by_ref().take(3).filter_map(|l| l.id).collect::<Vec<String>>();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+        assert_eq!(iter.data.len(), 2);
+        assert_eq!(iter.page_start(), 2);
+        assert_eq!(iter.count(), 2);
+
+        let before = fake.requests().len();
+        let pages = lists
+            .iter(filter)
+            .pages()
+            .prefetch(true)
+            .map(|p| p.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pages.iter().map(|p| (p.offset, p.items.len())).collect::<Vec<_>>(),
+            vec![(0, 2), (2, 2), (4, 1)]
+        );
+        assert!(pages.iter().all(|p| p.total_items == 5));
+        assert_eq!(fake.requests().len() - before, 3);
+    }
+
+    #[test]
+    fn ecommerce_store_orders_and_products() {
+        use crate::iter::SimpleFilter;
+        use crate::Ecommerce;
+
+        let fake = FakeTransport::new();
+        fake.route(Route::get("ecommerce/stores/store_001").respond_json(&json!({
+            "id": "store_001",
+            "list_id": "57afe96172",
+            "name": "Freddie's Merchandise",
+            "currency_code": "USD"
+        })));
+        for (offset, ids) in &[("0", vec!["p1", "p2"]), ("2", vec!["p3"])] {
+            let products = ids
+                .iter()
+                .map(|id| json!({"id": id, "variants": [{"id": id, "price": 9.5}]}))
+                .collect::<Vec<_>>();
+            fake.route(
+                Route::get("ecommerce/stores/store_001/products")
+                    .query("offset", offset)
+                    .respond_json(&json!({"products": products, "total_items": 3})),
+            );
+        }
+        fake.route(
+            Route::post("ecommerce/stores/store_001/orders").respond_json(&json!({
+                "id": "order_001",
+                "customer": {"id": "cust_001", "email_address": "freddie@example.com"},
+                "currency_code": "USD",
+                "order_total": 19.0,
+                "lines": [{"id": "line_1", "product_id": "p1", "quantity": 2, "price": 9.5}]
+            })),
+        );
+        fake.route(Route::delete("ecommerce/stores/store_001/orders/order_001/lines/line_1"));
+        let ecommerce = Ecommerce::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+
+        let store = ecommerce.get_store("store_001").unwrap();
+        assert_eq!(store.name, "Freddie's Merchandise");
+
+        let filter = SimpleFilter {
+            count: Some(2),
+            ..SimpleFilter::default()
+        };
+        let products = store.get_products(Some(filter)).collect::<Vec<_>>();
+        assert_eq!(
+            products.iter().map(|p| p.id.as_str()).collect::<Vec<_>>(),
+            vec!["p1", "p2", "p3"]
+        );
+        assert_eq!(products[2].variants[0].price, 9.5);
+
+        let param = EcommerceOrderParam {
+            id: Some("order_001".to_string()),
+            customer: Some(EcommerceCustomerParam {
+                id: Some("cust_001".to_string()),
+                ..EcommerceCustomerParam::default()
+            }),
+            currency_code: Some("USD".to_string()),
+            order_total: Some(19.0),
+            lines: Some(vec![EcommerceOrderLineParam {
+                id: Some("line_1".to_string()),
+                product_id: Some("p1".to_string()),
+                product_variant_id: Some("p1".to_string()),
+                quantity: Some(2),
+                price: Some(9.5),
+                ..EcommerceOrderLineParam::default()
+            }]),
+            ..EcommerceOrderParam::default()
+        };
+        let order = store.create_order(param).unwrap();
+        assert_eq!(
+            fake.assert_called(Method::POST, "ecommerce/stores/store_001/orders")
+                .payload,
+            Some(json!({
+                "id": "order_001",
+                "customer": {"id": "cust_001"},
+                "currency_code": "USD",
+                "order_total": 19.0,
+                "lines": [{
+                    "id": "line_1",
+                    "product_id": "p1",
+                    "product_variant_id": "p1",
+                    "quantity": 2,
+                    "price": 9.5
+                }]
+            }))
+        );
+        assert_eq!(order.customer.email_address, "freddie@example.com");
+
+        // Los elementos anidados conocen su endpoint
+        assert!(order.lines[0].delete().is_none());
+    }
+
+    #[test]
+    fn ecommerce_promo_rules_and_codes() {
+        use crate::Ecommerce;
+
+        let rules = "ecommerce/stores/store_001/promo-rules";
+        let codes = "ecommerce/stores/store_001/promo-rules/free-ship/promo-codes";
+        let fake = FakeTransport::new();
+        fake.route(Route::get("ecommerce/stores/store_001").respond(r#"{"id": "store_001"}"#));
+        fake.route(Route::post(rules).respond_json(&json!({
+            "id": "free-ship",
+            "description": "Free shipping",
+            "amount": 1.0,
+            "type": "percentage",
+            "target": "shipping",
+            "enabled": true
+        })));
+        fake.route(Route::get(rules).respond_json(&json!({
+            "promo_rules": [
+                {"id": "r1", "amount": 5.0, "type": "fixed", "target": "per_item"},
+                {"id": "r2", "amount": 0.2, "type": "percentage", "target": "total"}
+            ],
+            "total_items": 2
+        })));
+        fake.route(Route::post(codes).respond_json(&json!({
+            "id": "code_001",
+            "code": "SHIPFREE",
+            "redemption_url": "https://example.com/cart?promo=SHIPFREE",
+            "usage_count": 0
+        })));
+        fake.route(Route::get(codes).respond_json(&json!({
+            "promo_codes": [{"id": "code_001", "code": "SHIPFREE", "usage_count": 42}],
+            "total_items": 1
+        })));
+        let ecommerce = Ecommerce::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+        let store = ecommerce.get_store("store_001").unwrap();
+
+        let param = EcommercePromoRuleParam::free_shipping("free-ship", "Free shipping");
+        let rule = store.create_promo_rule(param).unwrap();
+        assert_eq!(
+            fake.assert_called(Method::POST, rules).payload,
+            Some(json!({
+                "id": "free-ship",
+                "description": "Free shipping",
+                "amount": 1.0,
+                "type": "percentage",
+                "target": "shipping"
+            }))
+        );
+        assert_eq!(rule.discount(), EcommerceDiscount::FreeShipping);
+
+        let discounts = store
+            .get_promo_rules(None)
+            .map(|r| r.discount())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            discounts,
+            vec![
+                EcommerceDiscount::Fixed {
+                    amount: 5.0,
+                    target: EcommercePromoRuleTarget::PerItem
+                },
+                EcommerceDiscount::Percentage {
+                    amount: 0.2,
+                    target: EcommercePromoRuleTarget::Total
+                }
+            ]
+        );
+
+        let code = rule
+            .create_promo_code(EcommercePromoCodeParam::new(
+                "code_001",
+                "SHIPFREE",
+                "https://example.com/cart?promo=SHIPFREE",
+            ))
+            .unwrap();
+        assert_eq!(code.redemption_url, "https://example.com/cart?promo=SHIPFREE");
+        let usage = rule.get_promo_codes(None).map(|c| c.usage_count).sum::<u64>();
+        assert_eq!(usage, 42);
+    }
+
+    #[test]
+    fn templates_filter_default_content_and_folders() {
+        use crate::{TemplateFilter, TemplateFolders, Templates};
+
+        let fake = FakeTransport::new();
+        fake.route(
+            Route::get("templates")
+                .query("type", "user")
+                .query("folder_id", "f1")
+                .respond_json(&json!({
+                    "templates": [{"id": 2000094, "type": "user", "name": "Freddie's Jokes"}],
+                    "total_items": 1
+                })),
+        );
+        fake.route(Route::get("templates/2000094/default-content").respond_json(&json!({
+            "sections": {"header_image": "<img src=\"logo.png\">", "body": "<p>Hi</p>"}
+        })));
+        fake.route(Route::post("template-folders").respond(r#"{"id": "f1", "name": "Jokes"}"#));
+        fake.route(Route::delete("template-folders/f1"));
+        let templates = Templates::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+        let folders = TemplateFolders::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+
+        let folder = folders
+            .create_folder(TemplateFolderParam {
+                name: "Jokes".to_string(),
+            })
+            .unwrap();
+        let filter = TemplateFilter {
+            template_type: Some("user".to_string()),
+            folder_id: Some(folder.id.clone()),
+            ..TemplateFilter::default()
+        };
+        let found = templates.iter(filter).collect::<Vec<_>>();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].template_type, "user");
+
+        let content = found[0].get_default_content().unwrap();
+        assert_eq!(content.sections["body"], "<p>Hi</p>");
+        assert!(folder.delete().is_none());
+        fake.assert_called(Method::DELETE, "template-folders/f1");
+    }
+
+    #[test]
+    fn campaign_folders_and_move_campaign() {
+        use crate::{CampaignFilter, CampaignFolders, Campaigns};
+
+        let fake = FakeTransport::new();
+        fake.route(Route::post("campaign-folders").respond(r#"{"id": "f1", "name": "Q3"}"#));
+        fake.route(Route::patch("campaign-folders/f1").respond(r#"{"id": "f1", "name": "Q3 2020"}"#));
+        fake.route(Route::get("campaigns").respond_json(&json!({
+            "campaigns": [{"id": "c1", "settings": {"subject_line": "Hi", "folder_id": "0"}}],
+            "total_items": 1
+        })));
+        fake.route(
+            Route::get("campaigns")
+                .query("folder_id", "f1")
+                .respond(r#"{"campaigns": [{"id": "c1"}], "total_items": 1}"#),
+        );
+        fake.route(Route::patch("campaigns/c1").respond(r#"{"id": "c1"}"#));
+        let api = MailchimpApi::with_transport("key-us6", fake.clone());
+        let folders = CampaignFolders::new(api.clone());
+        let campaigns = Campaigns::new(api);
+
+        let folder = folders
+            .create_folder(CampaignFolderParam {
+                name: "Q3".to_string(),
+            })
+            .unwrap();
+        let folder = folder
+            .update(CampaignFolderParam {
+                name: "Q3 2020".to_string(),
+            })
+            .unwrap();
+        assert_eq!(folder.name, "Q3 2020");
+
+        for campaign in campaigns.iter(CampaignFilter::default()) {
+            campaign.move_to_folder(&folder.id).unwrap();
+        }
+        assert_eq!(
+            fake.assert_called(Method::PATCH, "campaigns/c1").payload,
+            Some(json!({"settings": {"subject_line": "Hi", "folder_id": "f1"}}))
+        );
+
+        let filter = CampaignFilter {
+            folder_id: Some(folder.id.clone()),
+            ..CampaignFilter::default()
+        };
+        assert_eq!(campaigns.iter(filter).count(), 1);
+    }
+
+    #[test]
+    fn file_manager_upload_list_and_manage_files() {
+        use crate::{FileManager, FileManagerFilter};
+
+        let fake = FakeTransport::new();
+        fake.route(Route::post("file-manager/folders").respond(r#"{"id": 7, "name": "Headers"}"#));
+        fake.route(Route::post("file-manager/files").respond_json(&json!({
+            "id": 42,
+            "folder_id": 7,
+            "type": "image",
+            "name": "logo.png",
+            "full_size_url": "https://gallery.mailchimp.com/logo.png"
+        })));
+        fake.route(
+            Route::get("file-manager/folders/7/files")
+                .query("type", "image")
+                .respond(r#"{"files": [{"id": 42, "folder_id": 7, "name": "logo.png"}], "total_items": 1}"#),
+        );
+        fake.route(Route::patch("file-manager/files/42").respond(r#"{"id": 42, "name": "brand.png"}"#));
+        fake.route(Route::delete("file-manager/files/42"));
+        let file_manager = FileManager::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+
+        let folder = file_manager
+            .create_folder(FileManagerFolderParam {
+                name: "Headers".to_string(),
+            })
+            .unwrap();
+        let file = file_manager
+            .upload("logo.png", b"\x89PNG", Some(folder.id))
+            .unwrap();
+        assert_eq!(file.file_type, "image");
+        assert_eq!(
+            fake.assert_called(Method::POST, "file-manager/files").payload,
+            Some(json!({"folder_id": 7, "name": "logo.png", "file_data": "iVBORw=="}))
+        );
+
+        let filter = FileManagerFilter {
+            file_type: Some("image".to_string()),
+            folder_id: Some(folder.id),
+            ..FileManagerFilter::default()
+        };
+        let files = file_manager.iter(filter).collect::<Vec<_>>();
+        assert_eq!(files.len(), 1);
+
+        let renamed = files[0].rename("brand.png").unwrap();
+        assert_eq!(renamed.name, "brand.png");
+        files[0].move_to_folder(0).unwrap();
+        let moves = fake.requests_to(Method::PATCH, "file-manager/files/42");
+        assert_eq!(moves[0].payload, Some(json!({"name": "brand.png"})));
+        assert_eq!(moves[1].payload, Some(json!({"folder_id": 0})));
+        assert!(renamed.delete().is_none());
+    }
+
+    #[test]
+    fn report_click_details_open_details_and_email_activity() {
+        use crate::types::ReportActivityFilter;
+        use crate::Reports;
+
+        let fake = FakeTransport::new();
+        fake.route(Route::get("reports/c1").respond(r#"{"id": "c1", "emails_sent": 2}"#));
+        fake.route(Route::get("reports/c1/click-details").respond_json(&json!({
+            "urls_clicked": [{"id": "l1", "url": "https://example.com", "total_clicks": 3}],
+            "campaign_id": "c1",
+            "total_items": 1
+        })));
+        fake.route(Route::get("reports/c1/click-details/l1/members").respond_json(&json!({
+            "members": [{"email_address": "ana@example.com", "clicks": 3}],
+            "total_items": 1
+        })));
+        fake.route(
+            Route::get("reports/c1/open-details")
+                .query("since", "2020-10-01T00:00:00+00:00")
+                .respond_json(&json!({
+                    "members": [{
+                        "email_address": "ana@example.com",
+                        "opens_count": 2,
+                        "opens": [
+                            {"timestamp": "2020-10-02T10:00:00+00:00"},
+                            {"timestamp": "2020-10-03T10:00:00+00:00"}
+                        ]
+                    }],
+                    "total_opens": 2,
+                    "total_items": 1
+                })),
+        );
+        fake.route(Route::get("reports/c1/email-activity").respond_json(&json!({
+            "emails": [{
+                "email_address": "ana@example.com",
+                "activity": [
+                    {"action": "open", "timestamp": "2020-10-02T10:00:00+00:00", "ip": "10.0.0.1"},
+                    {"action": "click", "url": "https://example.com"}
+                ]
+            }],
+            "total_items": 1
+        })));
+        fake.route(Route::get("reports/c1/domain-performance").respond_json(&json!({
+            "domains": [{"domain": "example.com", "emails_sent": 2, "opens": 1}],
+            "total_sent": 2
+        })));
+        let reports = Reports::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+
+        let report = reports.get_campaign_report("c1").unwrap();
+        let links = report.get_click_details(None).collect::<Vec<_>>();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].total_clicks, 3);
+        let members = links[0].get_members(None).collect::<Vec<_>>();
+        assert_eq!(members[0].email_address, "ana@example.com");
+
+        let filter = ReportActivityFilter {
+            since: Some("2020-10-01T00:00:00+00:00".parse().unwrap()),
+            ..ReportActivityFilter::default()
+        };
+        let opens = report.get_open_details(Some(filter)).collect::<Vec<_>>();
+        assert_eq!(opens[0].opens.len(), 2);
+
+        let activity = report.get_email_activity(None).collect::<Vec<_>>();
+        assert_eq!(activity[0].activity[0].action, "open");
+        assert_eq!(activity[0].activity[1].url, "https://example.com");
+
+        let domains = report.get_domain_performance().unwrap();
+        assert_eq!(domains.total_sent, 2);
+        assert_eq!(domains.domains[0].domain, "example.com");
+    }
+
+    #[test]
+    fn search_members_and_campaigns() {
+        use crate::Search;
+
+        let fake = FakeTransport::new();
+        fake.route(
+            Route::get("search-members")
+                .query("query", "smith")
+                .query("list_id", "l1")
+                .respond_json(&json!({
+                    "exact_matches": {
+                        "members": [{"id": "h1", "email_address": "smith@example.com", "list_id": "l1"}],
+                        "total_items": 1
+                    },
+                    "full_search": {
+                        "members": [{"id": "h2", "email_address": "j.smithers@example.com", "list_id": "l1"}],
+                        "total_items": 1
+                    }
+                })),
+        );
+        fake.route(
+            Route::get("search-campaigns")
+                .query("query", "sale")
+                .respond_json(&json!({
+                    "results": [
+                        {"campaign": {"id": "c1"}, "snippet": "Summer <b>sale</b>"},
+                        {"snippet": "Winter <b>sale</b>"}
+                    ],
+                    "total_items": 2
+                })),
+        );
+        fake.route(Route::get("lists/l1/members/h2/activity").respond(r#"{"activity": [], "total_items": 0}"#));
+        let search = Search::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+
+        let members = search.search_members("smith", Some("l1")).unwrap();
+        assert_eq!(members.exact_matches.members[0].email_address, "smith@example.com");
+        assert_eq!(members.full_search.total_items, 1);
+        assert_eq!(members.full_search.members[0].get_activity().count(), 0);
+        fake.assert_called(Method::GET, "lists/l1/members/h2/activity");
+
+        let campaigns = search.search_campaigns("sale").unwrap();
+        assert_eq!(campaigns.results[0].campaign.id, Some("c1".to_string()));
+        assert_eq!(campaigns.results[0].snippet, "Summer <b>sale</b>");
+        assert_eq!(campaigns.results[1].campaign.id, None);
+    }
+
+    #[test]
+    fn list_member_create_and_iterate_events() {
+        use crate::types::ListMemberEventParam;
+        use crate::Search;
+
+        let fake = FakeTransport::new();
+        fake.route(
+            Route::get("search-members")
+                .respond(r#"{"exact_matches": {"members": [{"id": "h1", "list_id": "l1"}], "total_items": 1}}"#),
+        );
+        fake.route(Route::post("lists/l1/members/h1/events").respond_status(204, ""));
+        fake.route(Route::get("lists/l1/members/h1/events").respond_json(&json!({
+            "events": [{
+                "name": "trial_started",
+                "occurred_at": "2020-10-01T10:00:00+00:00",
+                "properties": {"plan": "pro"}
+            }],
+            "total_items": 1
+        })));
+        let search = Search::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+        let member = search.search_members("h1", None).unwrap().exact_matches.members[0].clone();
+
+        let event = ListMemberEventParam::new("trial_started")
+            .with_property("plan", "pro")
+            .with_occurred_at("2020-10-01T10:00:00+00:00".parse().unwrap())
+            .with_is_syncing(true);
+        assert!(member.create_event(event).is_none());
+        assert_eq!(
+            fake.assert_called(Method::POST, "lists/l1/members/h1/events").payload,
+            Some(json!({
+                "name": "trial_started",
+                "properties": {"plan": "pro"},
+                "is_syncing": true,
+                "occurred_at": "2020-10-01T10:00:00+00:00"
+            }))
+        );
+
+        let events = member.get_events(None).collect::<Vec<_>>();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].properties["plan"], "pro");
+        assert_eq!(
+            serde_json::to_value(&events[0]).unwrap()["occurred_at"],
+            json!("2020-10-01T10:00:00+00:00")
+        );
+    }
+
+    #[test]
+    fn typed_enums_keep_unknown_values() {
+        use crate::types::{EmailType, ListMembersFilter, MemberStatus, SortDir};
+        use crate::Lists;
+
+        let fake = FakeTransport::new();
+        fake.route(Route::get("lists/l1").respond(r#"{"id": "l1"}"#));
+        fake.route(
+            Route::get("lists/l1/members")
+                .query("status", "subscribed")
+                .query("sort_dir", "DESC")
+                .respond_json(&json!({
+                    "members": [
+                        {"id": "h1", "status": "subscribed", "email_type": "html"},
+                        {"id": "h2", "status": "frozen", "email_type": "text"}
+                    ],
+                    "total_items": 2
+                })),
+        );
+        let lists = Lists::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+        let list = lists.get_list_info("l1", HashMap::new()).unwrap();
+
+        let filter = ListMembersFilter {
+            status: Some(MemberStatus::Subscribed),
+            sort_dir: Some(SortDir::Desc),
+            ..ListMembersFilter::default()
+        };
+        let members = list.get_members(Some(filter)).collect::<Vec<_>>();
+        assert_eq!(members[0].status, MemberStatus::Subscribed);
+        assert_eq!(members[0].email_type, EmailType::Html);
+        assert_eq!(members[1].status, MemberStatus::Unknown("frozen".to_string()));
+        assert_eq!(serde_json::to_value(&members[1].status).unwrap(), json!("frozen"));
+        assert_eq!(MemberStatus::from("archived"), MemberStatus::Archived);
+        assert_eq!(SortDir::Asc.to_string(), "ASC");
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn datetime_feature_parses_and_formats_dates() {
+        use crate::types::ListMembersFilter;
+        use crate::Lists;
+        use chrono::{TimeZone, Utc};
+
+        let fake = FakeTransport::new();
+        fake.route(
+            Route::get("lists/l1")
+                .respond(r#"{"id": "l1", "date_created": "2015-09-15T14:40:36+00:00"}"#),
+        );
+        fake.route(
+            Route::get("lists/l1/members")
+                .query("since_last_changed", "2020-10-01T00:00:00+00:00")
+                .respond_json(&json!({
+                    "members": [{
+                        "id": "h1",
+                        "timestamp_signup": "",
+                        "timestamp_opt": "2020-10-02 10:30:00",
+                        "last_changed": "2020-10-03T12:00:00+02:00"
+                    }],
+                    "total_items": 1
+                })),
+        );
+        let lists = Lists::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+        let list = lists.get_list_info("l1", HashMap::new()).unwrap();
+        assert_eq!(
+            list.date_created,
+            Some(Utc.with_ymd_and_hms(2015, 9, 15, 14, 40, 36).unwrap())
+        );
+
+        let filter = ListMembersFilter {
+            since_last_changed: Some(Utc.with_ymd_and_hms(2020, 10, 1, 0, 0, 0).unwrap()),
+            ..ListMembersFilter::default()
+        };
+        let members = list.get_members(Some(filter)).collect::<Vec<_>>();
+        assert_eq!(members[0].timestamp_signup, None);
+        assert_eq!(
+            members[0].timestamp_opt,
+            Some(Utc.with_ymd_and_hms(2020, 10, 2, 10, 30, 0).unwrap())
+        );
+        assert_eq!(
+            members[0].last_changed,
+            Some(Utc.with_ymd_and_hms(2020, 10, 3, 10, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn merge_values_are_typed_and_validated_against_the_list() {
+        use crate::Lists;
+
+        let fake = FakeTransport::new();
+        fake.route(Route::get("lists/l1").respond(r#"{"id": "l1"}"#));
+        fake.route(Route::get("lists/l1/merge-fields").respond_json(&json!({
+            "merge_fields": [
+                {"tag": "FNAME", "type": "text", "required": true},
+                {"tag": "AGE", "type": "number"},
+                {"tag": "ADDRESS", "type": "address"},
+                {"tag": "PLAN", "type": "dropdown", "options": {"choices": ["free", "pro"]}}
+            ],
+            "total_items": 4
+        })));
+        fake.route(Route::post("lists/l1/members").respond(r#"{"id": "h1"}"#));
+        fake.route(
+            Route::get("lists/l1/members").respond_json(&json!({
+                "members": [{
+                    "id": "h1",
+                    "merge_fields": {
+                        "FNAME": "Urist",
+                        "AGE": 42,
+                        "ADDRESS": {"addr1": "1 Main St", "city": "Atlanta", "state": "GA", "zip": "30301", "country": "US"},
+                        "PLAN": ""
+                    }
+                }],
+                "total_items": 1
+            })),
+        );
+        let lists = Lists::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+        let list = lists.get_list_info("l1", HashMap::new()).unwrap();
+
+        let member = list.get_members(None).next().unwrap();
+        assert_eq!(member.merge_fields["FNAME"], MergeValue::from("Urist"));
+        assert_eq!(member.merge_fields["AGE"].as_number(), Some(42.0));
+        assert_eq!(
+            member.merge_fields["ADDRESS"].as_address().unwrap().city,
+            "Atlanta"
+        );
+        assert!(member.merge_fields["PLAN"].is_empty());
+
+        let param = |merge_fields: serde_json::Value| {
+            serde_json::from_value::<ListMemberParams>(json!({
+                "email_address": "urist@example.com",
+                "status": "subscribed",
+                "merge_fields": merge_fields
+            }))
+            .unwrap()
+        };
+
+        let bad = [
+            (json!({"AGE": 42}), "FNAME"),
+            (json!({"FNAME": "Urist", "AGE": "old"}), "AGE"),
+            (json!({"FNAME": "Urist", "ADDRESS": "1 Main St"}), "ADDRESS"),
+            (json!({"FNAME": "Urist", "PLAN": "gold"}), "PLAN"),
+            (json!({"FNAME": "Urist", "LNAME": "McVankab"}), "LNAME"),
+        ];
+        for (fields, tag) in bad.iter() {
+            match list.add_new_member_checked(param(fields.clone())) {
+                Err(MailchimpError::InvalidMergeField { tag: t, .. }) => assert_eq!(&t, tag),
+                other => panic!("{:?}", other),
+            }
+        }
+        assert!(fake
+            .requests_to(Method::POST, "lists/l1/members")
+            .is_empty());
+
+        let mut good = param(json!({"FNAME": "Urist", "AGE": "42", "PLAN": "pro"}));
+        good.merge_fields.as_mut().unwrap().insert(
+            "ADDRESS".to_string(),
+            MergeAddress {
+                addr1: "1 Main St".to_string(),
+                city: "Atlanta".to_string(),
+                ..MergeAddress::default()
+            }
+            .into(),
+        );
+        assert!(list.add_new_member_checked(good).is_ok());
+        let payload = fake
+            .assert_called(Method::POST, "lists/l1/members")
+            .payload
+            .unwrap();
+        assert_eq!(payload["merge_fields"]["PLAN"], json!("pro"));
+        assert_eq!(payload["merge_fields"]["ADDRESS"]["city"], json!("Atlanta"));
+        assert_eq!(
+            serde_json::to_value(MergeValue::Number(42.0)).unwrap(),
+            json!(42)
+        );
+    }
+
+    #[test]
+    fn member_operations_by_email_use_the_subscriber_hash() {
+        use crate::{subscriber_hash, Lists};
+
+        let hash = "62eeb292278cc15f5817cb78f7790b08";
+        assert_eq!(subscriber_hash("urist.mcvankab@freddiesjokes.com"), hash);
+        let email = " Urist.McVankab@FreddiesJokes.com ";
+        assert_eq!(subscriber_hash(email), hash);
+
+        let member_path = format!("lists/l1/members/{}", hash);
+        let fake = FakeTransport::new();
+        fake.route(Route::get("lists/l1").respond(r#"{"id": "l1"}"#));
+        fake.route(Route::get(&member_path).respond_json(&json!({"id": hash})));
+        fake.route(Route::put(&member_path).respond_json(&json!({"id": hash})));
+        fake.route(Route::delete(&member_path).respond_status(204, ""));
+        fake.route(
+            Route::post(&format!("{}/actions/delete-permanent", member_path))
+                .respond_status(204, ""),
+        );
+        fake.route(Route::post(&format!("{}/tags", member_path)).respond_status(204, ""));
+        fake.route(
+            Route::get(&format!("{}/activity", member_path))
+                .respond(r#"{"activity": [], "total_items": 0}"#),
+        );
+        let lists = Lists::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+        let list = lists.get_list_info("l1", HashMap::new()).unwrap();
+
+        let member = list.get_member_by_email(email).unwrap();
+        assert_eq!(member.id, hash);
+        member.get_activity().count();
+        fake.assert_called(Method::GET, &format!("{}/activity", member_path));
+
+        let param = serde_json::from_value::<ListMemberParams>(json!({
+            "status_if_new": "subscribed"
+        }))
+        .unwrap();
+        assert!(list.upsert_member_by_email(email, param).is_ok());
+        assert_eq!(
+            fake.assert_called(Method::PUT, &member_path).payload,
+            Some(json!({
+                "email_address": "Urist.McVankab@FreddiesJokes.com",
+                "status_if_new": "subscribed"
+            }))
+        );
+
+        let blank = serde_json::from_value::<ListMemberParams>(json!({
+            "email_address": "  ",
+            "status_if_new": "subscribed"
+        }))
+        .unwrap();
+        assert!(list.upsert_member_by_email(email, blank).is_ok());
+        let puts = fake.requests_to(Method::PUT, &member_path);
+        assert_eq!(
+            puts[1].payload.as_ref().unwrap()["email_address"],
+            json!("Urist.McVankab@FreddiesJokes.com")
+        );
+
+        assert!(list.archive_member_by_email(email).is_none());
+        fake.assert_called(Method::DELETE, &member_path);
+
+        assert!(list.delete_member_by_email(email).is_none());
+        fake.assert_called(
+            Method::POST,
+            &format!("{}/actions/delete-permanent", member_path),
+        );
+
+        let tags = vec![ListMemberTagType {
+            name: "vip".to_string(),
+            status: "active".to_string(),
+        }];
+        assert!(list.tag_member_by_email(email, tags).is_none());
+        assert_eq!(
+            fake.assert_called(Method::POST, &format!("{}/tags", member_path))
+                .payload,
+            Some(json!({"tags": [{"name": "vip", "status": "active"}]}))
+        );
+    }
+
+    #[test]
+    fn promo_rules_keep_unknown_types_and_targets() {
+        let rule = serde_json::from_value::<EcommercePromoRule>(json!({
+            "id": "rule_001",
+            "amount": 3.0,
+            "type": "bogo",
+            "target": "cheapest_item"
+        }))
+        .unwrap();
+        assert_eq!(
+            rule.rule_type,
+            EcommercePromoRuleType::Unknown("bogo".to_string())
+        );
+        assert_eq!(rule.target.as_str(), "cheapest_item");
+        assert_eq!(rule.discount(), EcommerceDiscount::Unknown);
+        assert_eq!(
+            serde_json::from_value::<EcommercePromoRuleTarget>(json!("per_item")).unwrap(),
+            EcommercePromoRuleTarget::PerItem
+        );
+    }
+
+    #[test]
+    fn promo_rule_and_code_ids_are_encoded_as_path_segments() {
+        use crate::Ecommerce;
+
+        let rule = "ecommerce/stores/store_001/promo-rules/summer%2F2020";
+        let fake = FakeTransport::new();
+        fake.route(Route::get("ecommerce/stores/store_001").respond(r#"{"id": "store_001"}"#));
+        fake.route(Route::get(rule).respond(r#"{"id": "summer/2020"}"#));
+        fake.route(
+            Route::get(&format!("{}/promo-codes/SUN%3FFUN", rule))
+                .respond(r#"{"id": "SUN?FUN", "code": "SUNFUN"}"#),
+        );
+        fake.route(Route::delete(&format!("{}/promo-codes/SUN%3FFUN", rule)));
+        let ecommerce = Ecommerce::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+
+        let store = ecommerce.get_store("store_001").unwrap();
+        let promo_rule = store.get_promo_rule("summer/2020").unwrap();
+        let code = promo_rule.get_promo_code("SUN?FUN").unwrap();
+        assert_eq!(code.code, "SUNFUN");
+        assert!(code.delete().is_none());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn nonblocking_lists_validate_merge_fields_before_sending() {
+        use crate::nonblocking::{Lists, MailchimpApi};
+        use futures::executor::block_on;
+
+        let fake = FakeTransport::new();
+        fake.route(Route::get("lists/l1/merge-fields").respond_json(&json!({
+            "merge_fields": [{"tag": "FNAME", "type": "text", "required": true}],
+            "total_items": 1
+        })));
+        fake.route(Route::post("lists/l1/members").respond(r#"{"id": "h1"}"#));
+        let lists = Lists::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+
+        let param = |merge_fields: serde_json::Value| {
+            serde_json::from_value::<ListMemberParams>(json!({
+                "email_address": "urist@example.com",
+                "status": "subscribed",
+                "merge_fields": merge_fields
+            }))
+            .unwrap()
+        };
+        match block_on(lists.add_new_member_checked("l1", param(json!({})))) {
+            Err(MailchimpError::InvalidMergeField { tag, .. }) => assert_eq!(tag, "FNAME"),
+            other => panic!("{:?}", other),
+        }
+        assert!(fake
+            .requests_to(Method::POST, "lists/l1/members")
+            .is_empty());
+
+        let member = block_on(lists.add_new_member_checked("l1", param(json!({"FNAME": "Urist"}))));
+        assert_eq!(member.unwrap().id, "h1");
+        fake.assert_called(Method::POST, "lists/l1/members");
+    }
+
+    #[test]
+    fn file_manager_upload_file_reports_a_missing_file() {
+        use crate::FileManager;
+
+        let fake = FakeTransport::new();
+        let file_manager = FileManager::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+        match file_manager.upload_file("/does/not/exist/logo.png", None) {
+            Err(MailchimpError::Io(e)) => {
+                assert_eq!(e.kind(), std::io::ErrorKind::NotFound);
+                assert!(e.to_string().contains("logo.png"));
+            }
+            other => panic!("{:?}", other),
+        }
+        assert!(fake.requests().is_empty());
+    }
+
+    #[test]
+    fn ecommerce_ids_are_encoded_as_path_segments() {
+        use crate::Ecommerce;
+
+        let customer = "ecommerce/stores/store%2F001/customers/acme%2F42%3F%23";
+        let fake = FakeTransport::new();
+        fake.route(Route::get("ecommerce/stores/store%2F001").respond(r#"{"id": "store/001"}"#));
+        fake.route(Route::get(customer).respond(r#"{"id": "acme/42?#"}"#));
+        fake.route(Route::delete(customer));
+        fake.route(
+            Route::get("ecommerce/stores/store%2F001/products/..%2Fcarts")
+                .respond(r#"{"id": "../carts"}"#),
+        );
+        let ecommerce = Ecommerce::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+
+        let store = ecommerce.get_store("store/001").unwrap();
+        let data = store.get_customer("acme/42?#").unwrap();
+        assert_eq!(data.id, "acme/42?#");
+        assert!(data.delete().is_none());
+        fake.assert_called(Method::DELETE, customer);
+        assert_eq!(store.get_product("../carts").unwrap().id, "../carts");
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn nonblocking_stream_goes_through_the_pages() {
+        use crate::nonblocking::{Lists, MailchimpApi};
+        use crate::ListFilter;
+        use futures::executor::block_on;
+        use futures::StreamExt;
+
+        let fake = FakeTransport::new();
+        for (offset, ids) in &[("0", vec!["l1", "l2"]), ("2", vec!["l3"])] {
+            let lists = ids.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>();
+            fake.route(
+                Route::get("lists")
+                    .query("offset", offset)
+                    .respond_json(&json!({"lists": lists, "total_items": 3})),
+            );
+        }
+        fake.route(
+            Route::get("lists")
+                .query("offset", "2")
+                .fail_transport("connection reset")
+                .times(1),
+        );
+        let lists = Lists::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+        let filter = ListFilter {
+            count: Some(2),
+            ..ListFilter::default()
+        };
+
+        let mut stream = lists.iter(filter);
+        let first = block_on(stream.by_ref().take(2).collect::<Vec<_>>());
+        assert_eq!(
+            first.into_iter().map(|l| l.unwrap().id).collect::<Vec<_>>(),
+            vec![Some("l1".to_string()), Some("l2".to_string())]
+        );
+        match block_on(stream.next()) {
+            Some(Err(MailchimpError::Transport(_))) => {}
+            other => panic!("{:?}", other.map(|r| r.map(|l| l.id))),
+        }
+        assert!(block_on(stream.next()).is_none());
+
+        stream.resume();
+        let rest = block_on(stream.by_ref().collect::<Vec<_>>());
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].as_ref().unwrap().id.as_deref(), Some("l3"));
+        assert_eq!((stream.position(), stream.total_items()), (3, 3));
+        assert_eq!(fake.requests_to(Method::GET, "lists").len(), 3);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn nonblocking_campaign_actions() {
+        use crate::nonblocking::{Campaigns, MailchimpApi};
+        use futures::executor::block_on;
+
+        let fake = FakeTransport::new();
+        fake.route(Route::post("campaigns/c1/actions/send"));
+        fake.route(Route::post("campaigns/c1/actions/replicate").respond(r#"{"id": "c2"}"#));
+        fake.route(Route::post("campaigns/c1/actions/schedule"));
+        let campaigns = Campaigns::new(MailchimpApi::with_transport("key-us6", fake.clone()));
+
+        assert!(block_on(campaigns.send_campaign("c1")).is_ok());
+        let copy = block_on(campaigns.replicate_campaign("c1")).unwrap();
+        assert_eq!(copy.id.as_deref(), Some("c2"));
+        let param = serde_json::from_value::<ScheduleParam>(json!({
+            "schedule_time": "2017-02-04T19:13:00+00:00"
+        }))
+        .unwrap();
+        assert!(block_on(campaigns.schedule_campaign("c1", param)).is_ok());
+        let payload = fake
+            .assert_called(Method::POST, "campaigns/c1/actions/schedule")
+            .payload
+            .unwrap();
+        assert!(payload.get("schedule_time").is_some());
+    }
+
+    #[test]
+    fn fake_transport_paths_are_relative_to_the_api_base() {
+        use crate::internal::api::Api;
+        use std::sync::Arc;
+
+        let fake = FakeTransport::new();
+        fake.route(Route::get("ping").respond("{\"health_status\": \"ok\"}"));
+        let api = MailchimpApi::from_api(Api::with_base_url(
+            "http://localhost:8080/mailchimp/",
+            "3.1",
+            "key",
+            Arc::new(fake.clone()),
+        ));
+
+        assert!(api.ping().is_ok());
+        let request = fake.assert_called(Method::GET, "ping");
+        assert_eq!(request.path, "ping");
+    }
+
+    #[test]
+    fn fake_transport_route_with_zero_times_never_matches() {
+        let fake = FakeTransport::new();
+        fake.route(Route::get("ping").respond("{\"health_status\": \"ok\"}"));
+        fake.route(Route::get("ping").fail_timeout().times(0));
+        let api = MailchimpApi::with_transport("key-us6", fake.clone());
+
+        assert!(api.ping().is_ok());
+        assert!(api.ping().is_ok());
+    }
 }
@@ -1,37 +1,40 @@
-use lazy_static::lazy_static;
 use reqwest::header::HeaderMap;
 use reqwest::{
-    blocking::{Client, Response},
-    Error, StatusCode, Url,
+    blocking::{Client, RequestBuilder, Response},
+    Error, Method, StatusCode, Url,
 };
-use serde::ser::Serialize;
-use serde_json;
-
-lazy_static! {
-    static ref CLIENT: Client = Client::new();
-}
+use serde_json::Value;
 
 // import macro error
-use log::error;
+use log::{error, warn};
+use std::thread;
 
-use super::error_type::MailchimpErrorType;
+use super::error_type::{MailchimpError, MailchimpErrorType};
+use super::retry::{retry_after, RetryPolicy};
 
-// Define un aleas generico al Result para MailchimpErrorType
-pub type MailchimpResult<T> = Result<T, MailchimpErrorType>;
+/// Define un alias genérico al Result para MailchimpError
+pub type MailchimpResult<T> = Result<T, MailchimpError>;
 
 ///
 /// BasicAuth
 ///
 #[derive(Debug, Clone)]
 pub struct BasicAuth {
+    /// Nombre de usuario, Mailchimp acepta cualquier valor
     pub username: String,
+    /// API Key
     pub api_token: String,
 }
 
 ///
 /// Definición que deben cumplir para poder extaer datos mediante HTTP
 ///
-pub trait HttpReq {
+/// Implement it to plug your own transport (a recording transport, a proxy
+/// client, a test double...) into `MailchimpApi::with_transport`. The
+/// transport receives the full URL, the headers and the JSON payload, and
+/// returns the body of the response.
+///
+pub trait HttpReq: Send + Sync {
     ///
     /// Función para leer los recursos desde el servidor
     ///
@@ -53,15 +56,13 @@ pub trait HttpReq {
     ///     headers: Headers
     ///     payload: Datos a enviar a la URL especificada
     ///
-    fn post<P>(
+    fn post(
         &self,
         url: Url,
         headers: HeaderMap,
-        payload: P,
+        payload: Value,
         basic_auth: &Option<BasicAuth>,
-    ) -> MailchimpResult<String>
-    where
-        P: Serialize;
+    ) -> MailchimpResult<String>;
     ///
     /// Función para Actualizar algún recurso en el servidor
     ///
@@ -70,15 +71,13 @@ pub trait HttpReq {
     ///     headers: Headers
     ///     payload: Datos a enviar a la URL especificada
     ///
-    fn patch<P>(
+    fn patch(
         &self,
         url: Url,
         headers: HeaderMap,
-        payload: P,
+        payload: Value,
         basic_auth: &Option<BasicAuth>,
-    ) -> MailchimpResult<String>
-    where
-        P: Serialize;
+    ) -> MailchimpResult<String>;
     ///
     /// Función para Actualizar algún recurso en el servidor
     ///
@@ -87,15 +86,13 @@ pub trait HttpReq {
     ///     headers: Headers
     ///     payload: Datos a enviar a la URL especificada
     ///
-    fn put<P>(
+    fn put(
         &self,
         url: Url,
         headers: HeaderMap,
-        payload: P,
+        payload: Value,
         basic_auth: &Option<BasicAuth>,
-    ) -> MailchimpResult<String>
-    where
-        P: Serialize;
+    ) -> MailchimpResult<String>;
     ///
     /// Función para eliminar algun recursos en el servidor
     ///
@@ -109,24 +106,95 @@ pub trait HttpReq {
         headers: HeaderMap,
         basic_auth: &Option<BasicAuth>,
     ) -> MailchimpResult<String>;
+    ///
+    /// Cambia la política de reintentos, los transportes que no reintentan
+    /// las peticiones la ignoran
+    ///
+    fn set_retry_policy(&mut self, _policy: RetryPolicy) {}
+    ///
+    /// Recibe la url base del API, p.ej. `https://us6.api.mailchimp.com/3.0/`,
+    /// sobre la que se resuelven los endpoints. Por defecto no hace nada
+    ///
+    fn set_api_base(&self, _api_base: &Url) {}
+    ///
+    /// Descarga un archivo fuera del API, p.ej. los resultados de una
+    /// operación batch. La petición se envía sin credenciales.
+    ///
+    ///  Argumentos:
+    ///     url: Url
+    ///
+    fn download(&self, url: Url) -> MailchimpResult<Vec<u8>> {
+        Err(MailchimpError::Transport(
+            format!("The transport does not support downloads: {}", url).into(),
+        ))
+    }
 }
 
 ///
 /// MailchimpRequest
 ///
+/// Transporte HTTP basado en el cliente bloqueante de reqwest
+///
 #[derive(Debug, Clone)]
-pub struct MailchimpRequest {}
+pub struct MailchimpRequest {
+    client: Client,
+    retry: RetryPolicy,
+}
 
 impl MailchimpRequest {
     ///
     /// Devuelve una instancia nueva
     ///
     pub fn new() -> Self {
-        MailchimpRequest {}
+        MailchimpRequest::with_client(Client::new())
+    }
+
+    ///
+    /// Devuelve una instancia nueva que envía las peticiones con el cliente
+    /// indicado, p.ej. con timeouts o proxy
+    ///
+    pub fn with_client(client: Client) -> Self {
+        MailchimpRequest {
+            client,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    ///
+    /// Devuelve una instancia nueva con la política de reintentos indicada
+    ///
+    pub fn with_retry_policy(retry: RetryPolicy) -> Self {
+        MailchimpRequest {
+            client: Client::new(),
+            retry,
+        }
+    }
+}
+
+impl Default for MailchimpRequest {
+    fn default() -> Self {
+        MailchimpRequest::new()
     }
 }
 
 impl HttpReq for MailchimpRequest {
+    fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry = policy;
+    }
+
+    fn download(&self, url: Url) -> MailchimpResult<Vec<u8>> {
+        let resp = self.client.get(url).send().map_err(|e| {
+            error!(target: "mailchimp", "Download {:?}", e);
+            MailchimpError::from(e)
+        })?;
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text()?;
+            return check_response(status, body).map(String::into_bytes);
+        }
+        Ok(resp.bytes()?.to_vec())
+    }
+
     ///
     ///  Argumentos:
     ///     url: Url
@@ -138,15 +206,9 @@ impl HttpReq for MailchimpRequest {
         headers: HeaderMap,
         basic_auth: &Option<BasicAuth>,
     ) -> MailchimpResult<String> {
-        let builder = match basic_auth {
-            Some(auth) => CLIENT
-                .get(url)
-                .basic_auth(auth.username.clone(), Some(auth.api_token.clone())),
-            None => CLIENT.get(url),
-        };
-
-        let result = builder.headers(headers).send();
-        self.process_response(result, "GET")
+        self.execute(Method::GET, || {
+            with_auth(self.client.get(url.clone()), basic_auth).headers(headers.clone())
+        })
     }
     ///
     ///  Argumentos:
@@ -154,24 +216,18 @@ impl HttpReq for MailchimpRequest {
     ///     headers: HeaderMap
     ///     payload: Datos a enviar a la URL especificada
     ///
-    fn post<P>(
+    fn post(
         &self,
         url: Url,
         headers: HeaderMap,
-        payload: P,
+        payload: Value,
         basic_auth: &Option<BasicAuth>,
-    ) -> MailchimpResult<String>
-    where
-        P: Serialize,
-    {
-        let builder = match basic_auth {
-            Some(auth) => CLIENT
-                .post(url)
-                .basic_auth(auth.username.clone(), Some(auth.api_token.clone())),
-            None => CLIENT.post(url),
-        };
-        let result = builder.headers(headers).json(&payload).send();
-        self.process_response(result, "POST")
+    ) -> MailchimpResult<String> {
+        self.execute(Method::POST, || {
+            with_auth(self.client.post(url.clone()), basic_auth)
+                .headers(headers.clone())
+                .json(&payload)
+        })
     }
     ///
     ///  Argumentos:
@@ -179,24 +235,18 @@ impl HttpReq for MailchimpRequest {
     ///     headers: HeaderMap
     ///     payload: Datos a enviar a la URL especificada
     ///
-    fn patch<P>(
+    fn patch(
         &self,
         url: Url,
         headers: HeaderMap,
-        payload: P,
+        payload: Value,
         basic_auth: &Option<BasicAuth>,
-    ) -> MailchimpResult<String>
-    where
-        P: Serialize,
-    {
-        let builder = match basic_auth {
-            Some(auth) => CLIENT
-                .patch(url)
-                .basic_auth(auth.username.clone(), Some(auth.api_token.clone())),
-            None => CLIENT.post(url),
-        };
-        let result = builder.headers(headers).json(&payload).send();
-        self.process_response(result, "PATCH")
+    ) -> MailchimpResult<String> {
+        self.execute(Method::PATCH, || {
+            with_auth(self.client.patch(url.clone()), basic_auth)
+                .headers(headers.clone())
+                .json(&payload)
+        })
     }
     ///
     ///  Argumentos:
@@ -204,24 +254,18 @@ impl HttpReq for MailchimpRequest {
     ///     headers: HeaderMap
     ///     payload: Datos a enviar a la URL especificada
     ///
-    fn put<P>(
+    fn put(
         &self,
         url: Url,
         headers: HeaderMap,
-        payload: P,
+        payload: Value,
         basic_auth: &Option<BasicAuth>,
-    ) -> MailchimpResult<String>
-    where
-        P: Serialize,
-    {
-        let builder = match basic_auth {
-            Some(auth) => CLIENT
-                .patch(url)
-                .basic_auth(auth.username.clone(), Some(auth.api_token.clone())),
-            None => CLIENT.post(url),
-        };
-        let result = builder.headers(headers).json(&payload).send();
-        self.process_response(result, "PUT")
+    ) -> MailchimpResult<String> {
+        self.execute(Method::PUT, || {
+            with_auth(self.client.put(url.clone()), basic_auth)
+                .headers(headers.clone())
+                .json(&payload)
+        })
     }
     ///
     ///  Argumentos:
@@ -234,55 +278,92 @@ impl HttpReq for MailchimpRequest {
         headers: HeaderMap,
         basic_auth: &Option<BasicAuth>,
     ) -> MailchimpResult<String> {
-        let builder = match basic_auth {
-            Some(auth) => CLIENT
-                .delete(url)
-                .basic_auth(auth.username.clone(), Some(auth.api_token.clone())),
-            None => CLIENT.get(url),
-        };
-
-        let result = builder.headers(headers).send();
-        self.process_response(result, "DELETE")
+        self.execute(Method::DELETE, || {
+            with_auth(self.client.delete(url.clone()), basic_auth).headers(headers.clone())
+        })
     }
 }
 
 impl MailchimpRequest {
-    fn process_response<'a>(
+    ///
+    /// Envía la petición, repitiéndola mientras la política de reintentos lo permita
+    ///
+    /// Argumentos:
+    ///     method: Método HTTP de la petición
+    ///     build: Función que construye la petición para cada intento
+    ///
+    fn execute<F>(&self, method: Method, build: F) -> MailchimpResult<String>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let mut attempt = 0;
+        loop {
+            let result = build().send();
+            let wait = match &result {
+                Ok(resp) if self.retry.should_retry_status(&method, resp.status(), attempt) => {
+                    Some(self.retry.delay(attempt, retry_after(resp.headers())))
+                }
+                Err(e) if self.retry.should_retry_error(&method, e, attempt) => {
+                    Some(self.retry.delay(attempt, None))
+                }
+                _ => None,
+            };
+            match wait {
+                Some(delay) => {
+                    warn!(
+                        target: "mailchimp",
+                        "{:?}: Retrying request in {:?} (attempt {})", method, delay, attempt + 1);
+                    thread::sleep(delay);
+                    attempt += 1;
+                }
+                None => return self.process_response(result, method.as_str()),
+            }
+        }
+    }
+
+    fn process_response(
         &self,
         response: Result<Response, Error>,
-        method: &'a str,
+        method: &str,
     ) -> MailchimpResult<String> {
-        match response {
-            Ok(resp) => match resp.status() {
-                StatusCode::OK => match resp.text() {
-                    Ok(txt) => Ok(txt),
-                    Err(e) => {
-                        error!(target: "mailchimp", "{:?}: Response Error Details: {:?}", method, e);
-                        Err(MailchimpErrorType::default())
-                    }
-                },
-                status => match resp.text() {
-                    Ok(txt) => match serde_json::from_str(&txt) {
-                        Ok(value) => Err(value),
-                        Err(e) => {
-                            error!(
-                                target: "mailchimp",
-                                "{:?}: Response Error details: {:?} status {:?}",  method, e, status);
-                            Err(MailchimpErrorType::default())
-                        }
-                    },
-                    Err(e) => {
-                        error!(
-                            target: "mailchimp",
-                            "{:?}: Response Error details: {:?} status {:?}",  method, e, status);
-                        Err(MailchimpErrorType::default())
-                    }
-                },
-            },
-            Err(e) => {
-                error!(target: "mailchimp", "{:?} {:?}", method, e);
-                Err(MailchimpErrorType::default())
-            }
-        }
+        let resp = response.map_err(|e| {
+            error!(target: "mailchimp", "{:?} {:?}", method, e);
+            MailchimpError::from(e)
+        })?;
+        let status = resp.status();
+        let body = resp.text().map_err(|e| {
+            error!(
+                target: "mailchimp",
+                "{:?}: Response Error details: {:?} status {:?}",  method, e, status);
+            MailchimpError::from(e)
+        })?;
+        check_response(status, body)
+    }
+}
+
+///
+/// Adiciona las credenciales a la petición si existen
+///
+fn with_auth(builder: RequestBuilder, basic_auth: &Option<BasicAuth>) -> RequestBuilder {
+    match basic_auth {
+        Some(auth) => builder.basic_auth(auth.username.clone(), Some(auth.api_token.clone())),
+        None => builder,
+    }
+}
+
+///
+/// Devuelve el cuerpo de la respuesta si el estado es satisfactorio, en caso
+/// contrario el error que describe el servidor
+///
+pub fn check_response(status: StatusCode, body: String) -> MailchimpResult<String> {
+    if status.is_success() {
+        return Ok(body);
+    }
+    match serde_json::from_str::<MailchimpErrorType>(&body) {
+        Ok(value) => Err(MailchimpError::Api(value)),
+        Err(_) => Err(MailchimpError::Http {
+            status: status.as_u16(),
+            body,
+        }),
     }
 }
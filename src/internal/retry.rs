@@ -0,0 +1,165 @@
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+///
+/// Retry Policy
+///
+/// Defines how the transport retries a request that failed because of a
+/// transient problem: rate limiting (429), server errors (5xx), timeouts or
+/// connection errors.
+///
+/// The delay between attempts grows exponentially from `base_delay` up to
+/// `max_delay`, and when Mailchimp sends a `Retry-After` header its value is
+/// used instead, also limited to `max_delay`.
+///
+/// ```
+/// use mailchimp::{MailchimpApi, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let api = MailchimpApi::builder()
+///     .api_key("aac1e319006883125e18a89e529b5abb-us6")
+///     .retry_policy(RetryPolicy {
+///         max_retries: 5,
+///         base_delay: Duration::from_secs(1),
+///         ..RetryPolicy::default()
+///     })
+///     .build()
+///     .unwrap();
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt, 0 disables the retries
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound for the delay between two attempts
+    pub max_delay: Duration,
+    /// Randomize the delay to avoid that concurrent clients retry at the same time
+    pub jitter: bool,
+    /// Wait the time sent by the server in the `Retry-After` header
+    pub respect_retry_after: bool,
+    /// Retry also POST and PATCH requests after a server error or a timeout.
+    /// Rate limited requests (429) are always retried because the server did
+    /// not process them.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            respect_retry_after: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    ///
+    /// Policy that never retries a request
+    ///
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            ..RetryPolicy::default()
+        }
+    }
+
+    ///
+    /// Returns true if the request can be sent again after a failed response
+    ///
+    /// Arguments:
+    ///     method: HTTP method of the request
+    ///     status: Status of the response
+    ///     attempt: Number of retries already done
+    ///
+    pub fn should_retry_status(&self, method: &Method, status: StatusCode, attempt: u32) -> bool {
+        if attempt >= self.max_retries {
+            return false;
+        }
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return true;
+        }
+        status.is_server_error() && self.allows(method)
+    }
+
+    ///
+    /// Returns true if the request can be sent again after a transport error
+    ///
+    /// Arguments:
+    ///     method: HTTP method of the request
+    ///     error: Error returned by the HTTP client
+    ///     attempt: Number of retries already done
+    ///
+    pub fn should_retry_error(&self, method: &Method, error: &reqwest::Error, attempt: u32) -> bool {
+        attempt < self.max_retries
+            && (error.is_timeout() || error.is_connect())
+            && self.allows(method)
+    }
+
+    ///
+    /// Time to wait before the next attempt
+    ///
+    /// Arguments:
+    ///     attempt: Number of retries already done
+    ///     retry_after: Delay requested by the server
+    ///
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if self.respect_retry_after {
+            if let Some(wait) = retry_after {
+                return wait.min(self.max_delay);
+            }
+        }
+        let factor = 2u32.saturating_pow(attempt);
+        let delay = self
+            .base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        if self.jitter {
+            // Equal jitter: half of the delay plus a random part of the other half
+            let half = delay / 2;
+            let millis = half.as_millis() as u64;
+            if millis > 0 {
+                return half + Duration::from_millis(random_u64() % (millis + 1));
+            }
+        }
+        delay
+    }
+
+    fn allows(&self, method: &Method) -> bool {
+        self.retry_non_idempotent || is_idempotent(method)
+    }
+}
+
+///
+/// Returns the delay requested by the server in the `Retry-After` header,
+/// only the delay in seconds is supported
+///
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
+
+fn random_u64() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(0);
+    hasher.finish()
+}
@@ -2,10 +2,14 @@
 //!
 
 use crate::api::MailchimpApi;
-use std::rc::Rc;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::MailchimpResult;
 use log::error;
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
 
 ///
 /// Resource Filter
@@ -22,7 +26,7 @@ pub trait ResourceFilter {
 ///
 /// It filter have the the common fields for request filter
 ///
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SimpleFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -102,7 +106,7 @@ pub trait BuildIter {
     ///
     /// Create a resource from the data passed by parameter
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item;
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item;
     ///
     /// Update Filter Offset
     ///
@@ -120,16 +124,18 @@ where
 {
     /// Builder
     pub builder: B,
-    /// Data
+    /// Items of the current page, the consumed pages are dropped
     pub data: Vec<B::Item>,
     /// Current Filter
     pub cur_filters: B::FilterItem,
     /// Current Iterator Index
     pub cur_it: u64,
+    /// Index of the first item of `data` in the iteration
+    page_start: u64,
     /// Total items in collection
     pub total_items: u64,
     /// Mailchimp API
-    pub api: Rc<MailchimpApi>,
+    pub api: Arc<MailchimpApi>,
     /// Endpoint
     pub endpoint: String,
 }
@@ -143,26 +149,23 @@ where
     type Item = B::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let mut data_len = self.data.len();
-        // FIX: 'attempt to subtract with overflow' error
-        if let Some(_) = data_len.checked_sub(2) {
-            data_len = data_len - 2;
-        } else {
-            data_len = 0;
-        }
-
-        if self.cur_it < self.total_items && ((self.cur_it as usize) == data_len) {
+        // La siguiente página se pide al terminar la actual y la reemplaza,
+        // así la memoria no crece con el tamaño de la colección
+        let page_it = self.cur_it.saturating_sub(self.page_start) as usize;
+        if self.cur_it < self.total_items && page_it == self.data.len() {
             let new_filter = self.builder.update_filter_offset(&self.cur_filters);
-            let cl = self.get_collection(&new_filter);
-            self.cur_filters = new_filter;
-
-            for r in cl.get_values() {
-                self.data.push(r);
+            // Si la petición falla se mantiene el filtro de la última página
+            // cargada, para que `try_iter` pueda reintentar la siguiente
+            if let Ok(cl) = self.try_get_collection(&new_filter) {
+                self.cur_filters = new_filter;
+                self.page_start = self.cur_it;
+                self.data = cl.get_values();
             }
         }
 
-        if (self.cur_it as usize) < self.data.len() {
-            let data = &self.data[self.cur_it as usize];
+        let page_it = self.cur_it.saturating_sub(self.page_start) as usize;
+        if page_it < self.data.len() {
+            let data = &self.data[page_it];
             self.cur_it += 1;
             return Some(self.builder.update_item(data, self.api.clone()));
         }
@@ -177,17 +180,565 @@ where
     B::FilterItem: ResourceFilter,
     B::Collection: MailchimpCollection<B::Item> + DeserializeOwned + Default,
 {
+    ///
+    /// Iterator over the collection of the endpoint. The first page is
+    /// requested immediately, if it fails the error is logged and the
+    /// iterator is empty.
+    ///
+    /// Arguments:
+    ///     builder: Builder of the items of the collection
+    ///     api: MailchimpApi
+    ///     endpoint: Endpoint of the collection, e.g. "lists"
+    ///     filters: Filter of the first page
+    ///
+    pub fn from_endpoint(
+        builder: B,
+        api: Arc<MailchimpApi>,
+        endpoint: &str,
+        filters: B::FilterItem,
+    ) -> Self {
+        let mut iter = MalchimpIter {
+            builder,
+            data: Vec::new(),
+            cur_filters: filters,
+            cur_it: 0,
+            page_start: 0,
+            total_items: 0,
+            api,
+            endpoint: endpoint.to_string(),
+        };
+        if let Ok(collection) = iter.try_get_collection(&iter.cur_filters) {
+            iter.total_items = collection.get_total_items();
+            iter.data = collection.get_values();
+        }
+        iter
+    }
+
+    ///
+    /// Index of the first item of `data` in the iteration
+    ///
+    pub fn page_start(&self) -> u64 {
+        self.page_start
+    }
+
     ///
     /// get_collection
     ///
     pub fn get_collection(&self, filters: &B::FilterItem) -> B::Collection {
+        self.try_get_collection(filters).unwrap_or_default()
+    }
+
+    ///
+    /// Get the page of the collection for the given filters, returning the
+    /// error of the request instead of an empty collection
+    ///
+    pub fn try_get_collection(&self, filters: &B::FilterItem) -> MailchimpResult<B::Collection> {
         let payload = filters.build_payload();
         let response = self.api.get::<B::Collection>(&self.endpoint, payload);
-        match response {
-            Ok(value) => value,
+        if let Err(e) = &response {
+            error!( target: "mailchimp",  "MailchimpEndpointIter from remote: Response Error details: {:?}", e);
+        }
+        response
+    }
+
+    ///
+    /// Convert the iterator into a `TryMalchimpIter`, which yields the errors
+    /// of the requests instead of ending silently.
+    ///
+    /// The items already loaded are kept. If the first page could not be
+    /// loaded (the iterator is empty) it is requested again, so the error
+    /// is returned by the first call to `next`.
+    ///
+    pub fn try_iter(self) -> TryMalchimpIter<B> {
+        let loaded = !self.data.is_empty() || self.total_items > 0;
+        let page_it = (self.cur_it.saturating_sub(self.page_start) as usize).min(self.data.len());
+        TryMalchimpIter {
+            builder: self.builder,
+            data: self.data,
+            cur_filters: self.cur_filters,
+            cur_it: page_it,
+            skip: 0,
+            seen: self.page_start + page_it as u64,
+            total_items: self.total_items,
+            pending: !loaded,
+            failed: false,
+            api: self.api,
+            endpoint: self.endpoint,
+        }
+    }
+
+    ///
+    /// Convert the iterator into a `MalchimpPages`, which yields whole pages
+    /// instead of single items. The first page holds the items of the current
+    /// page not returned yet.
+    ///
+    pub fn pages(self) -> MalchimpPages<B> {
+        let loaded = !self.data.is_empty() || self.total_items > 0;
+        let page_it = (self.cur_it.saturating_sub(self.page_start) as usize).min(self.data.len());
+        let mut pages =
+            MalchimpPages::new(self.builder, self.api, &self.endpoint, self.cur_filters);
+        if loaded {
+            let mut data = self.data;
+            data.drain(..page_it);
+            pages.offset = self.page_start + page_it as u64;
+            pages.total_items = self.total_items;
+            pages.cur_filters = pages.builder.update_filter_offset(&pages.cur_filters);
+            pages.first = Some(data);
+        }
+        pages
+    }
+}
+
+impl<B> MalchimpIter<B>
+where
+    B: BuildIter,
+    B::FilterItem: ResourceFilter + Clone,
+{
+    ///
+    /// Position of the iterator, it can be saved to continue the iteration
+    /// later with `TryMalchimpIter::from_cursor`
+    ///
+    pub fn cursor(&self) -> IterCursor<B::FilterItem> {
+        IterCursor {
+            endpoint: self.endpoint.clone(),
+            filter: self.cur_filters.clone(),
+            page_offset: self.cur_it.saturating_sub(self.page_start),
+            offset: self.cur_it,
+            total_items: self.total_items,
+        }
+    }
+}
+
+///
+/// Position of an iteration over a collection
+///
+/// It can be serialized, e.g. to save a checkpoint of a long export and
+/// continue it after a crash without starting again from the first page.
+///
+/// ```no_run
+/// use mailchimp::iter::{IterCursor, TryMalchimpIter};
+/// use mailchimp::types::{ListMembersBuilder, ListMembersFilter};
+/// use mailchimp::{Lists, MailchimpApi};
+/// use std::sync::Arc;
+///
+/// let api = MailchimpApi::new("<API_KEY>");
+/// let list = Lists::new(api.clone()).get_list_info("57afe96172", Default::default()).unwrap();
+/// let mut members = list.get_members(None);
+/// for (i, member) in members.by_ref().take(500).enumerate() {
+///     println!("{} {}", i, member.email_address);
+/// }
+/// let checkpoint = serde_json::to_string(&members.cursor()).unwrap();
+///
+/// // After a restart
+/// let cursor: IterCursor<ListMembersFilter> = serde_json::from_str(&checkpoint).unwrap();
+/// let builder = ListMembersBuilder { endpoint: cursor.endpoint.clone() };
+/// let members = TryMalchimpIter::from_cursor(builder, Arc::new(api), cursor);
+/// for member in members {
+///     println!("{}", member.unwrap().email_address);
+/// }
+/// ```
+///
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IterCursor<F> {
+    /// Endpoint of the collection
+    pub endpoint: String,
+    /// Filter of the page of the next item
+    pub filter: F,
+    /// Items of that page already returned
+    pub page_offset: u64,
+    /// Items returned since the start of the iteration
+    pub offset: u64,
+    /// Total items in the collection, when the cursor was taken
+    pub total_items: u64,
+}
+
+///
+/// Fallible Mailchimp Iterator
+///
+/// Like `MalchimpIter`, but each item is a `MailchimpResult`, so an error in
+/// the middle of the pagination is not confused with the end of the
+/// collection. After an error the iterator returns `None`; call `resume` to
+/// request the failed page again and continue from the same position.
+///
+/// ```no_run
+/// use mailchimp::{ListFilter, Lists, MailchimpApi};
+///
+/// let lists = Lists::new(MailchimpApi::new("<API_KEY>"));
+/// let mut iter = lists.iter(ListFilter::default()).try_iter();
+/// while let Some(list) = iter.next() {
+///     match list {
+///         Ok(list) => println!("{:?}", list.name),
+///         Err(e) => {
+///             println!("Failed at item {}: {}", iter.position(), e);
+///             iter.resume();
+///         }
+///     }
+/// }
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct TryMalchimpIter<B>
+where
+    B: BuildIter,
+    B::FilterItem: ResourceFilter,
+{
+    builder: B,
+    /// Elementos de la página actual
+    data: Vec<B::Item>,
+    /// Filtro de la página actual
+    cur_filters: B::FilterItem,
+    /// Índice dentro de la página actual
+    cur_it: usize,
+    /// Elementos a saltar al cargar la página pendiente
+    skip: usize,
+    /// Elementos devueltos hasta el momento
+    seen: u64,
+    total_items: u64,
+    /// La página de `cur_filters` está pendiente de cargar
+    pending: bool,
+    failed: bool,
+    api: Arc<MailchimpApi>,
+    endpoint: String,
+}
+
+impl<B> TryMalchimpIter<B>
+where
+    B: BuildIter,
+    B::FilterItem: ResourceFilter,
+    B::Collection: MailchimpCollection<B::Item> + DeserializeOwned + Default,
+{
+    ///
+    /// Iterator over the collection of the endpoint, starting at the page of
+    /// the given filters. No request is sent until the first call to `next`.
+    ///
+    /// Arguments:
+    ///     builder: Builder of the items of the collection
+    ///     api: MailchimpApi
+    ///     endpoint: Endpoint of the collection, e.g. "lists"
+    ///     filters: Filter of the first page
+    ///
+    pub fn new(builder: B, api: Arc<MailchimpApi>, endpoint: &str, filters: B::FilterItem) -> Self {
+        TryMalchimpIter {
+            builder,
+            data: Vec::new(),
+            cur_filters: filters,
+            cur_it: 0,
+            skip: 0,
+            seen: 0,
+            total_items: 0,
+            pending: true,
+            failed: false,
+            api,
+            endpoint: endpoint.to_string(),
+        }
+    }
+
+    ///
+    /// Continue the iteration saved in the cursor. The page of the cursor is
+    /// requested on the first call to `next`, skipping the items already
+    /// returned.
+    ///
+    /// Arguments:
+    ///     builder: Builder of the items of the collection
+    ///     api: MailchimpApi
+    ///     cursor: Position returned by `cursor`
+    ///
+    pub fn from_cursor(
+        builder: B,
+        api: Arc<MailchimpApi>,
+        cursor: IterCursor<B::FilterItem>,
+    ) -> Self {
+        let mut iter = TryMalchimpIter::new(builder, api, &cursor.endpoint, cursor.filter);
+        iter.skip = cursor.page_offset as usize;
+        iter.seen = cursor.offset;
+        iter.total_items = cursor.total_items;
+        iter
+    }
+
+    ///
+    /// Position of the iterator, it can be saved to continue the iteration
+    /// later with `from_cursor`
+    ///
+    pub fn cursor(&self) -> IterCursor<B::FilterItem>
+    where
+        B::FilterItem: Clone,
+    {
+        IterCursor {
+            endpoint: self.endpoint.clone(),
+            filter: self.cur_filters.clone(),
+            page_offset: if self.pending {
+                self.skip as u64
+            } else {
+                self.cur_it as u64
+            },
+            offset: self.seen,
+            total_items: self.total_items,
+        }
+    }
+
+    ///
+    /// Total items in the collection, as reported by the last page loaded
+    ///
+    pub fn total_items(&self) -> u64 {
+        self.total_items
+    }
+
+    ///
+    /// Number of items returned so far
+    ///
+    pub fn position(&self) -> u64 {
+        self.seen
+    }
+
+    ///
+    /// Returns true if the last request failed
+    ///
+    pub fn is_failed(&self) -> bool {
+        self.failed
+    }
+
+    ///
+    /// Continue after an error, the next call to `next` requests the failed
+    /// page again
+    ///
+    pub fn resume(&mut self) {
+        self.failed = false;
+    }
+
+    fn fetch(&self) -> MailchimpResult<B::Collection> {
+        self.api
+            .get::<B::Collection>(&self.endpoint, self.cur_filters.build_payload())
+    }
+}
+
+impl<B> Iterator for TryMalchimpIter<B>
+where
+    B: BuildIter,
+    B::FilterItem: ResourceFilter,
+    B::Collection: MailchimpCollection<B::Item> + DeserializeOwned + Default,
+{
+    type Item = MailchimpResult<B::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        loop {
+            if self.cur_it < self.data.len() {
+                let data = &self.data[self.cur_it];
+                self.cur_it += 1;
+                self.seen += 1;
+                return Some(Ok(self.builder.update_item(data, self.api.clone())));
+            }
+            if !self.pending {
+                // Una página vacía también termina la iteración
+                if self.data.is_empty() || self.seen >= self.total_items {
+                    return None;
+                }
+                self.cur_filters = self.builder.update_filter_offset(&self.cur_filters);
+                self.pending = true;
+            }
+            match self.fetch() {
+                Ok(collection) => {
+                    self.total_items = collection.get_total_items();
+                    self.data = collection.get_values();
+                    self.cur_it = self.skip.min(self.data.len());
+                    self.skip = 0;
+                    self.pending = false;
+                }
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+///
+/// Page of a collection
+///
+#[derive(Debug, Clone)]
+pub struct MalchimpPage<T> {
+    /// Items of the page
+    pub items: Vec<T>,
+    /// Position of the first item of the page in the iteration
+    pub offset: u64,
+    /// Total items in the collection
+    pub total_items: u64,
+}
+
+///
+/// Mailchimp Page Iterator
+///
+/// Yields the pages of a collection, each page is dropped once consumed so
+/// the memory does not grow with the size of the collection. With
+/// `prefetch(true)` the next page is requested in a background thread while
+/// the current one is processed. The errors are returned like in
+/// `TryMalchimpIter`.
+///
+/// ```no_run
+/// use mailchimp::{Lists, MailchimpApi};
+///
+/// let lists = Lists::new(MailchimpApi::new("<API_KEY>"));
+/// let list = lists.get_list_info("57afe96172", Default::default()).unwrap();
+/// for page in list.get_members(None).pages().prefetch(true) {
+///     let page = page.unwrap();
+///     println!("{}/{}", page.offset + page.items.len() as u64, page.total_items);
+/// }
+/// ```
+///
+#[derive(Debug)]
+pub struct MalchimpPages<B>
+where
+    B: BuildIter,
+    B::FilterItem: ResourceFilter,
+{
+    builder: B,
+    /// Elementos ya cargados que se devuelven como primera página
+    first: Option<Vec<B::Item>>,
+    /// Filtro de la siguiente página
+    cur_filters: B::FilterItem,
+    /// Elementos devueltos hasta el momento
+    offset: u64,
+    total_items: u64,
+    done: bool,
+    failed: bool,
+    prefetch: bool,
+    /// Petición de la siguiente página en segundo plano
+    next_page: Option<JoinHandle<MailchimpResult<B::Collection>>>,
+    api: Arc<MailchimpApi>,
+    endpoint: String,
+}
+
+impl<B> MalchimpPages<B>
+where
+    B: BuildIter,
+    B::FilterItem: ResourceFilter,
+{
+    ///
+    /// Pages of the collection of the endpoint, starting at the page of the
+    /// given filters. No request is sent until the first call to `next`.
+    ///
+    /// Arguments:
+    ///     builder: Builder of the items of the collection
+    ///     api: MailchimpApi
+    ///     endpoint: Endpoint of the collection, e.g. "lists"
+    ///     filters: Filter of the first page
+    ///
+    pub fn new(builder: B, api: Arc<MailchimpApi>, endpoint: &str, filters: B::FilterItem) -> Self {
+        MalchimpPages {
+            builder,
+            first: None,
+            cur_filters: filters,
+            offset: 0,
+            total_items: 0,
+            done: false,
+            failed: false,
+            prefetch: false,
+            next_page: None,
+            api,
+            endpoint: endpoint.to_string(),
+        }
+    }
+
+    ///
+    /// Request the next page in a background thread while the current one
+    /// is processed
+    ///
+    pub fn prefetch(mut self, prefetch: bool) -> Self {
+        self.prefetch = prefetch;
+        self
+    }
+
+    ///
+    /// Number of items returned so far
+    ///
+    pub fn position(&self) -> u64 {
+        self.offset
+    }
+
+    ///
+    /// Continue after an error, the next call to `next` requests the failed
+    /// page again
+    ///
+    pub fn resume(&mut self) {
+        self.failed = false;
+    }
+}
+
+impl<B> MalchimpPages<B>
+where
+    B: BuildIter,
+    B::FilterItem: ResourceFilter,
+    B::Collection: MailchimpCollection<B::Item> + DeserializeOwned + Send + 'static,
+{
+    fn fetch(&mut self) -> MailchimpResult<B::Collection> {
+        match self.next_page.take() {
+            Some(handle) => handle.join().unwrap_or_else(|_| {
+                Err(MailchimpError::Transport(
+                    "the thread that requested the page panicked".into(),
+                ))
+            }),
+            None => self
+                .api
+                .get::<B::Collection>(&self.endpoint, self.cur_filters.build_payload()),
+        }
+    }
+
+    fn spawn_fetch(&self) -> JoinHandle<MailchimpResult<B::Collection>> {
+        let api = self.api.clone();
+        let endpoint = self.endpoint.clone();
+        let payload = self.cur_filters.build_payload();
+        thread::spawn(move || api.get::<B::Collection>(&endpoint, payload))
+    }
+
+    fn page(&mut self, data: Vec<B::Item>) -> MalchimpPage<B::Item> {
+        let items = data
+            .iter()
+            .map(|d| self.builder.update_item(d, self.api.clone()))
+            .collect::<Vec<B::Item>>();
+        let offset = self.offset;
+        self.offset += items.len() as u64;
+        self.done = data.is_empty() || self.offset >= self.total_items;
+        if self.prefetch && !self.done {
+            self.next_page = Some(self.spawn_fetch());
+        }
+        MalchimpPage {
+            items,
+            offset,
+            total_items: self.total_items,
+        }
+    }
+}
+
+impl<B> Iterator for MalchimpPages<B>
+where
+    B: BuildIter,
+    B::FilterItem: ResourceFilter,
+    B::Collection: MailchimpCollection<B::Item> + DeserializeOwned + Send + 'static,
+{
+    type Item = MailchimpResult<MalchimpPage<B::Item>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(data) = self.first.take() {
+            if !data.is_empty() {
+                return Some(Ok(self.page(data)));
+            }
+            self.done = self.offset >= self.total_items;
+        }
+        if self.done || self.failed {
+            return None;
+        }
+        match self.fetch() {
+            Ok(collection) => {
+                self.total_items = collection.get_total_items();
+                self.cur_filters = self.builder.update_filter_offset(&self.cur_filters);
+                Some(Ok(self.page(collection.get_values())))
+            }
             Err(e) => {
-                error!( target: "mailchimp",  "MailchimpEndpointIter from remote: Response Error details: {:?}", e);
-                B::Collection::default()
+                self.failed = true;
+                Some(Err(e))
             }
         }
     }
@@ -1,12 +1,11 @@
 use super::api::MailchimpApi;
 use super::internal::request::MailchimpResult;
 use super::types::{
-    CollectionLandingPage, LandingPageBuilder, LandingPage
+    LandingPageBuilder, LandingPage
 };
-use crate::iter::{MalchimpIter, SimpleFilter, ResourceFilter};
-use log::error;
+use crate::iter::{MalchimpIter, SimpleFilter};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Landing Pages
@@ -15,7 +14,7 @@ use std::rc::Rc;
 ///
 #[derive(Debug, Clone)]
 pub struct LandingPages {
-    api: Rc<MailchimpApi>,
+    api: Arc<MailchimpApi>,
 }
 
 impl LandingPages {
@@ -24,7 +23,7 @@ impl LandingPages {
     ///     api: MailchimpApi
     ///
     pub fn new(api: MailchimpApi) -> Self {
-        LandingPages { api: Rc::new(api) }
+        LandingPages { api: Arc::new(api) }
     }
 
     ///
@@ -42,32 +41,12 @@ impl LandingPages {
             filter_params = f;
         }
 
-        match self
-            .api
-            .get::<CollectionLandingPage>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: LandingPageBuilder {},
-                data: collection.landing_pages,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self.api.clone(),
-                endpoint: endpoint.to_string(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Activities: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: LandingPageBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self.api.clone(),
-                    endpoint: endpoint.to_string(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            LandingPageBuilder {},
+            self.api.clone(),
+            endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -13,25 +13,50 @@
 #![cfg_attr(not(debug_assertions), deny(warnings))]
 
 mod api;
+mod api_builder;
 mod api_root;
 mod authorized_apps;
 mod automations;
+mod batches;
+mod campaign_folders;
 mod campaigns;
 mod conversations;
+mod ecommerce;
+mod file_manager;
 mod internal;
 pub mod iter;
 mod landing_pages;
 mod list;
+mod oauth;
+#[cfg(feature = "async")]
+pub mod nonblocking;
 mod reports;
+mod search;
+mod template_folders;
+mod templates;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod transport;
 pub mod types;
 
 pub use crate::api::MailchimpApi;
+pub use crate::api_builder::MailchimpApiBuilder;
 pub use crate::api_root::ApiRoot;
-pub use crate::authorized_apps::{AuthorizedApps, AuthorizedFilter};
-pub use crate::automations::{Automations, AutomationsFilter};
-pub use crate::campaigns::{CampaignFilter, Campaigns};
+pub use crate::authorized_apps::{AuthorizedApps, AuthorizedAppsBuilder, AuthorizedFilter};
+pub use crate::automations::{Automations, AutomationsBuilder, AutomationsFilter};
+pub use crate::batches::{parse_batch_results, Batches};
+pub use crate::campaign_folders::CampaignFolders;
+pub use crate::campaigns::{CampaignFilter, Campaigns, CampaignsBuilder};
 pub use crate::conversations::Conversations;
-pub use crate::internal::error_type::MailchimpErrorType;
+pub use crate::ecommerce::Ecommerce;
+pub use crate::file_manager::{FileManager, FileManagerFilesBuilder, FileManagerFilter};
+pub use crate::internal::error_type::{MailchimpError, MailchimpErrorType};
+pub use crate::internal::retry::RetryPolicy;
 pub use crate::landing_pages::LandingPages;
-pub use crate::list::{ListFilter, Lists};
+pub use crate::list::{ListFilter, Lists, ListsBuilder};
+pub use crate::oauth::OAuthClient;
 pub use crate::reports::Reports;
+pub use crate::search::Search;
+pub use crate::template_folders::TemplateFolders;
+pub use crate::templates::{TemplateFilter, Templates, TemplatesBuilder};
+pub use crate::types::subscriber_hash;
@@ -28,13 +28,14 @@
 use super::api::{MailchimpApi, MailchimpApiUpdate};
 use super::internal::request::MailchimpResult;
 use super::iter::{BuildIter, MalchimpIter, ResourceFilter};
-use super::types::{ListParam, ListType, ListsType};
+use super::types::{format_datetime, ListParam, ListType, ListsType, MailchimpDateTime, SortDir};
 use log::error;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /// List Filter
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ListFilter {
     /// A comma-separated list of fields to return.
     /// Reference parameters of sub-objects with dot notation.
@@ -54,23 +55,23 @@ pub struct ListFilter {
     pub status: Option<String>,
     /// Restrict the response to campaigns sent before the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub before_send_time: Option<String>,
+    pub before_send_time: Option<MailchimpDateTime>,
     /// Restrict the response to campaigns sent after the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub since_send_time: Option<String>,
+    pub since_send_time: Option<MailchimpDateTime>,
     /// Restrict the response to campaigns created before the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub before_create_time: Option<String>,
+    pub before_create_time: Option<MailchimpDateTime>,
     /// Restrict the response to campaigns created after the set time. We recommend
     /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub since_create_time: Option<String>,
+    pub since_create_time: Option<MailchimpDateTime>,
     /// Restrict results to lists that include a specific subscriber’s email address.
     pub email: Option<String>,
     /// Returns files sorted by the specified field.
     pub sort_field: Option<String>,
     /// Determines the order direction for sorted results.
     /// ASC DESC
-    pub sort_dir: Option<String>,
+    pub sort_dir: Option<SortDir>,
 }
 
 impl Default for ListFilter {
@@ -127,30 +128,18 @@ impl ResourceFilter for ListFilter {
         if self.status.is_some() {
             payload.insert("status".to_string(), self.status.as_ref().unwrap().clone());
         }
-        if self.before_send_time.is_some() {
-            payload.insert(
-                "before_send_time".to_string(),
-                self.before_send_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.before_send_time {
+            payload.insert("before_send_time".to_string(), format_datetime(v));
         }
-        if self.since_send_time.is_some() {
-            payload.insert(
-                "since_send_time".to_string(),
-                self.since_send_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.since_send_time {
+            payload.insert("since_send_time".to_string(), format_datetime(v));
         }
-        if self.before_create_time.is_some() {
-            payload.insert(
-                "before_create_time".to_string(),
-                self.before_create_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.before_create_time {
+            payload.insert("before_create_time".to_string(), format_datetime(v));
         }
 
-        if self.since_create_time.is_some() {
-            payload.insert(
-                "since_create_time".to_string(),
-                self.since_create_time.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.since_create_time {
+            payload.insert("since_create_time".to_string(), format_datetime(v));
         }
 
         if self.email.is_some() {
@@ -168,11 +157,8 @@ impl ResourceFilter for ListFilter {
                 self.sort_field.as_ref().unwrap().clone(),
             );
         }
-        if self.sort_dir.is_some() {
-            payload.insert(
-                "sort_dir".to_string(),
-                self.sort_dir.as_ref().unwrap().clone(),
-            );
+        if let Some(v) = &self.sort_dir {
+            payload.insert("sort_dir".to_string(), v.to_string());
         }
 
         payload
@@ -187,9 +173,12 @@ impl ResourceFilter for ListFilter {
 ///
 #[derive(Debug, Clone)]
 pub struct Lists {
-    api: Rc<MailchimpApi>,
+    api: Arc<MailchimpApi>,
 }
 
+///
+/// ListsBuilder
+///
 #[derive(Debug)]
 pub struct ListsBuilder {}
 
@@ -201,7 +190,7 @@ impl BuildIter for ListsBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data
@@ -223,7 +212,7 @@ impl Lists {
     ///     api: MailchimpApi
     ///
     pub fn new(api: MailchimpApi) -> Self {
-        Lists { api: Rc::new(api) }
+        Lists { api: Arc::new(api) }
     }
 
     ///
@@ -303,25 +292,6 @@ impl Lists {
     /// anterior esta función te devuelve un iterador
     ///
     pub fn iter(&self, filters: ListFilter) -> MalchimpIter<ListsBuilder> {
-        if let Some(remote) = self.get_campaigns_from_remote(Some(&filters)) {
-            return MalchimpIter {
-                builder: ListsBuilder {},
-                data: remote.lists,
-                cur_filters: filters.clone(),
-                cur_it: 0,
-                total_items: remote.total_items,
-                api: self.api.clone(),
-                endpoint: "lists".to_string(),
-            };
-        }
-        MalchimpIter {
-            builder: ListsBuilder {},
-            data: Vec::new(),
-            cur_filters: filters.clone(),
-            cur_it: 0,
-            total_items: 0,
-            api: self.api.clone(),
-            endpoint: "lists".to_string(),
-        }
+        MalchimpIter::from_endpoint(ListsBuilder {}, self.api.clone(), "lists", filters)
     }
 }
@@ -0,0 +1,215 @@
+use crate::api_builder::MailchimpApiBuilder;
+use crate::internal::api::{parse_api_key, split_api_key};
+use crate::internal::async_api::AsyncApi;
+use crate::internal::async_request::{AsyncHttpReq, MailchimpAsyncRequest};
+use crate::internal::error_type::MailchimpError;
+use crate::types::Ping;
+use serde::de::DeserializeOwned;
+use serde::ser::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Async Mailchimp API
+///
+/// Same as `mailchimp::MailchimpApi` but every request returns a future.
+/// The requests are sent with the async reqwest client, so they must be
+/// awaited inside a tokio runtime.
+///
+/// ## Example
+///
+/// ```
+/// use mailchimp::nonblocking::MailchimpApi;
+///
+/// let api = MailchimpApi::new("<API Key>");
+/// println!("Api version: {}", api.version());
+/// println!("Api domain: {}", api.domain());
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct MailchimpApi {
+    i_api: Box<AsyncApi>,
+}
+
+impl MailchimpApi {
+    ///
+    /// Create a new instance of the API
+    ///
+    /// The API KEY is not validated, when it has no datacenter "usX" is used
+    /// and the requests fail later. Prefer `try_new`.
+    ///
+    /// Arguments:
+    ///     api_key: Mailchimp API KEY
+    ///
+    pub fn new(api_key: &str) -> Self {
+        MailchimpApi::with_transport(api_key, MailchimpAsyncRequest::new())
+    }
+
+    ///
+    /// Create a new instance of the API, validating the `<key>-<dc>` format
+    /// of the API KEY
+    ///
+    /// Arguments:
+    ///     api_key: Mailchimp API KEY
+    ///
+    pub fn try_new(api_key: &str) -> Result<Self, MailchimpError> {
+        let (key, dc) = parse_api_key(api_key)?;
+        Ok(MailchimpApi::from_api(AsyncApi::new(
+            dc,
+            key,
+            Arc::new(MailchimpAsyncRequest::new()),
+        )))
+    }
+
+    ///
+    /// Create a new instance of the API that sends the requests through the
+    /// given transport
+    ///
+    /// Arguments:
+    ///     api_key: Mailchimp API KEY
+    ///     transport: Implementation of `AsyncHttpReq` used to send the requests
+    ///
+    pub fn with_transport<R>(api_key: &str, transport: R) -> Self
+    where
+        R: AsyncHttpReq + 'static,
+    {
+        let (key, dc) = split_api_key(api_key);
+        MailchimpApi {
+            i_api: Box::new(AsyncApi::new(dc, key, Arc::new(transport))),
+        }
+    }
+    ///
+    /// Create a new instance of the API authenticated with an OAuth2 token,
+    /// the datacenter of the account is returned by `OAuthClient::metadata`.
+    /// Returns `MailchimpError::InvalidApiKey` if the token is not a valid
+    /// header value
+    ///
+    /// Arguments:
+    ///     access_token: OAuth2 token of the account
+    ///     dc: Datacenter of the account, e.g. `us6`
+    ///
+    pub fn with_oauth_token(access_token: &str, dc: &str) -> Result<Self, MailchimpError> {
+        let mut api = AsyncApi::new(dc, "", Arc::new(MailchimpAsyncRequest::new()));
+        api.set_bearer_token(access_token)?;
+        Ok(MailchimpApi::from_api(api))
+    }
+
+    ///
+    /// Returns a builder to configure the HTTP client and the server, finish
+    /// it with `MailchimpApiBuilder::build_async`
+    ///
+    pub fn builder() -> MailchimpApiBuilder {
+        MailchimpApiBuilder::new()
+    }
+
+    ///
+    /// Create the instance from an already configured internal API
+    ///
+    pub(crate) fn from_api(api: AsyncApi) -> Self {
+        MailchimpApi {
+            i_api: Box::new(api),
+        }
+    }
+
+    ///
+    /// Returns the domain
+    ///
+    pub fn domain(&self) -> String {
+        self.i_api.domain()
+    }
+
+    ///
+    /// Returns the API version
+    ///
+    pub fn version(&self) -> String {
+        self.i_api.api_version()
+    }
+
+    ///
+    /// Send a POST request
+    ///
+    /// #Arguments
+    ///     `endpoint`: API endpoint to access, it must not start with "/"
+    ///     `payload`: Data to send to the server
+    ///
+    pub async fn post<T, P>(&self, endpoint: &str, payload: P) -> Result<T, MailchimpError>
+    where
+        T: DeserializeOwned,
+        P: Serialize,
+    {
+        self.i_api.post_edge::<T, P>(endpoint, payload).await
+    }
+
+    ///
+    /// Send a PATCH request
+    ///
+    /// #Arguments
+    ///     `endpoint`: API endpoint to access, it must not start with "/"
+    ///     `payload`: Data to send to the server
+    ///
+    pub async fn patch<T, P>(&self, endpoint: &str, payload: P) -> Result<T, MailchimpError>
+    where
+        T: DeserializeOwned,
+        P: Serialize,
+    {
+        self.i_api.patch_edge::<T, P>(endpoint, payload).await
+    }
+
+    ///
+    /// Send a PUT request
+    ///
+    /// #Arguments
+    ///     `endpoint`: API endpoint to access, it must not start with "/"
+    ///     `payload`: Data to send to the server
+    ///
+    pub async fn put<T, P>(&self, endpoint: &str, payload: P) -> Result<T, MailchimpError>
+    where
+        T: DeserializeOwned,
+        P: Serialize,
+    {
+        self.i_api.put_edge::<T, P>(endpoint, payload).await
+    }
+
+    ///
+    /// Send a GET request
+    ///
+    /// #Arguments
+    ///     `endpoint`: API endpoint to access, it must not start with "/"
+    ///     `payload`: Query string parameters
+    ///
+    pub async fn get<T>(
+        &self,
+        endpoint: &str,
+        payload: HashMap<String, String>,
+    ) -> Result<T, MailchimpError>
+    where
+        T: DeserializeOwned,
+    {
+        self.i_api.get_edge(endpoint, payload).await
+    }
+
+    ///
+    /// Send a DELETE request
+    ///
+    /// #Arguments
+    ///     `endpoint`: API endpoint to access, it must not start with "/"
+    ///     `payload`: Query string parameters
+    ///
+    pub async fn delete<T>(
+        &self,
+        endpoint: &str,
+        payload: HashMap<String, String>,
+    ) -> Result<T, MailchimpError>
+    where
+        T: DeserializeOwned,
+    {
+        self.i_api.delete_edge(endpoint, payload).await
+    }
+
+    ///
+    /// A health check for the API that won’t return any account-specific information.
+    ///
+    pub async fn ping(&self) -> Result<Ping, MailchimpError> {
+        self.get::<Ping>("ping", HashMap::new()).await
+    }
+}
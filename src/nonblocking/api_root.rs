@@ -0,0 +1,39 @@
+use super::api::MailchimpApi;
+use crate::internal::request::MailchimpResult;
+use crate::types::ApiRootType;
+use std::collections::HashMap;
+
+///
+/// Async version of the Mailchimp API Root Endpoint
+///
+/// The API root resource links to all other resources available in the API.
+/// Calling the root directory also returns details about the Mailchimp user account.
+///
+#[derive(Debug, Clone)]
+pub struct ApiRoot {
+    api: MailchimpApi,
+}
+
+impl ApiRoot {
+    ///
+    /// Arguments:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        ApiRoot { api }
+    }
+
+    ///
+    /// Returns the account details and the links to the related resources
+    ///
+    /// Arguments:
+    ///     filters:
+    ///         fields: A comma-separated list of fields to return. Reference
+    ///             parameters of sub-objects with dot notation.
+    ///         exclude_fields: A comma-separated list of fields to exclude.
+    ///             Reference parameters of sub-objects with dot notation.
+    ///
+    pub async fn get_info(&self, filters: HashMap<String, String>) -> MailchimpResult<ApiRootType> {
+        self.api.get::<ApiRootType>("", filters).await
+    }
+}
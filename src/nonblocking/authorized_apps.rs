@@ -0,0 +1,101 @@
+use super::api::MailchimpApi;
+use super::stream::MailchimpStream;
+use crate::authorized_apps::AuthorizedFilter;
+use crate::internal::request::MailchimpResult;
+use crate::iter::ResourceFilter;
+use crate::types::{AuthorizedAppType, AuthorizedAppsType, CreatedAuthorizedAppType};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Async version of the Mailchimp Authorized Apps Endpoint
+///
+/// Manage registered, connected apps for your Mailchimp account with the Authorized Apps endpoints.
+///
+#[derive(Debug, Clone)]
+pub struct AuthorizedApps {
+    api: Arc<MailchimpApi>,
+}
+
+impl AuthorizedApps {
+    ///
+    /// Arguments:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        AuthorizedApps { api: Arc::new(api) }
+    }
+
+    ///
+    /// Get a list of an account’s registered, connected applications.
+    ///
+    /// Arguments:
+    ///     filters: Filters, use `offset` and `count` to go through the pages
+    ///
+    pub async fn get_authorized_apps(
+        &self,
+        filters: Option<&AuthorizedFilter>,
+    ) -> MailchimpResult<AuthorizedAppsType> {
+        let payload = filters.map(|f| f.build_payload()).unwrap_or_default();
+        self.api
+            .get::<AuthorizedAppsType>("authorized-apps", payload)
+            .await
+    }
+
+    ///
+    /// Stream over all the registered, connected applications of the account,
+    /// the pages are requested while the stream is polled.
+    ///
+    /// Arguments:
+    ///     filters: Filter of the first page
+    ///
+    pub fn iter(
+        &self,
+        filters: AuthorizedFilter,
+    ) -> MailchimpStream<AuthorizedAppsType, AuthorizedAppType> {
+        MailchimpStream::new(self.api.clone(), "authorized-apps", &filters)
+    }
+
+    ///
+    /// Retrieve OAuth2-based credentials to associate API calls with your application.
+    ///
+    /// Arguments:
+    ///     client_id: The client id for the authorization
+    ///     client_secret: The client secret for the authorization
+    ///
+    pub async fn link_authorized_apps(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+    ) -> MailchimpResult<CreatedAuthorizedAppType> {
+        let mut payload = HashMap::new();
+        payload.insert("client_id".to_string(), client_id.to_string());
+        payload.insert("client_secret".to_string(), client_secret.to_string());
+
+        self.api
+            .post::<CreatedAuthorizedAppType, HashMap<String, String>>("authorized-apps", payload)
+            .await
+    }
+
+    ///
+    /// Get information about a specific authorized app
+    ///
+    /// Arguments:
+    ///     app_id: The unique id for the connected authorized application.
+    ///     filters:
+    ///         fields: A comma-separated list of fields to return. Reference
+    ///             parameters of sub-objects with dot notation.
+    ///         exclude_fields: A comma-separated list of fields to exclude.
+    ///             Reference parameters of sub-objects with dot notation.
+    ///
+    pub async fn get_authorized_app_info(
+        &self,
+        app_id: &str,
+        filters: HashMap<String, String>,
+    ) -> MailchimpResult<AuthorizedAppType> {
+        let endpoint = String::from("authorized-apps/") + app_id;
+        self.api
+            .get::<AuthorizedAppType>(endpoint.as_str(), filters)
+            .await
+    }
+}
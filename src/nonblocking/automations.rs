@@ -0,0 +1,110 @@
+use super::api::MailchimpApi;
+use super::stream::MailchimpStream;
+use crate::automations::AutomationsFilter;
+use crate::internal::request::MailchimpResult;
+use crate::iter::ResourceFilter;
+use crate::types::{
+    AutomationCampaignSettingsType, AutomationModifier, AutomationTriggerType,
+    AutomationWorkflowType, CollectionAutomation, RecipientType,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Async version of the Mailchimp Automations Endpoint
+///
+/// Mailchimp’s free Automation feature lets you build a series of
+/// emails that send to subscribers when triggered by a specific date, activity,
+/// or event. Use the API to manage Automation workflows, emails, and queues.
+///
+#[derive(Debug, Clone)]
+pub struct Automations {
+    api: Arc<MailchimpApi>,
+}
+
+impl Automations {
+    ///
+    /// Arguments:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        Automations { api: Arc::new(api) }
+    }
+
+    ///
+    /// Get a summary of an account’s Automations.
+    ///
+    /// Arguments:
+    ///     filters: Filters, use `offset` and `count` to go through the pages
+    ///
+    pub async fn get_automations(
+        &self,
+        filters: Option<&AutomationsFilter>,
+    ) -> MailchimpResult<CollectionAutomation> {
+        let payload = filters.map(|f| f.build_payload()).unwrap_or_default();
+        self.api
+            .get::<CollectionAutomation>("automations", payload)
+            .await
+    }
+
+    ///
+    /// Stream over all Automations in the account, the pages are requested
+    /// while the stream is polled.
+    ///
+    /// Arguments:
+    ///     filters: Filter of the first page
+    ///
+    pub fn iter(
+        &self,
+        filters: AutomationsFilter,
+    ) -> MailchimpStream<CollectionAutomation, AutomationWorkflowType> {
+        MailchimpStream::new(self.api.clone(), "automations", &filters)
+    }
+
+    ///
+    /// Get a summary of an individual Automation workflow’s settings and content.
+    ///
+    /// Arguments:
+    ///     workflow_id: The unique id for the Automation workflow.
+    ///     filters:
+    ///         fields: A comma-separated list of fields to return. Reference
+    ///             parameters of sub-objects with dot notation.
+    ///         exclude_fields: A comma-separated list of fields to exclude.
+    ///             Reference parameters of sub-objects with dot notation.
+    ///
+    pub async fn get_automation_workflow_info(
+        &self,
+        workflow_id: &str,
+        filters: HashMap<String, String>,
+    ) -> MailchimpResult<AutomationWorkflowType> {
+        let endpoint = String::from("automations/") + workflow_id;
+        self.api
+            .get::<AutomationWorkflowType>(endpoint.as_str(), filters)
+            .await
+    }
+
+    ///
+    /// Create a new Automation in your Mailchimp account.
+    ///
+    /// Arguments:
+    ///     recipients: List settings for the Automation.
+    ///     trigger_settings: Trigger settings for the Automation.
+    ///     settings: The settings for the Automation workflow.
+    ///
+    pub async fn create_automation(
+        &self,
+        recipients: RecipientType,
+        trigger_settings: AutomationTriggerType,
+        settings: Option<AutomationCampaignSettingsType>,
+    ) -> MailchimpResult<AutomationWorkflowType> {
+        let modifier = AutomationModifier {
+            settings,
+            delay: None,
+            recipients: Some(recipients),
+            trigger_settings: Some(trigger_settings),
+        };
+        self.api
+            .post::<AutomationWorkflowType, AutomationModifier>("automations", modifier)
+            .await
+    }
+}
@@ -0,0 +1,215 @@
+use super::api::MailchimpApi;
+use super::stream::MailchimpStream;
+use crate::campaigns::CampaignFilter;
+use crate::internal::request::MailchimpResult;
+use crate::iter::ResourceFilter;
+use crate::types::{
+    CampaignContentParam, CampaignContentType, CampaignType, CampaignsType, EmailParam, EmptyType,
+    ScheduleParam, UpdateCampaignParam,
+};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Async version of the Mailchimp Campaigns Endpoint
+///
+/// Campaigns are how you send emails to your Mailchimp list. Use the Campaigns
+/// API calls to manage campaigns in your Mailchimp account.
+///
+#[derive(Debug, Clone)]
+pub struct Campaigns {
+    api: Arc<MailchimpApi>,
+}
+
+impl Campaigns {
+    ///
+    /// Arguments:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        Campaigns { api: Arc::new(api) }
+    }
+
+    ///
+    /// Get information about a specific campaign.
+    ///
+    /// Arguments:
+    ///     campaign_id: The unique id for the campaign.
+    ///     filters:
+    ///         fields: A comma-separated list of fields to return. Reference
+    ///             parameters of sub-objects with dot notation.
+    ///         exclude_fields: A comma-separated list of fields to exclude.
+    ///             Reference parameters of sub-objects with dot notation.
+    ///
+    pub async fn get_campaign_info(
+        &self,
+        campaign_id: &str,
+        filters: HashMap<String, String>,
+    ) -> MailchimpResult<CampaignType> {
+        let endpoint = String::from("campaigns/") + campaign_id;
+        self.api
+            .get::<CampaignType>(endpoint.as_str(), filters)
+            .await
+    }
+
+    ///
+    /// Get all campaigns in an account.
+    ///
+    /// Arguments:
+    ///     filters: Filters, use `offset` and `count` to go through the pages
+    ///
+    pub async fn get_campaigns(
+        &self,
+        filters: Option<&CampaignFilter>,
+    ) -> MailchimpResult<CampaignsType> {
+        let payload = filters.map(|f| f.build_payload()).unwrap_or_default();
+        self.api.get::<CampaignsType>("campaigns", payload).await
+    }
+
+    ///
+    /// Stream over all campaigns in the account, the pages are requested
+    /// while the stream is polled.
+    ///
+    /// Arguments:
+    ///     filters: Filter of the first page
+    ///
+    pub fn iter(&self, filters: CampaignFilter) -> MailchimpStream<CampaignsType, CampaignType> {
+        MailchimpStream::new(self.api.clone(), "campaigns", &filters)
+    }
+
+    ///
+    /// Update some or all of the settings for a specific campaign.
+    ///
+    pub async fn update_campaign(
+        &self,
+        campaign_id: &str,
+        param: UpdateCampaignParam,
+    ) -> MailchimpResult<CampaignType> {
+        // PATCH /campaigns/{campaign_id}
+        let endpoint = format!("campaigns/{}", campaign_id);
+        self.api
+            .patch::<CampaignType, UpdateCampaignParam>(&endpoint, param)
+            .await
+    }
+
+    ///
+    /// Remove a campaign from your Mailchimp account.
+    ///
+    pub async fn delete_campaign(&self, campaign_id: &str) -> MailchimpResult<EmptyType> {
+        // DELETE /campaigns/{campaign_id}
+        let endpoint = format!("campaigns/{}", campaign_id);
+        self.api
+            .delete::<EmptyType>(&endpoint, HashMap::new())
+            .await
+    }
+
+    ///
+    /// Send a Mailchimp campaign. For RSS Campaigns, the campaign will send
+    /// according to its schedule. All other campaigns will send immediately.
+    ///
+    pub async fn send_campaign(&self, campaign_id: &str) -> MailchimpResult<EmptyType> {
+        // POST /campaigns/{campaign_id}/actions/send
+        self.action::<EmptyType>(campaign_id, "send").await
+    }
+
+    ///
+    /// Schedule a campaign for delivery. If you’re using Multivariate Campaigns to
+    /// test send times or sending RSS Campaigns, use the send action instead.
+    ///
+    pub async fn schedule_campaign(
+        &self,
+        campaign_id: &str,
+        param: ScheduleParam,
+    ) -> MailchimpResult<EmptyType> {
+        // POST /campaigns/{campaign_id}/actions/schedule
+        let endpoint = format!("campaigns/{}/actions/schedule", campaign_id);
+        self.api
+            .post::<EmptyType, ScheduleParam>(&endpoint, param)
+            .await
+    }
+
+    ///
+    /// Unschedule a scheduled campaign that hasn’t started sending.
+    ///
+    pub async fn unschedule_campaign(&self, campaign_id: &str) -> MailchimpResult<EmptyType> {
+        // POST /campaigns/{campaign_id}/actions/unschedule
+        self.action::<EmptyType>(campaign_id, "unschedule").await
+    }
+
+    ///
+    /// Cancel a Regular or Plain-Text Campaign after you send, before all of
+    /// your recipients receive it.
+    ///
+    pub async fn cancel_campaign(&self, campaign_id: &str) -> MailchimpResult<EmptyType> {
+        // POST /campaigns/{campaign_id}/actions/cancel-send
+        self.action::<EmptyType>(campaign_id, "cancel-send").await
+    }
+
+    ///
+    /// Replicate a campaign in saved or send status.
+    ///
+    pub async fn replicate_campaign(&self, campaign_id: &str) -> MailchimpResult<CampaignType> {
+        // POST /campaigns/{campaign_id}/actions/replicate
+        self.action::<CampaignType>(campaign_id, "replicate").await
+    }
+
+    ///
+    /// Send a test email.
+    ///
+    pub async fn send_test_email(
+        &self,
+        campaign_id: &str,
+        param: EmailParam,
+    ) -> MailchimpResult<EmptyType> {
+        // POST /campaigns/{campaign_id}/actions/test
+        let endpoint = format!("campaigns/{}/actions/test", campaign_id);
+        self.api
+            .post::<EmptyType, EmailParam>(&endpoint, param)
+            .await
+    }
+
+    ///
+    /// Get the the HTML and plain-text content for a campaign.
+    ///
+    /// Arguments:
+    ///     campaign_id: The unique id for the campaign.
+    ///     filters: `fields` and `exclude_fields` of the response
+    ///
+    pub async fn get_content(
+        &self,
+        campaign_id: &str,
+        filters: HashMap<String, String>,
+    ) -> MailchimpResult<CampaignContentType> {
+        // GET /campaigns/{campaign_id}/content
+        let endpoint = format!("campaigns/{}/content", campaign_id);
+        self.api
+            .get::<CampaignContentType>(&endpoint, filters)
+            .await
+    }
+
+    ///
+    /// Set the content for a campaign.
+    ///
+    pub async fn update_content(
+        &self,
+        campaign_id: &str,
+        param: CampaignContentParam,
+    ) -> MailchimpResult<CampaignContentType> {
+        // PUT /campaigns/{campaign_id}/content
+        let endpoint = format!("campaigns/{}/content", campaign_id);
+        self.api
+            .put::<CampaignContentType, CampaignContentParam>(&endpoint, param)
+            .await
+    }
+
+    async fn action<T>(&self, campaign_id: &str, action: &str) -> MailchimpResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        let endpoint = format!("campaigns/{}/actions/{}", campaign_id, action);
+        self.api
+            .post::<T, HashMap<String, String>>(&endpoint, HashMap::new())
+            .await
+    }
+}
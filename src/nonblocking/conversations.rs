@@ -0,0 +1,72 @@
+use super::api::MailchimpApi;
+use super::stream::MailchimpStream;
+use crate::internal::request::MailchimpResult;
+use crate::iter::ResourceFilter;
+use crate::types::{CollectionConversations, Conversation, ConversationsFilter};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Async version of the Mailchimp Conversations Endpoint
+///
+/// Conversation tracking is a feature available to paid accounts that lets
+/// you view replies to your campaigns in your Mailchimp account.
+///
+#[derive(Debug, Clone)]
+pub struct Conversations {
+    api: Arc<MailchimpApi>,
+}
+
+impl Conversations {
+    ///
+    /// Arguments:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        Conversations { api: Arc::new(api) }
+    }
+
+    ///
+    /// Get a list of conversations for the account.
+    ///
+    /// Arguments:
+    ///     filter: Filters, use `offset` and `count` to go through the pages
+    ///
+    pub async fn get_conversations(
+        &self,
+        filter: Option<ConversationsFilter>,
+    ) -> MailchimpResult<CollectionConversations> {
+        let payload = filter.unwrap_or_default().build_payload();
+        self.api
+            .get::<CollectionConversations>("conversations", payload)
+            .await
+    }
+
+    ///
+    /// Stream over all conversations of the account, the pages are requested
+    /// while the stream is polled.
+    ///
+    /// Arguments:
+    ///     filter: Filter of the first page
+    ///
+    pub fn iter(
+        &self,
+        filter: Option<ConversationsFilter>,
+    ) -> MailchimpStream<CollectionConversations, Conversation> {
+        MailchimpStream::new(
+            self.api.clone(),
+            "conversations",
+            &filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get details about an individual conversation.
+    ///
+    pub async fn get_conversation(&self, conversation_id: &str) -> MailchimpResult<Conversation> {
+        let endpoint = format!("conversations/{}", conversation_id);
+        self.api
+            .get::<Conversation>(&endpoint, HashMap::new())
+            .await
+    }
+}
@@ -0,0 +1,69 @@
+use super::api::MailchimpApi;
+use super::stream::MailchimpStream;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{ResourceFilter, SimpleFilter};
+use crate::types::{CollectionLandingPage, LandingPage};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Async version of the Landing Pages Endpoint
+///
+/// Manage your Landing Pages, including publishing and unpublishing.
+///
+#[derive(Debug, Clone)]
+pub struct LandingPages {
+    api: Arc<MailchimpApi>,
+}
+
+impl LandingPages {
+    ///
+    /// Arguments:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        LandingPages { api: Arc::new(api) }
+    }
+
+    ///
+    /// Get a list of landing pages
+    ///
+    /// Arguments:
+    ///     filter: Filters, use `offset` and `count` to go through the pages
+    ///
+    pub async fn get_pages(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MailchimpResult<CollectionLandingPage> {
+        let payload = filter.unwrap_or_default().build_payload();
+        self.api
+            .get::<CollectionLandingPage>("landing-pages", payload)
+            .await
+    }
+
+    ///
+    /// Stream over all landing pages, the pages are requested while the
+    /// stream is polled.
+    ///
+    /// Arguments:
+    ///     filter: Filter of the first page
+    ///
+    pub fn iter(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MailchimpStream<CollectionLandingPage, LandingPage> {
+        MailchimpStream::new(
+            self.api.clone(),
+            "landing-pages",
+            &filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific page
+    ///
+    pub async fn get_landing_page(&self, page_id: &str) -> MailchimpResult<LandingPage> {
+        let endpoint = format!("landing-pages/{}", page_id);
+        self.api.get::<LandingPage>(&endpoint, HashMap::new()).await
+    }
+}
@@ -0,0 +1,284 @@
+use super::api::MailchimpApi;
+use super::stream::MailchimpStream;
+use crate::internal::request::MailchimpResult;
+use crate::iter::ResourceFilter;
+use crate::list::ListFilter;
+use crate::types::{
+    subscriber_hash, CollectionListMembers, CollectionListMergeField, EmptyType, ListMember,
+    ListMemberParams, ListMembersFilter, ListMergeFieldFilter, ListParam, ListType, ListsType,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Async version of the Mailchimp Lists Endpoint
+///
+/// A Mailchimp list is a powerful and flexible tool that helps you manage your
+/// contacts. Learn how to get started with lists in Mailchimp.
+///
+#[derive(Debug, Clone)]
+pub struct Lists {
+    api: Arc<MailchimpApi>,
+}
+
+impl Lists {
+    ///
+    /// Arguments:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        Lists { api: Arc::new(api) }
+    }
+
+    ///
+    /// Create a new list in your Mailchimp account.
+    ///
+    pub async fn create_list(&self, param: ListParam) -> MailchimpResult<ListType> {
+        self.api.post::<ListType, ListParam>("lists", param).await
+    }
+
+    ///
+    /// Get information about a specific list in your Mailchimp account.
+    ///
+    /// Arguments:
+    ///     list_id: The unique id for the list.
+    ///     filters:
+    ///         fields: A comma-separated list of fields to return. Reference
+    ///             parameters of sub-objects with dot notation.
+    ///         exclude_fields: A comma-separated list of fields to exclude.
+    ///             Reference parameters of sub-objects with dot notation.
+    ///
+    pub async fn get_list_info(
+        &self,
+        list_id: &str,
+        filters: HashMap<String, String>,
+    ) -> MailchimpResult<ListType> {
+        let endpoint = String::from("lists/") + list_id;
+        self.api.get::<ListType>(endpoint.as_str(), filters).await
+    }
+
+    ///
+    /// Get information about all lists in the account.
+    ///
+    /// Arguments:
+    ///     filters: Filters, use `offset` and `count` to go through the pages
+    ///
+    pub async fn get_lists(&self, filters: Option<&ListFilter>) -> MailchimpResult<ListsType> {
+        let payload = filters.map(|f| f.build_payload()).unwrap_or_default();
+        self.api.get::<ListsType>("lists", payload).await
+    }
+
+    ///
+    /// Stream over all lists in the account, the pages are requested while
+    /// the stream is polled.
+    ///
+    /// Arguments:
+    ///     filters: Filter of the first page
+    ///
+    pub fn iter(&self, filters: ListFilter) -> MailchimpStream<ListsType, ListType> {
+        MailchimpStream::new(self.api.clone(), "lists", &filters)
+    }
+
+    ///
+    /// Update the settings for a specific list.
+    ///
+    pub async fn update_list(&self, list_id: &str, param: ListParam) -> MailchimpResult<ListType> {
+        // PATCH /lists/{list_id}
+        let endpoint = String::from("lists/") + list_id;
+        self.api
+            .patch::<ListType, ListParam>(endpoint.as_str(), param)
+            .await
+    }
+
+    ///
+    /// Delete a list from your Mailchimp account.
+    ///
+    pub async fn delete_list(&self, list_id: &str) -> MailchimpResult<EmptyType> {
+        // DELETE /lists/{list_id}
+        let endpoint = String::from("lists/") + list_id;
+        self.api
+            .delete::<EmptyType>(endpoint.as_str(), HashMap::new())
+            .await
+    }
+
+    ///
+    /// Get information about members in a list
+    ///
+    /// Arguments:
+    ///     list_id: The unique id for the list.
+    ///     filter: Params to filter the response, use `offset` and `count` to
+    ///         go through the pages
+    ///
+    pub async fn get_members(
+        &self,
+        list_id: &str,
+        filter: Option<ListMembersFilter>,
+    ) -> MailchimpResult<CollectionListMembers> {
+        // GET /lists/{list_id}/members
+        let endpoint = format!("lists/{}/members", list_id);
+        let payload = filter.unwrap_or_default().build_payload();
+        self.api
+            .get::<CollectionListMembers>(&endpoint, payload)
+            .await
+    }
+
+    ///
+    /// Stream over the members of a list, the pages are requested while the
+    /// stream is polled.
+    ///
+    /// Arguments:
+    ///     list_id: The unique id for the list.
+    ///     filter: Filter of the first page
+    ///
+    pub fn iter_members(
+        &self,
+        list_id: &str,
+        filter: Option<ListMembersFilter>,
+    ) -> MailchimpStream<CollectionListMembers, ListMember> {
+        // GET /lists/{list_id}/members
+        let endpoint = format!("lists/{}/members", list_id);
+        MailchimpStream::new(self.api.clone(), &endpoint, &filter.unwrap_or_default())
+    }
+
+    ///
+    /// Get information about a specific list member.
+    ///
+    /// Arguments:
+    ///     list_id: The unique id for the list.
+    ///     subscriber_hash: The MD5 hash of the lowercase version of the list member’s email address.
+    ///
+    pub async fn get_member_info(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> MailchimpResult<ListMember> {
+        // GET /lists/{list_id}/members/{subscriber_hash}
+        let endpoint = format!("lists/{}/members/{}", list_id, subscriber_hash);
+        self.api.get::<ListMember>(&endpoint, HashMap::new()).await
+    }
+
+    ///
+    /// Add a new member to the list.
+    ///
+    /// Arguments:
+    ///     list_id: The unique id for the list.
+    ///     param: New member fields
+    ///
+    pub async fn add_new_member(
+        &self,
+        list_id: &str,
+        param: ListMemberParams,
+    ) -> MailchimpResult<ListMember> {
+        // POST /lists/{list_id}/members
+        let endpoint = format!("lists/{}/members", list_id);
+        self.api
+            .post::<ListMember, ListMemberParams>(&endpoint, param)
+            .await
+    }
+
+    ///
+    /// Check the merge fields of a member against the merge fields of the
+    /// list, without sending the member.
+    ///
+    /// Arguments:
+    ///     list_id: The unique id for the list.
+    ///     param: Member fields to check
+    ///
+    pub async fn validate_member(
+        &self,
+        list_id: &str,
+        param: &ListMemberParams,
+    ) -> MailchimpResult<()> {
+        // GET /lists/{list_id}/merge-fields
+        let endpoint = format!("lists/{}/merge-fields", list_id);
+        let filter = ListMergeFieldFilter {
+            count: Some(1000),
+            ..ListMergeFieldFilter::default()
+        };
+        let collection = self
+            .api
+            .get::<CollectionListMergeField>(&endpoint, filter.build_payload())
+            .await?;
+        param.validate_merge_fields(&collection.merge_fields)
+    }
+
+    ///
+    /// Add a new member to the list after checking the merge fields against
+    /// the merge fields of the list. Nothing is sent if a value is not valid.
+    ///
+    /// Arguments:
+    ///     list_id: The unique id for the list.
+    ///     param: New member fields
+    ///
+    pub async fn add_new_member_checked(
+        &self,
+        list_id: &str,
+        param: ListMemberParams,
+    ) -> MailchimpResult<ListMember> {
+        self.validate_member(list_id, &param).await?;
+        self.add_new_member(list_id, param).await
+    }
+
+    ///
+    /// Add or update a list member
+    ///
+    /// Arguments:
+    ///     list_id: The unique id for the list.
+    ///     subscriber_hash: The MD5 hash of the lowercase version of the list member’s email address.
+    ///     param: Member fields to update o create
+    ///
+    pub async fn add_update_member(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        param: ListMemberParams,
+    ) -> MailchimpResult<ListMember> {
+        // PUT /lists/{list_id}/members/{subscriber_hash}
+        let endpoint = format!("lists/{}/members/{}", list_id, subscriber_hash);
+        self.api
+            .put::<ListMember, ListMemberParams>(&endpoint, param)
+            .await
+    }
+
+    ///
+    /// Get information about a specific list member by the email address.
+    ///
+    /// Arguments:
+    ///     list_id: The unique id for the list.
+    ///     email: Email address of the member, it is normalised and hashed
+    ///
+    pub async fn get_member_by_email(
+        &self,
+        list_id: &str,
+        email: &str,
+    ) -> MailchimpResult<ListMember> {
+        self.get_member_info(list_id, &subscriber_hash(email)).await
+    }
+
+    ///
+    /// Add or update a list member by the email address. The email address
+    /// of the param is set to `email` if it is missing or blank.
+    ///
+    /// Arguments:
+    ///     list_id: The unique id for the list.
+    ///     email: Email address of the member, it is normalised and hashed
+    ///     param: Member fields to update or create
+    ///
+    pub async fn upsert_member_by_email(
+        &self,
+        list_id: &str,
+        email: &str,
+        param: ListMemberParams,
+    ) -> MailchimpResult<ListMember> {
+        let mut param = param;
+        let missing = match &param.email_address {
+            Some(v) => v.trim().is_empty(),
+            None => true,
+        };
+        if missing {
+            param.email_address = Some(email.trim().to_string());
+        }
+        self.add_update_member(list_id, &subscriber_hash(email), param)
+            .await
+    }
+}
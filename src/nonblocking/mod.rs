@@ -0,0 +1,56 @@
+//! Async Mailchimp API
+//!
+//! Async counterpart of the blocking resources, available with the `async`
+//! feature. Every endpoint returns a future that can be awaited from a tokio
+//! runtime instead of being wrapped in `spawn_blocking`.
+//!
+//! The module covers `ApiRoot`, `AuthorizedApps`, `Automations`, `Campaigns`
+//! (including the campaign actions and content), `Conversations`,
+//! `LandingPages`, `Lists` (including the members) and `Reports`. The `iter`
+//! methods return a `MailchimpStream`, the async counterpart of
+//! `MalchimpIter`. The items are returned as plain data: their own methods,
+//! e.g. `ListType::get_members`, and the other resources (batches,
+//! e-commerce, file manager, templates, folders and search) are only
+//! available in the blocking client.
+//!
+//! ```no_run
+//!     use mailchimp::nonblocking::{Lists, MailchimpApi};
+//!     use mailchimp::ListFilter;
+//!
+//!     async fn run() {
+//!         let api = MailchimpApi::new("<API_KEY>");
+//!
+//!         // Create Instance of Lists
+//!         let lists = Lists::new(api);
+//!
+//!         // Get the first page of lists in the account.
+//!         let collection = lists.get_lists(Some(&ListFilter::default())).await.unwrap();
+//!         for w in collection.lists {
+//!             println!("\tID       {:?}", w.id);
+//!             println!("\tName    {:?}", w.name);
+//!         }
+//!     }
+//! ```
+//!
+
+mod api;
+mod api_root;
+mod authorized_apps;
+mod automations;
+mod campaigns;
+mod conversations;
+mod landing_pages;
+mod list;
+mod reports;
+mod stream;
+
+pub use self::api::MailchimpApi;
+pub use self::api_root::ApiRoot;
+pub use self::authorized_apps::AuthorizedApps;
+pub use self::automations::Automations;
+pub use self::campaigns::Campaigns;
+pub use self::conversations::Conversations;
+pub use self::landing_pages::LandingPages;
+pub use self::list::Lists;
+pub use self::reports::Reports;
+pub use self::stream::MailchimpStream;
@@ -0,0 +1,66 @@
+use super::api::MailchimpApi;
+use super::stream::MailchimpStream;
+use crate::internal::request::MailchimpResult;
+use crate::iter::ResourceFilter;
+use crate::types::{CollectionReports, ReportType, ReportsFilter};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Async version of the Reports Endpoint
+///
+/// Manage campaign reports for your Mailchimp account. All Reports
+/// endpoints are read-only.
+///
+#[derive(Debug, Clone)]
+pub struct Reports {
+    api: Arc<MailchimpApi>,
+}
+
+impl Reports {
+    ///
+    /// Arguments:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        Reports { api: Arc::new(api) }
+    }
+
+    ///
+    /// Get campaign reports
+    ///
+    /// Arguments:
+    ///     filter: Filters, use `offset` and `count` to go through the pages
+    ///
+    pub async fn get_reports(
+        &self,
+        filter: Option<ReportsFilter>,
+    ) -> MailchimpResult<CollectionReports> {
+        let payload = filter.unwrap_or_default().build_payload();
+        self.api.get::<CollectionReports>("reports", payload).await
+    }
+
+    ///
+    /// Stream over all campaign reports, the pages are requested while the
+    /// stream is polled.
+    ///
+    /// Arguments:
+    ///     filter: Filter of the first page
+    ///
+    pub fn iter_reports(
+        &self,
+        filter: Option<ReportsFilter>,
+    ) -> MailchimpStream<CollectionReports, ReportType> {
+        MailchimpStream::new(self.api.clone(), "reports", &filter.unwrap_or_default())
+    }
+
+    ///
+    /// Get a specific campaign report
+    ///
+    /// Get report details for a specific sent campaign.
+    ///
+    pub async fn get_campaign_report(&self, campaign_id: &str) -> MailchimpResult<ReportType> {
+        let endpoint = format!("reports/{}", campaign_id);
+        self.api.get::<ReportType>(&endpoint, HashMap::new()).await
+    }
+}
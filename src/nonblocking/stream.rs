@@ -0,0 +1,175 @@
+use super::api::MailchimpApi;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{MailchimpCollection, ResourceFilter};
+use futures_core::{ready, Stream};
+use serde::de::DeserializeOwned;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+type PageFuture<C> = Pin<Box<dyn Future<Output = MailchimpResult<C>> + Send>>;
+
+///
+/// Async Mailchimp Stream
+///
+/// Async counterpart of `TryMalchimpIter`: a `futures::Stream` over the items
+/// of a collection that requests the pages while it is polled. Each item is a
+/// `MailchimpResult`, so an error in the middle of the pagination is not
+/// confused with the end of the collection. After an error the stream ends;
+/// call `resume` to request the failed page again and continue from the same
+/// position.
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use mailchimp::nonblocking::{Lists, MailchimpApi};
+/// use mailchimp::ListFilter;
+///
+/// async fn run() {
+///     let lists = Lists::new(MailchimpApi::new("<API_KEY>"));
+///     let mut stream = lists.iter(ListFilter::default());
+///     while let Some(list) = stream.next().await {
+///         println!("{:?}", list.unwrap().name);
+///     }
+/// }
+/// ```
+///
+pub struct MailchimpStream<C, T> {
+    api: Arc<MailchimpApi>,
+    endpoint: String,
+    /// Parámetros de la primera página, el offset cambia en cada página
+    payload: HashMap<String, String>,
+    /// Elementos de la página actual aún no devueltos
+    data: VecDeque<T>,
+    /// Offset de la siguiente página
+    offset: u64,
+    /// Elementos devueltos hasta el momento
+    seen: u64,
+    total_items: u64,
+    done: bool,
+    failed: bool,
+    /// Petición de la página en curso
+    request: Option<PageFuture<C>>,
+    collection: PhantomData<fn() -> C>,
+}
+
+impl<C, T> MailchimpStream<C, T>
+where
+    C: MailchimpCollection<T> + DeserializeOwned + Send + 'static,
+{
+    ///
+    /// Stream over the collection of the endpoint, starting at the page of
+    /// the given filters. No request is sent until the stream is polled.
+    ///
+    /// Arguments:
+    ///     api: MailchimpApi
+    ///     endpoint: Endpoint of the collection, e.g. "lists"
+    ///     filters: Filter of the first page
+    ///
+    pub fn new<F>(api: Arc<MailchimpApi>, endpoint: &str, filters: &F) -> Self
+    where
+        F: ResourceFilter,
+    {
+        let payload = filters.build_payload();
+        let offset = payload
+            .get("offset")
+            .and_then(|o| o.parse::<u64>().ok())
+            .unwrap_or(0);
+        MailchimpStream {
+            api,
+            endpoint: endpoint.to_string(),
+            payload,
+            data: VecDeque::new(),
+            offset,
+            seen: 0,
+            total_items: 0,
+            done: false,
+            failed: false,
+            request: None,
+            collection: PhantomData,
+        }
+    }
+
+    ///
+    /// Total items in the collection, as reported by the last page loaded
+    ///
+    pub fn total_items(&self) -> u64 {
+        self.total_items
+    }
+
+    ///
+    /// Number of items returned so far
+    ///
+    pub fn position(&self) -> u64 {
+        self.seen
+    }
+
+    ///
+    /// Returns true if the last request failed
+    ///
+    pub fn is_failed(&self) -> bool {
+        self.failed
+    }
+
+    ///
+    /// Continue after an error, the next poll requests the failed page again
+    ///
+    pub fn resume(&mut self) {
+        self.failed = false;
+    }
+
+    fn fetch(&self) -> PageFuture<C> {
+        let api = self.api.clone();
+        let endpoint = self.endpoint.clone();
+        let mut payload = self.payload.clone();
+        payload.insert("offset".to_string(), self.offset.to_string());
+        Box::pin(async move { api.get::<C>(&endpoint, payload).await })
+    }
+}
+
+// Ningún campo se fija en memoria, la petición en curso ya está en un `Box`
+impl<C, T> Unpin for MailchimpStream<C, T> {}
+
+impl<C, T> Stream for MailchimpStream<C, T>
+where
+    C: MailchimpCollection<T> + DeserializeOwned + Send + 'static,
+{
+    type Item = MailchimpResult<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.data.pop_front() {
+                this.seen += 1;
+                return Poll::Ready(Some(Ok(item)));
+            }
+            if this.done || this.failed {
+                return Poll::Ready(None);
+            }
+            if this.request.is_none() {
+                this.request = Some(this.fetch());
+            }
+            let result = match this.request.as_mut() {
+                Some(request) => ready!(request.as_mut().poll(cx)),
+                None => return Poll::Ready(None),
+            };
+            this.request = None;
+            match result {
+                Ok(collection) => {
+                    let values = collection.get_values();
+                    this.total_items = collection.get_total_items();
+                    this.offset += values.len() as u64;
+                    // Una página vacía también termina la iteración
+                    this.done = values.is_empty() || this.offset >= this.total_items;
+                    this.data = values.into();
+                }
+                Err(e) => {
+                    this.failed = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
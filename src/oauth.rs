@@ -0,0 +1,181 @@
+//! OAuth2
+//!
+//! Connects the Mailchimp accounts of your users with the OAuth2
+//! authorization code flow and discovers the datacenter of each account.
+//!
+//! ```no_run
+//!     use mailchimp::OAuthClient;
+//!
+//!     let oauth = OAuthClient::new("<CLIENT_ID>", "<CLIENT_SECRET>", "https://example.com/callback");
+//!
+//!     // 1. Redirect the user to the authorization page
+//!     println!("Open {}", oauth.authorize_url(Some("<state>")).unwrap());
+//!
+//!     // 2. Exchange the code sent to the callback for an API client
+//!     let api = oauth.connect("<CODE>").unwrap();
+//!     println!("{:?}", api.ping());
+//! ```
+//!
+use crate::api::MailchimpApi;
+use crate::api_builder::MailchimpApiBuilder;
+use crate::internal::api::decode_response;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::{check_response, MailchimpResult};
+use crate::types::{OAuthMetadataType, OAuthTokenType};
+use reqwest::blocking::{Client, RequestBuilder};
+use reqwest::header::AUTHORIZATION;
+use reqwest::Url;
+
+/// Servidor de autenticación de Mailchimp
+const LOGIN_URL: &str = "https://login.mailchimp.com/";
+
+///
+/// OAuth2 Client
+///
+/// Performs the authorize/token exchange of the authorization code flow and
+/// looks up the datacenter of the account in the metadata endpoint. The login
+/// server can be replaced with `with_login_url`, e.g. by a local stand-in
+/// server in the tests.
+///
+#[derive(Debug, Clone)]
+pub struct OAuthClient {
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
+    login_url: String,
+    client: Client,
+}
+
+impl OAuthClient {
+    ///
+    /// Arguments:
+    ///     client_id: Client ID of the registered application
+    ///     client_secret: Client secret of the registered application
+    ///     redirect_uri: Callback URL registered for the application
+    ///
+    pub fn new(client_id: &str, client_secret: &str, redirect_uri: &str) -> Self {
+        OAuthClient {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            login_url: LOGIN_URL.to_string(),
+            client: Client::new(),
+        }
+    }
+
+    ///
+    /// Replace the login server, by default `https://login.mailchimp.com/`.
+    /// An invalid URL is reported by the requests as `MailchimpError::InvalidConfig`
+    ///
+    pub fn with_login_url(mut self, login_url: &str) -> Self {
+        self.login_url = format!("{}/", login_url.trim_end_matches('/'));
+        self
+    }
+
+    ///
+    /// Send the requests with the given HTTP client, e.g. with timeouts or a proxy
+    ///
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    ///
+    /// URL of the page where the user authorizes the application
+    ///
+    /// Arguments:
+    ///     state: Opaque value sent back to the callback, use it to prevent CSRF
+    ///
+    pub fn authorize_url(&self, state: Option<&str>) -> MailchimpResult<Url> {
+        let mut url = self.url("oauth2/authorize")?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.client_id)
+            .append_pair("redirect_uri", &self.redirect_uri);
+        if let Some(state) = state {
+            url.query_pairs_mut().append_pair("state", state);
+        }
+        Ok(url)
+    }
+
+    ///
+    /// Exchange the code sent to the callback for an access token
+    ///
+    /// Arguments:
+    ///     code: Code received in the callback
+    ///
+    pub fn exchange_code(&self, code: &str) -> MailchimpResult<OAuthTokenType> {
+        let params = [
+            ("grant_type", "authorization_code"),
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+            ("redirect_uri", self.redirect_uri.as_str()),
+            ("code", code),
+        ];
+        let request = self.client.post(self.url("oauth2/token")?).form(&params);
+        self.send("oauth2/token", request)
+    }
+
+    ///
+    /// Metadata of the account linked to the token, including its datacenter
+    /// and the API endpoint
+    ///
+    /// Arguments:
+    ///     access_token: OAuth2 access token
+    ///
+    pub fn metadata(&self, access_token: &str) -> MailchimpResult<OAuthMetadataType> {
+        let request = self
+            .client
+            .get(self.url("oauth2/metadata")?)
+            .header(AUTHORIZATION, format!("OAuth {}", access_token));
+        self.send("oauth2/metadata", request)
+    }
+
+    ///
+    /// Exchange the code for a token, discover the datacenter of the account
+    /// and return an API client authenticated with the token
+    ///
+    /// Arguments:
+    ///     code: Code received in the callback
+    ///
+    pub fn connect(&self, code: &str) -> MailchimpResult<MailchimpApi> {
+        let token = self.exchange_code(code)?;
+        self.api_for_token(&token.access_token)
+    }
+
+    ///
+    /// API client for an access token obtained before, the datacenter is
+    /// discovered with the metadata endpoint
+    ///
+    /// Arguments:
+    ///     access_token: OAuth2 access token
+    ///
+    pub fn api_for_token(&self, access_token: &str) -> MailchimpResult<MailchimpApi> {
+        let metadata = self.metadata(access_token)?;
+        let mut builder = MailchimpApiBuilder::new().oauth_token(access_token);
+        builder = if metadata.api_endpoint.is_empty() {
+            builder.datacenter(&metadata.dc)
+        } else {
+            builder.base_url(&metadata.api_endpoint)
+        };
+        builder.build()
+    }
+
+    fn url(&self, path: &str) -> MailchimpResult<Url> {
+        Url::parse(&self.login_url)
+            .and_then(|url| url.join(path))
+            .map_err(|e| {
+                MailchimpError::InvalidConfig(format!("login url {:?}: {}", self.login_url, e))
+            })
+    }
+
+    fn send<T>(&self, endpoint: &str, request: RequestBuilder) -> MailchimpResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let resp = request.send().map_err(MailchimpError::from)?;
+        let status = resp.status();
+        let body = resp.text().map_err(MailchimpError::from)?;
+        decode_response(endpoint, check_response(status, body)?)
+    }
+}
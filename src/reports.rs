@@ -1,10 +1,9 @@
 use super::api::MailchimpApi;
 use super::internal::request::MailchimpResult;
-use super::types::{CollectionReports, ReportType, ReportsBuilder, ReportsFilter};
-use crate::iter::{MalchimpIter, ResourceFilter};
-use log::error;
+use super::types::{ReportType, ReportsBuilder, ReportsFilter};
+use crate::iter::MalchimpIter;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Reports
@@ -17,7 +16,7 @@ use std::rc::Rc;
 ///
 #[derive(Debug, Clone)]
 pub struct Reports {
-    api: Rc<MailchimpApi>,
+    api: Arc<MailchimpApi>,
 }
 
 impl Reports {
@@ -26,7 +25,7 @@ impl Reports {
     ///     api: MailchimpApi
     ///
     pub fn new(api: MailchimpApi) -> Self {
-        Reports { api: Rc::new(api) }
+        Reports { api: Arc::new(api) }
     }
 
     ///
@@ -41,32 +40,7 @@ impl Reports {
             filter_params = f;
         }
 
-        match self
-            .api
-            .get::<CollectionReports>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ReportsBuilder {},
-                data: collection.reports,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self.api.clone(),
-                endpoint: endpoint.to_string(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Reports: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ReportsBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self.api.clone(),
-                    endpoint: endpoint.to_string(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(ReportsBuilder {}, self.api.clone(), endpoint, filter_params)
     }
 
     ///
@@ -78,6 +52,8 @@ impl Reports {
         let endpoint = format!("reports/{}", campaign_id);
         let mut payload = HashMap::new();
         payload.insert("campaign_id".to_string(), campaign_id.to_string());
-        self.api.get::<ReportType>(&endpoint, payload)
+        let mut report = self.api.get::<ReportType>(&endpoint, payload)?;
+        report.set_api(self.api.clone());
+        Ok(report)
     }
 }
@@ -0,0 +1,112 @@
+//! Implement Mailchimp Search Endpoints
+//!
+//! Search all campaigns and all list members of the account.
+//!
+//! ```no_run
+//!     use mailchimp::{MailchimpApi, Search};
+//!
+//!     fn main() {
+//!         let api = MailchimpApi::new("<API_KEY>");
+//!         let search = Search::new(api);
+//!
+//!         // Members of every list whose email or name contains "smith"
+//!         let matches = search.search_members("smith", None).unwrap();
+//!         for m in matches.exact_matches.members.iter() {
+//!             println!("\t exact {} ({})", m.email_address, m.list_id);
+//!         }
+//!         for m in matches.full_search.members.iter() {
+//!             println!("\t partial {} ({})", m.email_address, m.list_id);
+//!         }
+//!
+//!         // Campaigns that mention "sale"
+//!         let campaigns = search.search_campaigns("sale").unwrap();
+//!         for r in campaigns.results.iter() {
+//!             println!("\t {:?} {}", r.campaign.id, r.snippet);
+//!         }
+//!     }
+//! ```
+//!
+
+use super::api::{MailchimpApi, MailchimpApiUpdate};
+use super::internal::request::MailchimpResult;
+use super::types::{ListMember, SearchCampaignsType, SearchMembersType};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Implement Mailchimp Search Endpoints
+///
+/// Search for list members and campaigns. Member searches match the email
+/// address or the name, across all lists or only in one of them.
+///
+#[derive(Debug, Clone)]
+pub struct Search {
+    api: Arc<MailchimpApi>,
+}
+
+impl Search {
+    ///
+    /// Argumentos:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        Search { api: Arc::new(api) }
+    }
+
+    ///
+    /// Search for list members. This search can be restricted to a specific list,
+    /// or can be used to search across all lists in an account.
+    ///
+    /// Argumentos:
+    ///     query: The search query used to filter results (email address or name)
+    ///     list_id: The unique id for the list, None to search in all lists
+    ///
+    pub fn search_members(
+        &self,
+        query: &str,
+        list_id: Option<&str>,
+    ) -> MailchimpResult<SearchMembersType> {
+        // GET /search-members
+        let mut payload = HashMap::new();
+        payload.insert("query".to_string(), query.to_string());
+        if let Some(v) = list_id {
+            payload.insert("list_id".to_string(), v.to_string());
+        }
+        let mut result = self
+            .api
+            .get::<SearchMembersType>("search-members", payload)?;
+        for member in result
+            .exact_matches
+            .members
+            .iter_mut()
+            .chain(result.full_search.members.iter_mut())
+        {
+            self.update_member(member);
+        }
+        Ok(result)
+    }
+
+    ///
+    /// Search all campaigns for the specified query terms
+    ///
+    /// Argumentos:
+    ///     query: The search query used to filter results
+    ///
+    pub fn search_campaigns(&self, query: &str) -> MailchimpResult<SearchCampaignsType> {
+        // GET /search-campaigns
+        let mut payload = HashMap::new();
+        payload.insert("query".to_string(), query.to_string());
+        let mut result = self
+            .api
+            .get::<SearchCampaignsType>("search-campaigns", payload)?;
+        for r in result.results.iter_mut() {
+            r.campaign.set_api(self.api.clone());
+        }
+        Ok(result)
+    }
+
+    fn update_member(&self, member: &mut ListMember) {
+        member.set_api(self.api.clone());
+        member.set_endpoint(&format!("lists/{}/members", member.list_id));
+    }
+}
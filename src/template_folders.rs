@@ -0,0 +1,81 @@
+use super::api::MailchimpApi;
+use super::internal::request::MailchimpResult;
+use super::types::{TemplateFolderParam, TemplateFolderType, TemplateFoldersBuilder};
+use crate::iter::{MalchimpIter, SimpleFilter};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Template Folders
+///
+/// Organize your templates using folders.
+///
+/// ```no_run
+/// use mailchimp::types::TemplateFolderParam;
+/// use mailchimp::{MailchimpApi, TemplateFolders};
+///
+/// let folders = TemplateFolders::new(MailchimpApi::new("<API_KEY>"));
+/// let param = TemplateFolderParam {
+///     name: "Newsletters".to_string(),
+/// };
+/// let folder = folders.create_folder(param).unwrap();
+/// for f in folders.get_folders(None) {
+///     println!("{} {} templates", f.name, f.count);
+/// }
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct TemplateFolders {
+    api: Arc<MailchimpApi>,
+}
+
+impl TemplateFolders {
+    ///
+    /// Argumentos:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        TemplateFolders { api: Arc::new(api) }
+    }
+
+    ///
+    /// Get all folders used to organize templates
+    ///
+    pub fn get_folders(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<TemplateFoldersBuilder> {
+        // GET /template-folders
+        MalchimpIter::from_endpoint(
+            TemplateFoldersBuilder {},
+            self.api.clone(),
+            "template-folders",
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific folder used to organize templates
+    ///
+    pub fn get_folder(&self, folder_id: &str) -> MailchimpResult<TemplateFolderType> {
+        // GET /template-folders/{folder_id}
+        let endpoint = format!("template-folders/{}", folder_id);
+        let mut folder = self
+            .api
+            .get::<TemplateFolderType>(&endpoint, HashMap::new())?;
+        folder.set_api(self.api.clone());
+        Ok(folder)
+    }
+
+    ///
+    /// Create a new template folder
+    ///
+    pub fn create_folder(&self, param: TemplateFolderParam) -> MailchimpResult<TemplateFolderType> {
+        // POST /template-folders
+        let mut folder = self
+            .api
+            .post::<TemplateFolderType, TemplateFolderParam>("template-folders", param)?;
+        folder.set_api(self.api.clone());
+        Ok(folder)
+    }
+}
@@ -0,0 +1,218 @@
+//! Implement Mailchimp Templates Endpoint
+//!
+//! Manage your Mailchimp templates. A template is an HTML file used to create
+//! the layout and basic design for a campaign.
+//!
+//! ```no_run
+//!     use mailchimp::MailchimpApi;
+//!     use mailchimp::{TemplateFilter, Templates};
+//!
+//!     fn main() {
+//!         let api = MailchimpApi::new("<API_KEY>");
+//!         let templates = Templates::new(api);
+//!
+//!         let filter = TemplateFilter {
+//!             template_type: Some("user".to_string()),
+//!             ..TemplateFilter::default()
+//!         };
+//!         for t in templates.iter(filter) {
+//!             println!("\t Template {} {}", t.id, t.name);
+//!             let content = t.get_default_content().unwrap();
+//!             println!("\t Sections {:?}", content.sections.keys());
+//!         }
+//!     }
+//! ```
+//!
+
+use super::api::MailchimpApi;
+use super::internal::request::MailchimpResult;
+use super::iter::{BuildIter, MalchimpIter, ResourceFilter};
+use super::types::{
+    format_datetime, CollectionTemplates, MailchimpDateTime, SortDir, TemplateParam, TemplateType,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Template Request Filter
+///
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TemplateFilter {
+    /// A comma-separated list of fields to return.
+    /// Reference parameters of sub-objects with dot notation.
+    pub fields: Option<String>,
+    /// A comma-separated list of fields to exclude. Reference
+    /// parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<String>,
+    /// The number of records to return. Default value is 10.
+    pub count: Option<u64>,
+    /// The number of records from a collection to skip. Iterating over
+    /// large collections with this parameter can be slow. Default value is 0..
+    pub offset: Option<u64>,
+    /// The Mailchimp account user who created the template.
+    pub created_by: Option<String>,
+    /// Restrict the response to templates created after the set date. We recommend
+    /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_date_created: Option<MailchimpDateTime>,
+    /// Restrict the response to templates created before the set date. We recommend
+    /// ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_date_created: Option<MailchimpDateTime>,
+    /// Limit results based on template type.
+    /// user base gallery
+    pub template_type: Option<String>,
+    /// Limit results based on category.
+    pub category: Option<String>,
+    /// The unique folder id.
+    pub folder_id: Option<String>,
+    /// Returns user templates sorted by the specified field.
+    /// date_created name
+    pub sort_field: Option<String>,
+    /// Determines the order direction for sorted results.
+    /// ASC DESC
+    pub sort_dir: Option<SortDir>,
+}
+
+impl Default for TemplateFilter {
+    fn default() -> Self {
+        TemplateFilter {
+            fields: None,
+            exclude_fields: None,
+            count: Some(50),
+            offset: Some(0),
+            created_by: None,
+            since_date_created: None,
+            before_date_created: None,
+            template_type: None,
+            category: None,
+            folder_id: None,
+            sort_field: None,
+            sort_dir: None,
+        }
+    }
+}
+
+impl ResourceFilter for TemplateFilter {
+    fn build_payload(&self) -> HashMap<String, String> {
+        let mut payload = HashMap::new();
+
+        if let Some(v) = &self.fields {
+            payload.insert("fields".to_string(), v.clone());
+        }
+        if let Some(v) = &self.exclude_fields {
+            payload.insert("exclude_fields".to_string(), v.clone());
+        }
+        if let Some(v) = self.count {
+            payload.insert("count".to_string(), format!("{:}", v));
+        }
+        if let Some(v) = self.offset {
+            payload.insert("offset".to_string(), format!("{:}", v));
+        }
+        if let Some(v) = &self.created_by {
+            payload.insert("created_by".to_string(), v.clone());
+        }
+        if let Some(v) = &self.since_date_created {
+            payload.insert("since_date_created".to_string(), format_datetime(v));
+        }
+        if let Some(v) = &self.before_date_created {
+            payload.insert("before_date_created".to_string(), format_datetime(v));
+        }
+        if let Some(v) = &self.template_type {
+            payload.insert("type".to_string(), v.clone());
+        }
+        if let Some(v) = &self.category {
+            payload.insert("category".to_string(), v.clone());
+        }
+        if let Some(v) = &self.folder_id {
+            payload.insert("folder_id".to_string(), v.clone());
+        }
+        if let Some(v) = &self.sort_field {
+            payload.insert("sort_field".to_string(), v.clone());
+        }
+        if let Some(v) = &self.sort_dir {
+            payload.insert("sort_dir".to_string(), v.to_string());
+        }
+        payload
+    }
+}
+
+///
+/// TemplatesBuilder
+///
+#[derive(Debug)]
+pub struct TemplatesBuilder {}
+
+impl BuildIter for TemplatesBuilder {
+    type Item = TemplateType;
+    type FilterItem = TemplateFilter;
+    type Collection = CollectionTemplates;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// Implement Mailchimp Templates Endpoint
+///
+/// Manage your Mailchimp templates. A template is an HTML file used to create
+/// the layout and basic design for a campaign.
+///
+#[derive(Debug, Clone)]
+pub struct Templates {
+    api: Arc<MailchimpApi>,
+}
+
+impl Templates {
+    ///
+    /// Argumentos:
+    ///     api: MailchimpApi
+    ///
+    pub fn new(api: MailchimpApi) -> Self {
+        Templates { api: Arc::new(api) }
+    }
+
+    ///
+    /// Get a list of an account’s available templates
+    ///
+    pub fn iter(&self, filters: TemplateFilter) -> MalchimpIter<TemplatesBuilder> {
+        // GET /templates
+        MalchimpIter::from_endpoint(TemplatesBuilder {}, self.api.clone(), "templates", filters)
+    }
+
+    ///
+    /// Get information about a specific template
+    ///
+    pub fn get_template(&self, template_id: u64) -> MailchimpResult<TemplateType> {
+        // GET /templates/{template_id}
+        let endpoint = format!("templates/{}", template_id);
+        let mut template = self.api.get::<TemplateType>(&endpoint, HashMap::new())?;
+        template.set_api(self.api.clone());
+        Ok(template)
+    }
+
+    ///
+    /// Create a new template for the account. Only Classic templates are supported.
+    ///
+    pub fn create_template(&self, param: TemplateParam) -> MailchimpResult<TemplateType> {
+        // POST /templates
+        let mut template = self
+            .api
+            .post::<TemplateType, TemplateParam>("templates", param)?;
+        template.set_api(self.api.clone());
+        Ok(template)
+    }
+}
@@ -0,0 +1,503 @@
+//! Fake Mailchimp transport for tests
+//!
+//! Available with the `testing` feature. `FakeTransport` answers the requests
+//! with the responses registered per method, path and query string, and keeps
+//! every request it receives so the tests can assert on the payloads sent.
+//! It can be plugged into `MailchimpApi::with_transport` (and into
+//! `nonblocking::MailchimpApi::with_transport` with the `async` feature), so
+//! the code around `Lists`, `Campaigns`, ... can be tested without a live
+//! account.
+//!
+//! ```
+//! use mailchimp::testing::{FakeTransport, Method, Route};
+//! use mailchimp::{Lists, MailchimpApi};
+//! use serde_json::json;
+//! use std::collections::HashMap;
+//!
+//! let fake = FakeTransport::new();
+//! fake.route(Route::get("lists/57afe96172").respond_json(&json!({
+//!     "id": "57afe96172",
+//!     "name": "Freddie's Jokes"
+//! })));
+//! fake.route(Route::get("lists/unknown").respond_status(404, ""));
+//!
+//! let api = MailchimpApi::with_transport("<API Key>-us6", fake.clone());
+//! let lists = Lists::new(api);
+//!
+//! let list = lists.get_list_info("57afe96172", HashMap::new()).unwrap();
+//! assert_eq!(list.name, Some("Freddie's Jokes".to_string()));
+//! assert!(lists.get_list_info("unknown", HashMap::new()).unwrap_err().is_not_found());
+//!
+//! let request = fake.assert_called(Method::GET, "lists/57afe96172");
+//! assert!(request.payload.is_none());
+//! ```
+//!
+
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::{check_response, BasicAuth, HttpReq, MailchimpResult};
+use reqwest::header::HeaderMap;
+use reqwest::{StatusCode, Url};
+use serde::ser::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+pub use reqwest::Method;
+
+///
+/// Response returned by a route of the fake transport
+///
+#[derive(Debug, Clone)]
+pub enum FakeResponse {
+    /// Answer with the status and the body, the error statuses are decoded
+    /// like the real responses of Mailchimp
+    Status {
+        /// HTTP status code
+        status: u16,
+        /// Response body
+        body: String,
+    },
+    /// Answer 200 with a binary body, e.g. the archive downloaded with
+    /// `MailchimpApi::download`
+    Bytes(Vec<u8>),
+    /// Fail with `MailchimpError::Timeout`
+    Timeout,
+    /// Fail with `MailchimpError::Transport` and the given message
+    Transport(String),
+}
+
+///
+/// Route of the fake transport
+///
+/// A route matches the requests with the same method and path (relative to
+/// the API base, e.g. `lists/57afe96172/members`) whose query string
+/// contains all the parameters registered with `query`. The requests outside
+/// the API base, e.g. the downloads, are matched by their full path.
+///
+#[derive(Debug, Clone)]
+pub struct Route {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    response: FakeResponse,
+    times: Option<usize>,
+}
+
+impl Route {
+    ///
+    /// Route for the given method and path, by default it answers 200 with
+    /// an empty JSON object
+    ///
+    pub fn new(method: Method, path: &str) -> Self {
+        Route {
+            method,
+            path: path.trim_matches('/').to_string(),
+            query: Vec::new(),
+            response: FakeResponse::Status {
+                status: 200,
+                body: "{}".to_string(),
+            },
+            times: None,
+        }
+    }
+
+    /// Route for a GET request
+    pub fn get(path: &str) -> Self {
+        Route::new(Method::GET, path)
+    }
+
+    /// Route for a POST request
+    pub fn post(path: &str) -> Self {
+        Route::new(Method::POST, path)
+    }
+
+    /// Route for a PATCH request
+    pub fn patch(path: &str) -> Self {
+        Route::new(Method::PATCH, path)
+    }
+
+    /// Route for a PUT request
+    pub fn put(path: &str) -> Self {
+        Route::new(Method::PUT, path)
+    }
+
+    /// Route for a DELETE request
+    pub fn delete(path: &str) -> Self {
+        Route::new(Method::DELETE, path)
+    }
+
+    ///
+    /// Match only the requests that send this query string parameter
+    ///
+    pub fn query(mut self, key: &str, value: &str) -> Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    ///
+    /// Answer 200 with the given body
+    ///
+    pub fn respond(self, body: &str) -> Self {
+        self.respond_status(200, body)
+    }
+
+    ///
+    /// Answer 200 with the value encoded as JSON
+    ///
+    pub fn respond_json<T: Serialize>(self, value: &T) -> Self {
+        let body = serde_json::to_string(value).expect("The response must be encodable as JSON");
+        self.respond_status(200, &body)
+    }
+
+    ///
+    /// Answer with the given status and body, e.g. a problem detail document
+    /// to simulate an API error
+    ///
+    pub fn respond_status(mut self, status: u16, body: &str) -> Self {
+        self.response = FakeResponse::Status {
+            status,
+            body: body.to_string(),
+        };
+        self
+    }
+
+    ///
+    /// Answer 200 with the given binary body
+    ///
+    pub fn respond_bytes(mut self, body: &[u8]) -> Self {
+        self.response = FakeResponse::Bytes(body.to_vec());
+        self
+    }
+
+    ///
+    /// Fail the request as if the server did not answer in time
+    ///
+    pub fn fail_timeout(mut self) -> Self {
+        self.response = FakeResponse::Timeout;
+        self
+    }
+
+    ///
+    /// Fail the request as if it could not be sent
+    ///
+    pub fn fail_transport(mut self, message: &str) -> Self {
+        self.response = FakeResponse::Transport(message.to_string());
+        self
+    }
+
+    ///
+    /// Remove the route after answering `n` requests, useful to answer
+    /// several pages or to fail only the first attempt. With `0` the route
+    /// never matches
+    ///
+    pub fn times(mut self, n: usize) -> Self {
+        self.times = Some(n);
+        self
+    }
+
+    fn matches(&self, method: &Method, path: &str, query: &HashMap<String, String>) -> bool {
+        self.times != Some(0)
+            && self.method == *method
+            && self.path == path
+            && self
+                .query
+                .iter()
+                .all(|(k, v)| query.get(k).map(|q| q == v).unwrap_or(false))
+    }
+}
+
+///
+/// Request received by the fake transport
+///
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// HTTP method
+    pub method: Method,
+    /// Path relative to the API base, e.g. `lists/57afe96172/members`
+    pub path: String,
+    /// Query string parameters
+    pub query: HashMap<String, String>,
+    /// JSON payload of the POST, PATCH and PUT requests
+    pub payload: Option<Value>,
+}
+
+#[derive(Debug, Default)]
+struct FakeState {
+    /// Path of the API base, e.g. `/3.0/`, given by the API the transport is plugged into
+    api_base: Option<String>,
+    routes: Vec<Route>,
+    requests: Vec<RecordedRequest>,
+}
+
+///
+/// Fake Mailchimp transport
+///
+/// The clones share the routes and the recorded requests, so keep a clone to
+/// inspect the requests after giving the transport to the API. When several
+/// routes match a request the last registered wins, and the requests without
+/// route are answered with a 404 error.
+///
+#[derive(Debug, Clone, Default)]
+pub struct FakeTransport {
+    state: Arc<Mutex<FakeState>>,
+}
+
+impl FakeTransport {
+    ///
+    /// Fake transport without routes
+    ///
+    pub fn new() -> Self {
+        FakeTransport::default()
+    }
+
+    ///
+    /// Register a route
+    ///
+    pub fn route(&self, route: Route) -> &Self {
+        self.lock().routes.push(route);
+        self
+    }
+
+    ///
+    /// Requests received so far, in order
+    ///
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().requests.clone()
+    }
+
+    ///
+    /// Requests received for the given method and path
+    ///
+    pub fn requests_to(&self, method: Method, path: &str) -> Vec<RecordedRequest> {
+        let path = path.trim_matches('/');
+        self.lock()
+            .requests
+            .iter()
+            .filter(|r| r.method == method && r.path == path)
+            .cloned()
+            .collect()
+    }
+
+    ///
+    /// Last request received
+    ///
+    pub fn last_request(&self) -> Option<RecordedRequest> {
+        self.lock().requests.last().cloned()
+    }
+
+    ///
+    /// Panics if no request was sent to the given method and path, otherwise
+    /// returns the last one
+    ///
+    pub fn assert_called(&self, method: Method, path: &str) -> RecordedRequest {
+        match self.requests_to(method.clone(), path).pop() {
+            Some(request) => request,
+            None => panic!(
+                "Expected a {} request to \"{}\", received: {:?}",
+                method,
+                path,
+                self.requests()
+                    .iter()
+                    .map(|r| format!("{} {}", r.method, r.path))
+                    .collect::<Vec<String>>()
+            ),
+        }
+    }
+
+    ///
+    /// Remove the routes and the recorded requests
+    ///
+    pub fn reset(&self) {
+        let mut state = self.lock();
+        state.routes.clear();
+        state.requests.clear();
+    }
+
+    fn set_base(&self, api_base: &Url) {
+        self.lock().api_base = Some(api_base.path().to_string());
+    }
+
+    fn lock(&self) -> MutexGuard<'_, FakeState> {
+        // A failed assertion in another test thread must not hide the routes
+        match self.state.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn handle(&self, method: Method, url: Url, payload: Option<Value>) -> MailchimpResult<String> {
+        self.handle_bytes(method, url, payload)
+            .map(|body| String::from_utf8_lossy(&body).into_owned())
+    }
+
+    fn handle_bytes(
+        &self,
+        method: Method,
+        url: Url,
+        payload: Option<Value>,
+    ) -> MailchimpResult<Vec<u8>> {
+        let mut state = self.lock();
+        let full_path = url.path();
+        let path = state
+            .api_base
+            .as_deref()
+            .and_then(|base| full_path.strip_prefix(base))
+            .unwrap_or(full_path)
+            .trim_matches('/')
+            .to_string();
+        let query = url.query_pairs().into_owned().collect::<HashMap<String, String>>();
+
+        state.requests.push(RecordedRequest {
+            method: method.clone(),
+            path: path.clone(),
+            query: query.clone(),
+            payload,
+        });
+        let position = state
+            .routes
+            .iter()
+            .rposition(|r| r.matches(&method, &path, &query));
+        let response = match position {
+            Some(i) => {
+                let route = &mut state.routes[i];
+                let response = route.response.clone();
+                if let Some(n) = route.times.as_mut() {
+                    *n = n.saturating_sub(1);
+                    if *n == 0 {
+                        state.routes.remove(i);
+                    }
+                }
+                response
+            }
+            None => FakeResponse::Status {
+                status: 404,
+                body: format!("No route for {} {}", method, path),
+            },
+        };
+
+        match response {
+            FakeResponse::Status { status, body } => {
+                let status = StatusCode::from_u16(status)
+                    .expect("The route must answer a valid HTTP status");
+                check_response(status, body).map(String::into_bytes)
+            }
+            FakeResponse::Bytes(body) => Ok(body),
+            FakeResponse::Timeout => Err(MailchimpError::Timeout(format!("{} {}", method, path))),
+            FakeResponse::Transport(message) => Err(MailchimpError::Transport(message.into())),
+        }
+    }
+}
+
+impl HttpReq for FakeTransport {
+    fn set_api_base(&self, api_base: &Url) {
+        self.set_base(api_base)
+    }
+
+    fn get(
+        &self,
+        url: Url,
+        _headers: HeaderMap,
+        _basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.handle(Method::GET, url, None)
+    }
+
+    fn post(
+        &self,
+        url: Url,
+        _headers: HeaderMap,
+        payload: Value,
+        _basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.handle(Method::POST, url, Some(payload))
+    }
+
+    fn patch(
+        &self,
+        url: Url,
+        _headers: HeaderMap,
+        payload: Value,
+        _basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.handle(Method::PATCH, url, Some(payload))
+    }
+
+    fn put(
+        &self,
+        url: Url,
+        _headers: HeaderMap,
+        payload: Value,
+        _basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.handle(Method::PUT, url, Some(payload))
+    }
+
+    fn delete(
+        &self,
+        url: Url,
+        _headers: HeaderMap,
+        _basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.handle(Method::DELETE, url, None)
+    }
+
+    fn download(&self, url: Url) -> MailchimpResult<Vec<u8>> {
+        self.handle_bytes(Method::GET, url, None)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl crate::internal::async_request::AsyncHttpReq for FakeTransport {
+    fn set_api_base(&self, api_base: &Url) {
+        self.set_base(api_base)
+    }
+
+    async fn get(
+        &self,
+        url: Url,
+        _headers: HeaderMap,
+        _basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.handle(Method::GET, url, None)
+    }
+
+    async fn post(
+        &self,
+        url: Url,
+        _headers: HeaderMap,
+        payload: Value,
+        _basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.handle(Method::POST, url, Some(payload))
+    }
+
+    async fn patch(
+        &self,
+        url: Url,
+        _headers: HeaderMap,
+        payload: Value,
+        _basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.handle(Method::PATCH, url, Some(payload))
+    }
+
+    async fn put(
+        &self,
+        url: Url,
+        _headers: HeaderMap,
+        payload: Value,
+        _basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.handle(Method::PUT, url, Some(payload))
+    }
+
+    async fn delete(
+        &self,
+        url: Url,
+        _headers: HeaderMap,
+        _basic_auth: &Option<BasicAuth>,
+    ) -> MailchimpResult<String> {
+        self.handle(Method::DELETE, url, None)
+    }
+}
@@ -0,0 +1,13 @@
+//! HTTP transport
+//!
+//! Everything needed to implement a custom transport and plug it into
+//! `MailchimpApi::with_transport`, e.g. to use another HTTP client or to
+//! return canned responses in the tests of an application.
+//!
+
+pub use crate::internal::request::{BasicAuth, HttpReq, MailchimpRequest, MailchimpResult};
+pub use reqwest::header::HeaderMap;
+pub use reqwest::Url;
+
+#[cfg(feature = "async")]
+pub use crate::internal::async_request::{AsyncHttpReq, MailchimpAsyncRequest};
@@ -3,6 +3,7 @@
 use super::contact::ContactType;
 use super::industry_stats::IndustryStatsType;
 use super::link::LinkType;
+use super::timestamp::Timestamp;
 use serde::{Deserialize, Serialize};
 
 ///
@@ -39,16 +40,16 @@ pub struct ApiRootType {
     #[serde(default)]
     pub role: String,
     /// The date and time that the account was created in ISO 8601 format.
-    #[serde(default)]
-    pub member_since: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub member_since: Timestamp,
     /// The type of pricing plan the account is on.
     /// Possible Values:
     ///     monthly pay_as_you_go forever_free
     #[serde(default)]
     pub pricing_plan_type: String,
     /// Date of first payment for monthly plans.
-    #[serde(default)]
-    pub first_payment: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub first_payment: Timestamp,
     /// The timezone currently set for the account.
     #[serde(default)]
     pub account_timezone: String,
@@ -62,8 +63,8 @@ pub struct ApiRootType {
     #[serde(default)]
     pub pro_enabled: bool,
     /// The date and time of the last login for this account in ISO 8601 format.
-    #[serde(default)]
-    pub last_login: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_login: Timestamp,
     /// The total number of subscribers across all lists in the account.
     #[serde(default)]
     pub total_subscribers: u64,
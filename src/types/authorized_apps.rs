@@ -4,7 +4,7 @@ use super::LinkType;
 use crate::api::{MailchimpApi, MailchimpApiUpdate};
 use crate::iter::MailchimpCollection;
 use serde::{Deserialize, Serialize};
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Created Authorized App Type
@@ -50,14 +50,14 @@ pub struct AuthorizedAppType {
     pub _links: Vec<LinkType>,
     /// A list of link types and descriptions for the API schema documents.
     #[serde(skip)]
-    pub _api: Rc<MailchimpApi>,
+    pub _api: Arc<MailchimpApi>,
 }
 
 impl MailchimpApiUpdate for AuthorizedAppType {
     ///
     /// Update API
     ///
-    fn set_api(&mut self, n_api: Rc<MailchimpApi>) {
+    fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
         self._api = n_api
     }
 }
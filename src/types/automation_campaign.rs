@@ -1,21 +1,21 @@
 //! Implement Automations Model Types
 
-use super::automation_subscriber::{
-    AutomationSubscriberBuilder, AutomationSubscriberType, CollectionAutomationSubscriber,
-};
+use super::automation_subscriber::{AutomationSubscriberBuilder, AutomationSubscriberType};
 use super::ecommerce::ECommerceReportType;
 use super::empty::EmptyType;
+use super::enums::AutomationStatus;
 use super::link::LinkType;
 use super::list_segment_options::SegmentOptionsType;
+use super::timestamp::MailchimpDateTime;
 use super::workflow_email::{WorkflowEmailType, WorkflowEmailsType};
 use crate::api::{MailchimpApi, MailchimpApiUpdate};
-use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::error_type::MailchimpError;
 use crate::internal::request::MailchimpResult;
 use crate::iter::MailchimpCollection;
-use crate::iter::{MalchimpIter, ResourceFilter, SimpleFilter};
+use crate::iter::{MalchimpIter, SimpleFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Automation Delay Type
@@ -436,16 +436,24 @@ pub struct AutomationWorkflowType {
     pub id: Option<String>,
 
     /// Desc: The date and time the Automation was created in ISO 8601 format.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub create_time: Option<MailchimpDateTime>,
 
     /// Desc: The date and time the Automation was started in ISO 8601 format.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_time: Option<MailchimpDateTime>,
 
     /// Desc: The current status of the Automation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AutomationStatus>,
 
     /// Desc: The total number of emails sent for the Automation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -477,14 +485,14 @@ pub struct AutomationWorkflowType {
 
     /// Mailchimp APi
     #[serde(default, skip)]
-    pub _api: Rc<MailchimpApi>,
+    pub _api: Arc<MailchimpApi>,
 }
 
 impl MailchimpApiUpdate for AutomationWorkflowType {
     /**
      * Update API
      */
-    fn set_api(&mut self, n_api: Rc<MailchimpApi>) {
+    fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
         self._api = n_api.clone()
     }
 }
@@ -510,7 +518,7 @@ impl AutomationWorkflowType {
     /// En caso de ser satisfactoria la ejecución, devuelve None,
     /// en caso contrario devuelve el error, con su respectiva descripción
     ///
-    pub fn pause_all_emails(&self) -> Option<MailchimpErrorType> {
+    pub fn pause_all_emails(&self) -> Option<MailchimpError> {
         let mut b_endpoint = self.get_base_endpoint();
         b_endpoint.push_str("/actions/pause-all-emails");
         match self
@@ -528,7 +536,7 @@ impl AutomationWorkflowType {
     /// En caso de ser satisfactoria la ejecución, devuelve None,
     /// en caso contrario devuelve el error, con su respectiva descripción
     ///
-    pub fn start_all_emails(&self) -> Option<MailchimpErrorType> {
+    pub fn start_all_emails(&self) -> Option<MailchimpError> {
         let mut b_endpoint = self.get_base_endpoint();
         b_endpoint.push_str("/actions/start-all-emails");
         match self
@@ -647,7 +655,7 @@ impl AutomationWorkflowType {
     pub fn delete_automation_workflow_email<'a>(
         &self,
         workflow_email_id: &'a str,
-    ) -> Option<MailchimpErrorType> {
+    ) -> Option<MailchimpError> {
         let mut endpoint = self.get_base_endpoint().clone();
         endpoint.push_str("/emails/");
         endpoint.push_str(workflow_email_id);
@@ -701,29 +709,12 @@ impl AutomationWorkflowType {
     pub fn get_subscribers_removed(&self) -> MalchimpIter<AutomationSubscriberBuilder> {
         let endpoint = self.get_base_endpoint() + "/removed-subscribers";
         let filters = SimpleFilter::default();
-        let response = self
-            ._api
-            .get::<CollectionAutomationSubscriber>(endpoint.as_str(), filters.build_payload());
-        match response {
-            Ok(collection) => MalchimpIter {
-                builder: AutomationSubscriberBuilder {},
-                data: collection.subscribers,
-                cur_filters: filters.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: endpoint.clone(),
-            },
-            Err(_) => MalchimpIter {
-                builder: AutomationSubscriberBuilder {},
-                data: Vec::new(),
-                cur_filters: filters.clone(),
-                cur_it: 0,
-                total_items: 0,
-                api: self._api.clone(),
-                endpoint: endpoint.clone(),
-            },
-        }
+        MalchimpIter::from_endpoint(
+            AutomationSubscriberBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filters,
+        )
     }
 
     ///
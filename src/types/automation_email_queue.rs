@@ -2,11 +2,12 @@
 //!
 
 use super::link::LinkType;
+use super::timestamp::Timestamp;
 use crate::api::MailchimpApi;
 use crate::iter::{BuildIter, MailchimpCollection, ResourceFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Workflow Email
@@ -35,8 +36,8 @@ pub struct AutomationEmailQueueType {
     #[serde(default)]
     pub email_address: String,
     /// The date and time of the next send for the workflow email in ISO 8601 format.
-    #[serde(default)]
-    pub next_send: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub next_send: Timestamp,
     /// A list of link types and descriptions for the API schema documents.
     #[serde(default)]
     pub _links: Vec<LinkType>,
@@ -94,7 +95,7 @@ impl Default for CollectionAutomationEmailQueue {
 ///
 /// Automation Email Queue Filter
 ///
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AutomationEmailQueueFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -163,7 +164,7 @@ impl BuildIter for AutomationEmailQueueBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
         let in_data = data.clone();
         in_data
     }
@@ -5,7 +5,7 @@ use super::link::LinkType;
 use crate::api::MailchimpApi;
 use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
 use serde::{Deserialize, Serialize};
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Automation Subscribers
@@ -93,7 +93,7 @@ impl BuildIter for AutomationSubscriberBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
         let in_data = data.clone();
         in_data
     }
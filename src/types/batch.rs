@@ -0,0 +1,354 @@
+//! Implement Batch Operations Model Types
+
+use super::empty::EmptyType;
+use super::link::LinkType;
+use super::list_member_tags::{ListMemberTagParam, ListMemberTagType};
+use super::list_members::ListMemberParams;
+use super::timestamp::Timestamp;
+use crate::api::MailchimpApi;
+use crate::internal::error_type::{MailchimpError, MailchimpErrorType};
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Operation of a batch request
+///
+/// ```
+/// use mailchimp::types::{BatchOperation, ListMemberParams, MemberStatus};
+/// use std::collections::HashMap;
+///
+/// let mut merge_fields = HashMap::new();
+/// merge_fields.insert("FNAME".to_string(), "Freddie".into());
+/// let param = ListMemberParams {
+///     email_address: Some("freddie@example.com".to_string()),
+///     status_if_new: Some(MemberStatus::Subscribed),
+///     email_type: None,
+///     status: None,
+///     merge_fields: Some(merge_fields),
+///     interests: None,
+///     language: None,
+///     vip: None,
+///     location: None,
+///     marketing_permissions: None,
+///     tags_count: None,
+///     tags: None,
+///     ip_signup: None,
+///     timestamp_signup: None,
+///     ip_opt: None,
+///     timestamp_opt: None,
+/// };
+/// let op = BatchOperation::upsert_member("57afe96172", "62eeb292278cc15f5817cb78f7790b08", &param)
+///     .unwrap()
+///     .with_operation_id("freddie");
+/// assert_eq!(op.method, "PUT");
+/// ```
+///
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BatchOperation {
+    /// The HTTP method to use for the operation.
+    /// Possible Values: GET POST PUT PATCH DELETE
+    pub method: String,
+    /// The relative path to use for the operation, e.g. `/lists/57afe96172/members`.
+    pub path: String,
+    /// Any request query parameters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<HashMap<String, String>>,
+    /// A string containing the JSON body to use with the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// An optional client-supplied id returned with the operation results.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
+}
+
+impl BatchOperation {
+    ///
+    /// Operation without body
+    ///
+    /// Arguments:
+    ///     method: HTTP method, e.g. "PUT"
+    ///     path: Endpoint of the operation, e.g. "lists/57afe96172/members"
+    ///
+    pub fn new(method: &str, path: &str) -> Self {
+        BatchOperation {
+            method: method.to_uppercase(),
+            path: format!("/{}", path.trim_start_matches('/')),
+            params: None,
+            body: None,
+            operation_id: None,
+        }
+    }
+
+    ///
+    /// Set the JSON body of the operation
+    ///
+    pub fn with_body<P: Serialize>(mut self, body: &P) -> MailchimpResult<Self> {
+        let body = serde_json::to_string(body).map_err(MailchimpError::InvalidPayload)?;
+        self.body = Some(body);
+        Ok(self)
+    }
+
+    ///
+    /// Set the query string parameters of the operation
+    ///
+    pub fn with_params(mut self, params: HashMap<String, String>) -> Self {
+        self.params = Some(params);
+        self
+    }
+
+    ///
+    /// Set the id used to match the result with the operation
+    ///
+    pub fn with_operation_id(mut self, operation_id: &str) -> Self {
+        self.operation_id = Some(operation_id.to_string());
+        self
+    }
+
+    ///
+    /// Add a new member to the list (POST /lists/{list_id}/members)
+    ///
+    pub fn add_member(list_id: &str, param: &ListMemberParams) -> MailchimpResult<Self> {
+        BatchOperation::new("POST", &format!("lists/{}/members", list_id)).with_body(param)
+    }
+
+    ///
+    /// Add or update a list member (PUT /lists/{list_id}/members/{subscriber_hash})
+    ///
+    pub fn upsert_member(
+        list_id: &str,
+        subscriber_hash: &str,
+        param: &ListMemberParams,
+    ) -> MailchimpResult<Self> {
+        BatchOperation::new(
+            "PUT",
+            &format!("lists/{}/members/{}", list_id, subscriber_hash),
+        )
+        .with_body(param)
+    }
+
+    ///
+    /// Add or remove tags from a list member
+    /// (POST /lists/{list_id}/members/{subscriber_hash}/tags)
+    ///
+    pub fn update_member_tags(
+        list_id: &str,
+        subscriber_hash: &str,
+        tags: Vec<ListMemberTagType>,
+    ) -> MailchimpResult<Self> {
+        BatchOperation::new(
+            "POST",
+            &format!("lists/{}/members/{}/tags", list_id, subscriber_hash),
+        )
+        .with_body(&ListMemberTagParam { tags })
+    }
+
+    ///
+    /// Archive a list member (DELETE /lists/{list_id}/members/{subscriber_hash})
+    ///
+    pub fn archive_member(list_id: &str, subscriber_hash: &str) -> Self {
+        BatchOperation::new(
+            "DELETE",
+            &format!("lists/{}/members/{}", list_id, subscriber_hash),
+        )
+    }
+}
+
+///
+/// Param for POST /batches
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BatchParam {
+    /// An array of objects that describes operations to perform.
+    #[serde(default)]
+    pub operations: Vec<BatchOperation>,
+}
+
+///
+/// Batch Operation
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BatchType {
+    /// A string that uniquely identifies this batch request.
+    #[serde(default)]
+    pub id: String,
+    /// The status of the batch call.
+    /// Possible Values: pending preprocessing started finalizing finished
+    #[serde(default)]
+    pub status: String,
+    /// The total number of operations to complete as part of this batch request.
+    #[serde(default)]
+    pub total_operations: u64,
+    /// The number of completed operations. This includes operations that returned an error.
+    #[serde(default)]
+    pub finished_operations: u64,
+    /// The number of completed operations that returned an error.
+    #[serde(default)]
+    pub errored_operations: u64,
+    /// The date and time when the server received the batch request in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub submitted_at: Timestamp,
+    /// The date and time when all operations in the batch request completed in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub completed_at: Timestamp,
+    /// The URL of the gzipped archive of the results of all the operations.
+    #[serde(default)]
+    pub response_body_url: String,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+}
+
+impl BatchType {
+    ///
+    /// Returns true when all the operations are completed
+    ///
+    pub fn is_finished(&self) -> bool {
+        self.status == "finished"
+    }
+
+    ///
+    /// Get the current status of the batch
+    ///
+    pub fn refresh(&self) -> MailchimpResult<BatchType> {
+        // GET /batches/{batch_id}
+        let endpoint = format!("batches/{}", self.id);
+        let mut batch = self._api.get::<BatchType>(&endpoint, HashMap::new())?;
+        batch.set_api(self._api.clone());
+        Ok(batch)
+    }
+
+    ///
+    /// Stops a batch request from running. Since only one batch request is run
+    /// at a time, this can be used to cancel a long running request. The results
+    /// of any completed operations will not be available after this call.
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /batches/{batch_id}
+        let endpoint = format!("batches/{}", self.id);
+        self._api
+            .delete::<EmptyType>(&endpoint, HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, api: Arc<MailchimpApi>) {
+        self._api = api;
+    }
+}
+
+///
+/// Result of an operation of a batch request
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BatchOperationResult {
+    /// HTTP status code of the operation.
+    #[serde(default)]
+    pub status_code: u16,
+    /// The id sent with the operation.
+    #[serde(default)]
+    pub operation_id: Option<String>,
+    /// Body of the response of the operation, encoded as JSON.
+    #[serde(default)]
+    pub response: String,
+}
+
+impl BatchOperationResult {
+    ///
+    /// Returns true if the operation succeeded
+    ///
+    pub fn is_success(&self) -> bool {
+        self.status_code >= 200 && self.status_code < 300
+    }
+
+    ///
+    /// Decode the response of the operation, the failed operations return
+    /// the error sent by Mailchimp
+    ///
+    pub fn response_as<T: DeserializeOwned>(&self) -> MailchimpResult<T> {
+        if !self.is_success() {
+            return Err(match serde_json::from_str::<MailchimpErrorType>(&self.response) {
+                Ok(e) => MailchimpError::Api(e),
+                Err(_) => MailchimpError::Http {
+                    status: self.status_code,
+                    body: self.response.clone(),
+                },
+            });
+        }
+        let body = if self.response.trim().is_empty() {
+            "{}"
+        } else {
+            self.response.as_str()
+        };
+        serde_json::from_str(body).map_err(|e| MailchimpError::Deserialize {
+            endpoint: self.operation_id.clone().unwrap_or_default(),
+            body: self.response.clone(),
+            source: e,
+        })
+    }
+}
+
+///
+/// Response for endpoint GET /batches
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionBatches {
+    /// An array of objects representing batch calls.
+    #[serde(default)]
+    pub batches: Vec<BatchType>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<BatchType> for CollectionBatches {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<BatchType> {
+        self.batches.clone()
+    }
+}
+
+///
+/// BatchesBuilder
+///
+#[derive(Debug)]
+pub struct BatchesBuilder {}
+
+impl BuildIter for BatchesBuilder {
+    type Item = BatchType;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionBatches;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
@@ -6,19 +6,19 @@ use super::automation_campaign::{
     SocialCardType,
 };
 use super::campaign_content::{CampaignContentParam, CampaignContentType};
-use super::campaign_feedback::{
-    CampaignFeedbackBuilder, CampaignFeedbackType, CollectionCampaignFeedback,
-};
+use super::campaign_feedback::{CampaignFeedbackBuilder, CampaignFeedbackType};
 use super::campaign_send_checklist::SendChecklistType;
 use super::empty::EmptyType;
+use super::enums::{CampaignContentKind, CampaignKind, CampaignStatus, DeliveryStatus};
 use super::link::LinkType;
+use super::timestamp::MailchimpDateTime;
 use crate::api::{MailchimpApi, MailchimpApiUpdate};
 use crate::internal::request::MailchimpResult;
 use crate::iter::MailchimpCollection;
-use crate::iter::{MalchimpIter, ResourceFilter, SimpleFilter};
+use crate::iter::{MalchimpIter, SimpleFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// The days of the week to send a daily RSS Campaign.
@@ -33,7 +33,7 @@ pub struct CampaignDeliveryStatusType {
     pub can_cancel: Option<bool>,
     /// The current state of a campaign delivery.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeliveryStatus>,
     /// The total number of emails confirmed sent for this campaign so far.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub emails_sent: Option<u64>,
@@ -218,8 +218,12 @@ pub struct RSSOptionsType {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schedule: Option<SendingScheduleType>,
     /// The date the campaign was last sent.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub last_sent: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_sent: Option<MailchimpDateTime>,
     /// Whether to add CSS to images in the RSS feed to constrain their width in campaigns.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub constrain_rss_img: Option<bool>,
@@ -359,7 +363,7 @@ impl Default for VariateSettingsType {
 /// Endpoint
 ///     GET /campaigns/{campaign_id}
 ///
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct CampaignType {
     /// A string that uniquely identifies this campaign.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -375,10 +379,14 @@ pub struct CampaignType {
     /// There are four types of campaigns you can create in Mailchimp. A/B Split
     /// campaigns have been deprecated and variate campaigns should be used instead.
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub campaign_type: Option<String>,
+    pub campaign_type: Option<CampaignKind>,
     /// The date and time the campaign was created in ISO 8601 format.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub create_time: Option<MailchimpDateTime>,
     /// The link to the campaign’s archive version in ISO 8601 format.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archive_url: Option<String>,
@@ -387,16 +395,20 @@ pub struct CampaignType {
     pub long_archive_url: Option<String>,
     /// The current status of the campaign.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CampaignStatus>,
     /// The total number of emails sent for this campaign.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub emails_sent: Option<u64>,
     /// The date and time a campaign was sent.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub send_time: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub send_time: Option<MailchimpDateTime>,
     /// How the campaign’s content is put together (‘template’, ‘drag_and_drop’, ‘html’, ‘url’).
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
+    pub content_type: Option<CampaignContentKind>,
     /// Determines if the campaign needs its blocks refreshed by opening the web-based campaign editor.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub needs_block_refresh: Option<bool>,
@@ -441,14 +453,14 @@ pub struct CampaignType {
 
     // Mailchimp API
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
 }
 
 impl MailchimpApiUpdate for CampaignType {
     /**
      * Update API
      */
-    fn set_api(&mut self, n_api: Rc<MailchimpApi>) {
+    fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
         self._api = n_api
     }
 }
@@ -523,7 +535,7 @@ pub struct ScheduleParam {
     /// The UTC date and time to schedule the campaign for delivery in ISO 8601 format.
     /// Campaigns may only be scheduled to send on the quarter-hour (:00, :15, :30, :45).
     #[serde(default)]
-    pub schedule_time: String,
+    pub schedule_time: MailchimpDateTime,
     /// Choose whether the campaign should use Timewarp when sending. Campaigns
     /// scheduled with Timewarp are localized based on the recipients’ time zones.
     /// For example, a Timewarp campaign with a schedule_time of 13:00 will be sent
@@ -700,6 +712,31 @@ impl CampaignType {
             .patch::<CampaignType, UpdateCampaignParam>(&endpoint, param)
     }
 
+    ///
+    /// Move the campaign into a campaign folder. The current settings of the
+    /// campaign are sent along with the new folder, as Mailchimp requires
+    /// the subject line, from name and reply-to to update the settings.
+    ///
+    /// Arguments:
+    ///     folder_id: The unique folder id, e.g. `CampaignFolderType.id`
+    ///
+    pub fn move_to_folder(&self, folder_id: &str) -> MailchimpResult<CampaignType> {
+        // PATCH /campaigns/{campaign_id}
+        let mut settings = self.settings.clone().unwrap_or_default();
+        settings.folder_id = Some(folder_id.to_string());
+        let param = UpdateCampaignParam {
+            recipients: None,
+            settings: Some(settings),
+            variate_settings: None,
+            tracking: None,
+            rss_opts: None,
+            social_card: None,
+        };
+        let mut campaign = self.update(param)?;
+        campaign.set_api(self._api.clone());
+        Ok(campaign)
+    }
+
     // ======================== Content ===========
 
     ///
@@ -795,37 +832,14 @@ impl CampaignType {
             filters.exclude_fields = Some(ex.clone())
         }
 
-        let payload = filters.build_payload();
-        let response = self
-            ._api
-            .get::<CollectionCampaignFeedback>(&endpoint, payload);
-        match response {
-            Ok(collection) => MalchimpIter {
-                builder: CampaignFeedbackBuilder {
-                    endpoint: endpoint.clone(),
-                },
-                data: collection.feedback,
-                cur_filters: filters.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
+        MalchimpIter::from_endpoint(
+            CampaignFeedbackBuilder {
                 endpoint: endpoint.clone(),
             },
-            Err(e) => {
-                println!("Feedback Iter {:?}", e);
-                MalchimpIter {
-                    builder: CampaignFeedbackBuilder {
-                        endpoint: endpoint.clone(),
-                    },
-                    data: Vec::new(),
-                    cur_filters: filters.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+            self._api.clone(),
+            &endpoint,
+            filters,
+        )
     }
 
     ///
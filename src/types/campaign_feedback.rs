@@ -3,12 +3,13 @@
 
 use super::empty::EmptyType;
 use super::link::LinkType;
+use super::timestamp::Timestamp;
 use crate::api::MailchimpApi;
 use crate::internal::request::MailchimpResult;
 use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Campaign Feedback Type
@@ -34,11 +35,11 @@ pub struct CampaignFeedbackType {
     #[serde(default)]
     pub created_by: String,
     /// The date and time the feedback item was created in ISO 8601 format.
-    #[serde(default)]
-    pub created_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at: Timestamp,
     /// The date and time the feedback was last updated in ISO 8601 format.
-    #[serde(default)]
-    pub updated_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub updated_at: Timestamp,
     /// The source of the feedback. api email smsw eb ios android
     #[serde(default)]
     pub source: String,
@@ -51,7 +52,7 @@ pub struct CampaignFeedbackType {
 
     /// Mailchimp API
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
     /// Endpoint
     #[serde(skip)]
     _endpoint: String,
@@ -116,7 +117,7 @@ impl BuildIter for CampaignFeedbackBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data.set_endpoint(&self.endpoint);
@@ -169,7 +170,7 @@ impl CampaignFeedbackType {
     ///
     /// Mailchimp API
     ///
-    pub fn set_api(&mut self, api: Rc<MailchimpApi>) {
+    pub fn set_api(&mut self, api: Arc<MailchimpApi>) {
         self._api = api
     }
 
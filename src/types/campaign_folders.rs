@@ -0,0 +1,139 @@
+//! Implement Campaign Folders Model Types
+
+use super::empty::EmptyType;
+use super::link::LinkType;
+use crate::api::MailchimpApi;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Folder used to organize campaigns
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CampaignFolderType {
+    /// The name of the folder.
+    #[serde(default)]
+    pub name: String,
+    /// A string that uniquely identifies this campaign folder.
+    #[serde(default)]
+    pub id: String,
+    /// The number of campaigns in the folder.
+    #[serde(default)]
+    pub count: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+}
+
+///
+/// Create/Update Campaign Folder
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CampaignFolderParam {
+    /// The name of the folder.
+    #[serde(default)]
+    pub name: String,
+}
+
+///
+/// Response for endpoint GET /campaign-folders
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionCampaignFolders {
+    /// An array of objects representing campaign folders.
+    #[serde(default)]
+    pub folders: Vec<CampaignFolderType>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<CampaignFolderType> for CollectionCampaignFolders {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<CampaignFolderType> {
+        self.folders.clone()
+    }
+}
+
+///
+/// CampaignFoldersBuilder
+///
+#[derive(Debug)]
+pub struct CampaignFoldersBuilder {}
+
+impl BuildIter for CampaignFoldersBuilder {
+    type Item = CampaignFolderType;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionCampaignFolders;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+impl CampaignFolderType {
+    ///
+    /// Update a specific folder used to organize campaigns
+    ///
+    pub fn update(&self, param: CampaignFolderParam) -> MailchimpResult<CampaignFolderType> {
+        // PATCH /campaign-folders/{folder_id}
+        let mut data = self
+            ._api
+            .patch::<CampaignFolderType, CampaignFolderParam>(&self.get_base_endpoint(), param)?;
+        data.set_api(self._api.clone());
+        Ok(data)
+    }
+
+    ///
+    /// Delete a specific campaign folder, and mark all the campaigns in the
+    /// folder as ‘unfiled’
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /campaign-folders/{folder_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("campaign-folders/{}", self.id)
+    }
+}
@@ -2,11 +2,12 @@
 //!
 
 use super::link::LinkType;
+use super::timestamp::{format_datetime, MailchimpDateTime, Timestamp};
 use crate::api::MailchimpApi;
 use crate::iter::{BuildIter, MailchimpCollection, ResourceFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// The most recent message in the conversation.
@@ -38,8 +39,8 @@ pub struct ConversationMessage {
     #[serde(default)]
     pub read: bool,
     /// The date and time the message was either sent or received in ISO 8601 format.
-    #[serde(default)]
-    pub timestamp: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub timestamp: Timestamp,
     /// A list of link types and descriptions for the API schema documents.
     #[serde(default)]
     pub _links: Vec<LinkType>,
@@ -56,7 +57,7 @@ impl Default for ConversationMessage {
             subject: "".to_string(),
             message: "".to_string(),
             read: true,
-            timestamp: "".to_string(),
+            timestamp: Timestamp::default(),
             _links: Vec::new(),
         }
     }
@@ -126,7 +127,7 @@ impl Default for CollectionConversationMessages {
 ///
 /// Messages Filter
 ///
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MessagesFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -141,10 +142,10 @@ pub struct MessagesFilter {
     pub offset: Option<u64>,
     /// Restrict the response to messages created before the set time. We recommend ISO 8601
     /// time format: 2015-10-21T15:41:36+00:00.
-    pub before_timestamp: Option<String>,
+    pub before_timestamp: Option<MailchimpDateTime>,
     /// Restrict the response to messages created after the set time. We recommend ISO 8601
     /// time format: 2015-10-21T15:41:36+00:00.
-    pub since_timestamp: Option<String>,
+    pub since_timestamp: Option<MailchimpDateTime>,
 }
 
 impl Default for MessagesFilter {
@@ -186,17 +187,11 @@ impl ResourceFilter for MessagesFilter {
             );
         }
 
-        if self.before_timestamp.is_some() {
-            payload.insert(
-                "before_timestamp".to_string(),
-                format!("{:}", self.before_timestamp.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.before_timestamp {
+            payload.insert("before_timestamp".to_string(), format_datetime(v));
         }
-        if self.since_timestamp.is_some() {
-            payload.insert(
-                "since_timestamp".to_string(),
-                format!("{:}", self.since_timestamp.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.since_timestamp {
+            payload.insert("since_timestamp".to_string(), format_datetime(v));
         }
         payload
     }
@@ -216,7 +211,7 @@ impl BuildIter for MessagesBuider {
     ///
     /// Return a new data updated
     ///
-    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
         let in_data = data.clone();
         in_data
     }
@@ -1,17 +1,14 @@
 //! Conversations
 //!
 
-use super::conversation_messages::{
-    CollectionConversationMessages, ConversationMessage, MessagesBuider, MessagesFilter,
-};
+use super::conversation_messages::{ConversationMessage, MessagesBuider, MessagesFilter};
 use serde::{Deserialize, Serialize};
-use std::rc::Rc;
+use std::sync::Arc;
 
 use super::link::LinkType;
 use crate::api::MailchimpApi;
 use crate::internal::request::MailchimpResult;
 use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, ResourceFilter};
-use log::error;
 use std::collections::HashMap;
 
 ///
@@ -53,7 +50,7 @@ pub struct Conversation {
 
     /// Mailchimp API
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
 }
 
 ///
@@ -104,32 +101,12 @@ impl Conversation {
             filter_params = f;
         }
 
-        match self
-            ._api
-            .get::<CollectionConversationMessages>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: MessagesBuider {},
-                data: collection.conversation_messages,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: endpoint.to_string(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Activities: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: MessagesBuider {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.to_string(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            MessagesBuider {},
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -150,7 +127,7 @@ impl Conversation {
     ///
     /// Update API
     ///
-    pub fn set_api(&mut self, n_api: Rc<MailchimpApi>) {
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
         self._api = n_api;
     }
 
@@ -202,7 +179,7 @@ impl Default for CollectionConversations {
 ///
 /// Conversations Filter
 ///
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ConversationsFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -299,7 +276,7 @@ impl BuildIter for ConversationBuilder {
     ///
     /// Return a new data updated
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data
@@ -0,0 +1,425 @@
+//! Implement E-commerce Carts Model Types
+
+use super::ecommerce_customers::{EcommerceCustomer, EcommerceCustomerParam};
+use super::empty::EmptyType;
+use super::link::LinkType;
+use super::timestamp::Timestamp;
+use crate::api::MailchimpApi;
+use crate::internal::api::path_segment;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, SimpleFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Cart of an E-commerce Store
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceCart {
+    /// A unique identifier for the cart.
+    #[serde(default)]
+    pub id: String,
+    /// Information about a specific customer.
+    #[serde(default)]
+    pub customer: EcommerceCustomer,
+    /// A string that uniquely identifies the campaign associated with a cart.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The URL for the cart.
+    #[serde(default)]
+    pub checkout_url: String,
+    /// The three-letter ISO 4217 code for the currency that the cart uses.
+    #[serde(default)]
+    pub currency_code: String,
+    /// The order total for the cart.
+    #[serde(default)]
+    pub order_total: f64,
+    /// The total tax for the cart.
+    #[serde(default)]
+    pub tax_total: f64,
+    /// An array of the cart’s line items.
+    #[serde(default)]
+    pub lines: Vec<EcommerceCartLine>,
+    /// The date and time the cart was created in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at: Timestamp,
+    /// The date and time the cart was last updated in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub updated_at: Timestamp,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+    /// Endpoint
+    #[serde(skip)]
+    _endpoint: String,
+}
+
+///
+/// Create/Update E-commerce Cart
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceCartParam {
+    /// A unique identifier for the cart. Required to create a cart.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Information about a specific customer. Required to create a cart.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<EcommerceCustomerParam>,
+    /// A string that uniquely identifies the campaign for a cart.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub campaign_id: Option<String>,
+    /// The URL for the cart.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkout_url: Option<String>,
+    /// The three-letter ISO 4217 code for the currency that the cart uses.
+    /// Required to create a cart.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+    /// The order total for the cart. Required to create a cart.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_total: Option<f64>,
+    /// The total tax for the cart.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_total: Option<f64>,
+    /// An array of the cart’s line items. Required to create a cart.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lines: Option<Vec<EcommerceCartLineParam>>,
+}
+
+///
+/// Line item of a cart
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceCartLine {
+    /// A unique identifier for the cart line item.
+    #[serde(default)]
+    pub id: String,
+    /// A unique identifier for the product associated with the cart line item.
+    #[serde(default)]
+    pub product_id: String,
+    /// The name of the product for the cart line item.
+    #[serde(default)]
+    pub product_title: String,
+    /// A unique identifier for the product variant associated with the cart line item.
+    #[serde(default)]
+    pub product_variant_id: String,
+    /// The name of the product variant for the cart line item.
+    #[serde(default)]
+    pub product_variant_title: String,
+    /// The quantity of a cart line item.
+    #[serde(default)]
+    pub quantity: u64,
+    /// The price of a cart line item.
+    #[serde(default)]
+    pub price: f64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+    /// Endpoint
+    #[serde(skip)]
+    _endpoint: String,
+}
+
+///
+/// Create/Update Cart Line Item
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceCartLineParam {
+    /// A unique identifier for the cart line item. Required to create a line item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// A unique identifier for the product. Required to create a line item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<String>,
+    /// A unique identifier for the product variant. Required to create a line item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_variant_id: Option<String>,
+    /// The quantity of a cart line item. Required to create a line item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    /// The price of a cart line item. Required to create a line item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+}
+
+///
+/// Response for endpoint GET /ecommerce/stores/{store_id}/carts
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEcommerceCarts {
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// An array of objects, each representing a cart.
+    #[serde(default)]
+    pub carts: Vec<EcommerceCart>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EcommerceCart> for CollectionEcommerceCarts {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EcommerceCart> {
+        self.carts.clone()
+    }
+}
+
+///
+/// Response for endpoint GET /ecommerce/stores/{store_id}/carts/{cart_id}/lines
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEcommerceCartLines {
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// The cart id.
+    #[serde(default)]
+    pub cart_id: String,
+    /// An array of objects, each representing a cart’s line item.
+    #[serde(default)]
+    pub lines: Vec<EcommerceCartLine>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EcommerceCartLine> for CollectionEcommerceCartLines {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EcommerceCartLine> {
+        self.lines.clone()
+    }
+}
+
+///
+/// EcommerceCartsBuilder
+///
+#[derive(Debug)]
+pub struct EcommerceCartsBuilder {
+    /// Resource Endpoint
+    pub endpoint: String,
+}
+
+impl BuildIter for EcommerceCartsBuilder {
+    type Item = EcommerceCart;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionEcommerceCarts;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data.set_endpoint(&self.endpoint);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// EcommerceCartLinesBuilder
+///
+#[derive(Debug)]
+pub struct EcommerceCartLinesBuilder {
+    /// Resource Endpoint
+    pub endpoint: String,
+}
+
+impl BuildIter for EcommerceCartLinesBuilder {
+    type Item = EcommerceCartLine;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionEcommerceCartLines;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data.set_endpoint(&self.endpoint);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+impl EcommerceCart {
+    ///
+    /// Update a cart
+    ///
+    pub fn update(&self, param: EcommerceCartParam) -> MailchimpResult<EcommerceCart> {
+        // PATCH /ecommerce/stores/{store_id}/carts/{cart_id}
+        let mut data = self
+            ._api
+            .patch::<EcommerceCart, EcommerceCartParam>(&self.get_base_endpoint(), param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&self._endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Delete a cart
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /ecommerce/stores/{store_id}/carts/{cart_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Get information about a cart’s line items
+    ///
+    pub fn get_lines(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<EcommerceCartLinesBuilder> {
+        // GET /ecommerce/stores/{store_id}/carts/{cart_id}/lines
+        let endpoint = self.get_base_endpoint() + "/lines";
+        MalchimpIter::from_endpoint(
+            EcommerceCartLinesBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific cart line item
+    ///
+    pub fn get_line(&self, line_id: &str) -> MailchimpResult<EcommerceCartLine> {
+        // GET /ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}
+        let endpoint = self.get_base_endpoint() + "/lines";
+        let mut data = self._api.get::<EcommerceCartLine>(
+            &format!("{}/{}", endpoint, path_segment(line_id)),
+            HashMap::new(),
+        )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add a new line item to an existing cart
+    ///
+    pub fn add_line(&self, param: EcommerceCartLineParam) -> MailchimpResult<EcommerceCartLine> {
+        // POST /ecommerce/stores/{store_id}/carts/{cart_id}/lines
+        let endpoint = self.get_base_endpoint() + "/lines";
+        let mut data = self
+            ._api
+            .post::<EcommerceCartLine, EcommerceCartLineParam>(&endpoint, param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        for item in self.lines.iter_mut() {
+            item.set_api(n_api.clone());
+        }
+        self._api = n_api;
+    }
+
+    /// Set Endpoint
+    pub fn set_endpoint(&mut self, n_endpoint: &str) {
+        self._endpoint = n_endpoint.to_string();
+        let line_endpoint = self.get_base_endpoint() + "/lines";
+        for item in self.lines.iter_mut() {
+            item.set_endpoint(&line_endpoint);
+        }
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("{}/{}", self._endpoint, path_segment(&self.id))
+    }
+}
+
+impl EcommerceCartLine {
+    ///
+    /// Update a specific cart line item
+    ///
+    pub fn update(&self, param: EcommerceCartLineParam) -> MailchimpResult<EcommerceCartLine> {
+        // PATCH /ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}
+        let mut data = self
+            ._api
+            .patch::<EcommerceCartLine, EcommerceCartLineParam>(&self.get_base_endpoint(), param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&self._endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Delete a specific cart line item
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    /// Set Endpoint
+    pub fn set_endpoint(&mut self, n_endpoint: &str) {
+        self._endpoint = n_endpoint.to_string();
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("{}/{}", self._endpoint, path_segment(&self.id))
+    }
+}
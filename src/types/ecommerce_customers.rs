@@ -0,0 +1,200 @@
+//! Implement E-commerce Customers Model Types
+
+use super::ecommerce_stores::EcommerceAddress;
+use super::empty::EmptyType;
+use super::link::LinkType;
+use super::timestamp::Timestamp;
+use crate::api::MailchimpApi;
+use crate::internal::api::path_segment;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Customer of an E-commerce Store
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceCustomer {
+    /// A unique identifier for the customer.
+    #[serde(default)]
+    pub id: String,
+    /// The customer’s email address.
+    #[serde(default)]
+    pub email_address: String,
+    /// The customer’s opt-in status. This value will never overwrite the opt-in
+    /// status of a pre-existing Mailchimp list member.
+    #[serde(default)]
+    pub opt_in_status: bool,
+    /// The customer’s company.
+    #[serde(default)]
+    pub company: String,
+    /// The customer’s first name.
+    #[serde(default)]
+    pub first_name: String,
+    /// The customer’s last name.
+    #[serde(default)]
+    pub last_name: String,
+    /// The customer’s total order count.
+    #[serde(default)]
+    pub orders_count: u64,
+    /// The total amount the customer has spent.
+    #[serde(default)]
+    pub total_spent: f64,
+    /// The customer’s address.
+    #[serde(default)]
+    pub address: EcommerceAddress,
+    /// The date and time the customer was created in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at: Timestamp,
+    /// The date and time the customer was last updated in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub updated_at: Timestamp,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+    /// Endpoint
+    #[serde(skip)]
+    _endpoint: String,
+}
+
+///
+/// Create/Update E-commerce Customer
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceCustomerParam {
+    /// A unique identifier for the customer. Required to create a customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The customer’s email address. Required to create a customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    /// The customer’s opt-in status. Required to create a customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opt_in_status: Option<bool>,
+    /// The customer’s company.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    /// The customer’s first name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// The customer’s last name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// The customer’s address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<EcommerceAddress>,
+}
+
+///
+/// Response for endpoint GET /ecommerce/stores/{store_id}/customers
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEcommerceCustomers {
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// An array of objects, each representing a customer of a store.
+    #[serde(default)]
+    pub customers: Vec<EcommerceCustomer>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EcommerceCustomer> for CollectionEcommerceCustomers {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EcommerceCustomer> {
+        self.customers.clone()
+    }
+}
+
+///
+/// EcommerceCustomersBuilder
+///
+#[derive(Debug)]
+pub struct EcommerceCustomersBuilder {
+    /// Resource Endpoint
+    pub endpoint: String,
+}
+
+impl BuildIter for EcommerceCustomersBuilder {
+    type Item = EcommerceCustomer;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionEcommerceCustomers;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data.set_endpoint(&self.endpoint);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+impl EcommerceCustomer {
+    ///
+    /// Update a customer
+    ///
+    pub fn update(&self, param: EcommerceCustomerParam) -> MailchimpResult<EcommerceCustomer> {
+        // PATCH /ecommerce/stores/{store_id}/customers/{customer_id}
+        let mut data = self
+            ._api
+            .patch::<EcommerceCustomer, EcommerceCustomerParam>(&self.get_base_endpoint(), param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&self._endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Delete a customer from a store
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /ecommerce/stores/{store_id}/customers/{customer_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    /// Set Endpoint
+    pub fn set_endpoint(&mut self, n_endpoint: &str) {
+        self._endpoint = n_endpoint.to_string();
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("{}/{}", self._endpoint, path_segment(&self.id))
+    }
+}
@@ -0,0 +1,536 @@
+//! Implement E-commerce Orders Model Types
+
+use super::ecommerce_customers::{EcommerceCustomer, EcommerceCustomerParam};
+use super::ecommerce_stores::EcommerceAddress;
+use super::empty::EmptyType;
+use super::link::LinkType;
+use super::timestamp::{MailchimpDateTime, Timestamp};
+use crate::api::MailchimpApi;
+use crate::internal::api::path_segment;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, SimpleFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Promo code applied to an order
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceOrderPromo {
+    /// The Promo Code
+    #[serde(default)]
+    pub code: String,
+    /// The amount of discount applied on the total price.
+    #[serde(default)]
+    pub amount_discounted: f64,
+    /// Type of discount.
+    /// Possible Values: fixed percentage
+    #[serde(default, rename = "type")]
+    pub promo_type: String,
+}
+
+///
+/// Order of an E-commerce Store
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceOrder {
+    /// A unique identifier for the order.
+    #[serde(default)]
+    pub id: String,
+    /// Information about a specific customer.
+    #[serde(default)]
+    pub customer: EcommerceCustomer,
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// A string that uniquely identifies the campaign associated with an order.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The URL for the page where the buyer landed when entering the shop.
+    #[serde(default)]
+    pub landing_site: String,
+    /// The order status.
+    #[serde(default)]
+    pub financial_status: String,
+    /// The fulfillment status for the order.
+    #[serde(default)]
+    pub fulfillment_status: String,
+    /// The three-letter ISO 4217 code for the currency that the store accepts.
+    #[serde(default)]
+    pub currency_code: String,
+    /// The total for the order.
+    #[serde(default)]
+    pub order_total: f64,
+    /// The URL for the order.
+    #[serde(default)]
+    pub order_url: String,
+    /// The total amount of the discounts to be applied to the price of the order.
+    #[serde(default)]
+    pub discount_total: f64,
+    /// The tax total for the order.
+    #[serde(default)]
+    pub tax_total: f64,
+    /// The shipping total for the order.
+    #[serde(default)]
+    pub shipping_total: f64,
+    /// The Mailchimp tracking code for the order.
+    #[serde(default)]
+    pub tracking_code: String,
+    /// The date and time the order was processed in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub processed_at_foreign: Timestamp,
+    /// The date and time the order was cancelled in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub cancelled_at_foreign: Timestamp,
+    /// The date and time the order was updated in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub updated_at_foreign: Timestamp,
+    /// The shipping address for the order.
+    #[serde(default)]
+    pub shipping_address: EcommerceAddress,
+    /// The billing address for the order.
+    #[serde(default)]
+    pub billing_address: EcommerceAddress,
+    /// The promo codes applied on the order.
+    #[serde(default)]
+    pub promos: Vec<EcommerceOrderPromo>,
+    /// An array of the order’s line items.
+    #[serde(default)]
+    pub lines: Vec<EcommerceOrderLine>,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+    /// Endpoint
+    #[serde(skip)]
+    _endpoint: String,
+}
+
+///
+/// Create/Update E-commerce Order
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceOrderParam {
+    /// A unique identifier for the order. Required to create an order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Information about a specific customer. Required to create an order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<EcommerceCustomerParam>,
+    /// A string that uniquely identifies the campaign for an order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub campaign_id: Option<String>,
+    /// The URL for the page where the buyer landed when entering the shop.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub landing_site: Option<String>,
+    /// The order status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub financial_status: Option<String>,
+    /// The fulfillment status for the order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment_status: Option<String>,
+    /// The three-letter ISO 4217 code for the currency that the store accepts.
+    /// Required to create an order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+    /// The total for the order. Required to create an order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_total: Option<f64>,
+    /// The URL for the order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_url: Option<String>,
+    /// The total amount of the discounts to be applied to the price of the order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discount_total: Option<f64>,
+    /// The tax total for the order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_total: Option<f64>,
+    /// The shipping total for the order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping_total: Option<f64>,
+    /// The Mailchimp tracking code for the order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking_code: Option<String>,
+    /// The date and time the order was processed in ISO 8601 format.
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub processed_at_foreign: Option<MailchimpDateTime>,
+    /// The date and time the order was cancelled in ISO 8601 format.
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cancelled_at_foreign: Option<MailchimpDateTime>,
+    /// The date and time the order was updated in ISO 8601 format.
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at_foreign: Option<MailchimpDateTime>,
+    /// The shipping address for the order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<EcommerceAddress>,
+    /// The billing address for the order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub billing_address: Option<EcommerceAddress>,
+    /// The promo codes applied on the order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promos: Option<Vec<EcommerceOrderPromo>>,
+    /// An array of the order’s line items. Required to create an order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lines: Option<Vec<EcommerceOrderLineParam>>,
+}
+
+///
+/// Line item of an order
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceOrderLine {
+    /// A unique identifier for the order line item.
+    #[serde(default)]
+    pub id: String,
+    /// A unique identifier for the product associated with the order line item.
+    #[serde(default)]
+    pub product_id: String,
+    /// The name of the product for the order line item.
+    #[serde(default)]
+    pub product_title: String,
+    /// A unique identifier for the product variant associated with the order line item.
+    #[serde(default)]
+    pub product_variant_id: String,
+    /// The name of the product variant for the order line item.
+    #[serde(default)]
+    pub product_variant_title: String,
+    /// The image URL for a product.
+    #[serde(default)]
+    pub image_url: String,
+    /// The quantity of an order line item.
+    #[serde(default)]
+    pub quantity: u64,
+    /// The price of an order line item.
+    #[serde(default)]
+    pub price: f64,
+    /// The total discount amount applied to this line item.
+    #[serde(default)]
+    pub discount: f64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+    /// Endpoint
+    #[serde(skip)]
+    _endpoint: String,
+}
+
+///
+/// Create/Update Order Line Item
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceOrderLineParam {
+    /// A unique identifier for the order line item. Required to create a line item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// A unique identifier for the product. Required to create a line item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<String>,
+    /// A unique identifier for the product variant. Required to create a line item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_variant_id: Option<String>,
+    /// The quantity of an order line item. Required to create a line item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    /// The price of an order line item. Required to create a line item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+    /// The total discount amount applied to this line item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discount: Option<f64>,
+}
+
+///
+/// Response for endpoint GET /ecommerce/stores/{store_id}/orders
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEcommerceOrders {
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// An array of objects, each representing an order resource.
+    #[serde(default)]
+    pub orders: Vec<EcommerceOrder>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EcommerceOrder> for CollectionEcommerceOrders {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EcommerceOrder> {
+        self.orders.clone()
+    }
+}
+
+///
+/// Response for endpoint GET /ecommerce/stores/{store_id}/orders/{order_id}/lines
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEcommerceOrderLines {
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// The order id.
+    #[serde(default)]
+    pub order_id: String,
+    /// An array of objects, each representing an order’s line item.
+    #[serde(default)]
+    pub lines: Vec<EcommerceOrderLine>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EcommerceOrderLine> for CollectionEcommerceOrderLines {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EcommerceOrderLine> {
+        self.lines.clone()
+    }
+}
+
+///
+/// EcommerceOrdersBuilder
+///
+#[derive(Debug)]
+pub struct EcommerceOrdersBuilder {
+    /// Resource Endpoint
+    pub endpoint: String,
+}
+
+impl BuildIter for EcommerceOrdersBuilder {
+    type Item = EcommerceOrder;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionEcommerceOrders;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data.set_endpoint(&self.endpoint);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// EcommerceOrderLinesBuilder
+///
+#[derive(Debug)]
+pub struct EcommerceOrderLinesBuilder {
+    /// Resource Endpoint
+    pub endpoint: String,
+}
+
+impl BuildIter for EcommerceOrderLinesBuilder {
+    type Item = EcommerceOrderLine;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionEcommerceOrderLines;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data.set_endpoint(&self.endpoint);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+impl EcommerceOrder {
+    ///
+    /// Update an order
+    ///
+    pub fn update(&self, param: EcommerceOrderParam) -> MailchimpResult<EcommerceOrder> {
+        // PATCH /ecommerce/stores/{store_id}/orders/{order_id}
+        let mut data = self
+            ._api
+            .patch::<EcommerceOrder, EcommerceOrderParam>(&self.get_base_endpoint(), param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&self._endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Delete an order
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /ecommerce/stores/{store_id}/orders/{order_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Get information about an order’s line items
+    ///
+    pub fn get_lines(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<EcommerceOrderLinesBuilder> {
+        // GET /ecommerce/stores/{store_id}/orders/{order_id}/lines
+        let endpoint = self.get_base_endpoint() + "/lines";
+        MalchimpIter::from_endpoint(
+            EcommerceOrderLinesBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific order line item
+    ///
+    pub fn get_line(&self, line_id: &str) -> MailchimpResult<EcommerceOrderLine> {
+        // GET /ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}
+        let endpoint = self.get_base_endpoint() + "/lines";
+        let mut data = self._api.get::<EcommerceOrderLine>(
+            &format!("{}/{}", endpoint, path_segment(line_id)),
+            HashMap::new(),
+        )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add a new line item to an existing order
+    ///
+    pub fn add_line(&self, param: EcommerceOrderLineParam) -> MailchimpResult<EcommerceOrderLine> {
+        // POST /ecommerce/stores/{store_id}/orders/{order_id}/lines
+        let endpoint = self.get_base_endpoint() + "/lines";
+        let mut data = self
+            ._api
+            .post::<EcommerceOrderLine, EcommerceOrderLineParam>(&endpoint, param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        for item in self.lines.iter_mut() {
+            item.set_api(n_api.clone());
+        }
+        self._api = n_api;
+    }
+
+    /// Set Endpoint
+    pub fn set_endpoint(&mut self, n_endpoint: &str) {
+        self._endpoint = n_endpoint.to_string();
+        let line_endpoint = self.get_base_endpoint() + "/lines";
+        for item in self.lines.iter_mut() {
+            item.set_endpoint(&line_endpoint);
+        }
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("{}/{}", self._endpoint, path_segment(&self.id))
+    }
+}
+
+impl EcommerceOrderLine {
+    ///
+    /// Update a specific order line item
+    ///
+    pub fn update(&self, param: EcommerceOrderLineParam) -> MailchimpResult<EcommerceOrderLine> {
+        // PATCH /ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}
+        let mut data = self
+            ._api
+            .patch::<EcommerceOrderLine, EcommerceOrderLineParam>(
+                &self.get_base_endpoint(),
+                param,
+            )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&self._endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Delete a specific order line item
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    /// Set Endpoint
+    pub fn set_endpoint(&mut self, n_endpoint: &str) {
+        self._endpoint = n_endpoint.to_string();
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("{}/{}", self._endpoint, path_segment(&self.id))
+    }
+}
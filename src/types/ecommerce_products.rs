@@ -0,0 +1,713 @@
+//! Implement E-commerce Products Model Types
+
+use super::empty::EmptyType;
+use super::link::LinkType;
+use super::timestamp::{MailchimpDateTime, Timestamp};
+use crate::api::MailchimpApi;
+use crate::internal::api::path_segment;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, SimpleFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Product of an E-commerce Store
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceProduct {
+    /// A unique identifier for the product.
+    #[serde(default)]
+    pub id: String,
+    /// The currency code of the store.
+    #[serde(default)]
+    pub currency_code: String,
+    /// The title of a product.
+    #[serde(default)]
+    pub title: String,
+    /// The handle of a product.
+    #[serde(default)]
+    pub handle: String,
+    /// The URL for a product.
+    #[serde(default)]
+    pub url: String,
+    /// The description of a product.
+    #[serde(default)]
+    pub description: String,
+    /// The type of product.
+    #[serde(default, rename = "type")]
+    pub p_type: String,
+    /// The vendor for a product.
+    #[serde(default)]
+    pub vendor: String,
+    /// The image URL for a product.
+    #[serde(default)]
+    pub image_url: String,
+    /// An array of the product’s variants.
+    #[serde(default)]
+    pub variants: Vec<EcommerceProductVariant>,
+    /// An array of the product’s images.
+    #[serde(default)]
+    pub images: Vec<EcommerceProductImage>,
+    /// The date and time the product was published in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub published_at_foreign: Timestamp,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+    /// Endpoint
+    #[serde(skip)]
+    _endpoint: String,
+}
+
+///
+/// Create/Update E-commerce Product
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceProductParam {
+    /// A unique identifier for the product. Required to create a product.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The title of a product. Required to create a product.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The handle of a product.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    /// The URL for a product.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The description of a product.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The type of product.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub p_type: Option<String>,
+    /// The vendor for a product.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    /// The image URL for a product.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    /// An array of the product’s variants. At least one variant is required
+    /// to create a product.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variants: Option<Vec<EcommerceProductVariantParam>>,
+    /// An array of the product’s images.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<EcommerceProductImageParam>>,
+    /// The date and time the product was published in ISO 8601 format.
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub published_at_foreign: Option<MailchimpDateTime>,
+}
+
+///
+/// Variant of a product
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceProductVariant {
+    /// A unique identifier for the product variant.
+    #[serde(default)]
+    pub id: String,
+    /// The title of a product variant.
+    #[serde(default)]
+    pub title: String,
+    /// The URL for a product variant.
+    #[serde(default)]
+    pub url: String,
+    /// The stock keeping unit (SKU) of a product variant.
+    #[serde(default)]
+    pub sku: String,
+    /// The price of a product variant.
+    #[serde(default)]
+    pub price: f64,
+    /// The inventory quantity of a product variant.
+    #[serde(default)]
+    pub inventory_quantity: i64,
+    /// The image URL for a product variant.
+    #[serde(default)]
+    pub image_url: String,
+    /// The backorders of a product variant.
+    #[serde(default)]
+    pub backorders: String,
+    /// The visibility of a product variant.
+    #[serde(default)]
+    pub visibility: String,
+    /// The date and time the product variant was created in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at: Timestamp,
+    /// The date and time the product variant was last updated in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub updated_at: Timestamp,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+    /// Endpoint
+    #[serde(skip)]
+    _endpoint: String,
+}
+
+///
+/// Create/Update Product Variant
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceProductVariantParam {
+    /// A unique identifier for the product variant. Required to create a variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The title of a product variant. Required to create a variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The URL for a product variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The stock keeping unit (SKU) of a product variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    /// The price of a product variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+    /// The inventory quantity of a product variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inventory_quantity: Option<i64>,
+    /// The image URL for a product variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    /// The backorders of a product variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backorders: Option<String>,
+    /// The visibility of a product variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+}
+
+///
+/// Image of a product
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceProductImage {
+    /// A unique identifier for the product image.
+    #[serde(default)]
+    pub id: String,
+    /// The URL for a product image.
+    #[serde(default)]
+    pub url: String,
+    /// The list of product variants using the image.
+    #[serde(default)]
+    pub variant_ids: Vec<String>,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+    /// Endpoint
+    #[serde(skip)]
+    _endpoint: String,
+}
+
+///
+/// Create/Update Product Image
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceProductImageParam {
+    /// A unique identifier for the product image. Required to create an image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The URL for a product image. Required to create an image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The list of product variants using the image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant_ids: Option<Vec<String>>,
+}
+
+///
+/// Response for endpoint GET /ecommerce/stores/{store_id}/products
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEcommerceProducts {
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// An array of objects, each representing a store product.
+    #[serde(default)]
+    pub products: Vec<EcommerceProduct>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EcommerceProduct> for CollectionEcommerceProducts {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EcommerceProduct> {
+        self.products.clone()
+    }
+}
+
+///
+/// Response for endpoint GET /ecommerce/stores/{store_id}/products/{product_id}/variants
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEcommerceProductVariants {
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// The product id.
+    #[serde(default)]
+    pub product_id: String,
+    /// An array of objects, each representing a product variant.
+    #[serde(default)]
+    pub variants: Vec<EcommerceProductVariant>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EcommerceProductVariant> for CollectionEcommerceProductVariants {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EcommerceProductVariant> {
+        self.variants.clone()
+    }
+}
+
+///
+/// Response for endpoint GET /ecommerce/stores/{store_id}/products/{product_id}/images
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEcommerceProductImages {
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// The product id.
+    #[serde(default)]
+    pub product_id: String,
+    /// An array of objects, each representing a product image.
+    #[serde(default)]
+    pub images: Vec<EcommerceProductImage>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EcommerceProductImage> for CollectionEcommerceProductImages {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EcommerceProductImage> {
+        self.images.clone()
+    }
+}
+
+///
+/// EcommerceProductsBuilder
+///
+#[derive(Debug)]
+pub struct EcommerceProductsBuilder {
+    /// Resource Endpoint
+    pub endpoint: String,
+}
+
+impl BuildIter for EcommerceProductsBuilder {
+    type Item = EcommerceProduct;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionEcommerceProducts;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data.set_endpoint(&self.endpoint);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// EcommerceProductVariantsBuilder
+///
+#[derive(Debug)]
+pub struct EcommerceProductVariantsBuilder {
+    /// Resource Endpoint
+    pub endpoint: String,
+}
+
+impl BuildIter for EcommerceProductVariantsBuilder {
+    type Item = EcommerceProductVariant;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionEcommerceProductVariants;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data.set_endpoint(&self.endpoint);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// EcommerceProductImagesBuilder
+///
+#[derive(Debug)]
+pub struct EcommerceProductImagesBuilder {
+    /// Resource Endpoint
+    pub endpoint: String,
+}
+
+impl BuildIter for EcommerceProductImagesBuilder {
+    type Item = EcommerceProductImage;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionEcommerceProductImages;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data.set_endpoint(&self.endpoint);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+impl EcommerceProduct {
+    ///
+    /// Update a product
+    ///
+    pub fn update(&self, param: EcommerceProductParam) -> MailchimpResult<EcommerceProduct> {
+        // PATCH /ecommerce/stores/{store_id}/products/{product_id}
+        let mut data = self
+            ._api
+            .patch::<EcommerceProduct, EcommerceProductParam>(&self.get_base_endpoint(), param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&self._endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Delete a product
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /ecommerce/stores/{store_id}/products/{product_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Get information about a product’s variants
+    ///
+    pub fn get_variants(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<EcommerceProductVariantsBuilder> {
+        // GET /ecommerce/stores/{store_id}/products/{product_id}/variants
+        let endpoint = self.get_base_endpoint() + "/variants";
+        MalchimpIter::from_endpoint(
+            EcommerceProductVariantsBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific product variant
+    ///
+    pub fn get_variant(&self, variant_id: &str) -> MailchimpResult<EcommerceProductVariant> {
+        // GET /ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}
+        let endpoint = self.get_base_endpoint() + "/variants";
+        let mut data = self._api.get::<EcommerceProductVariant>(
+            &format!("{}/{}", endpoint, path_segment(variant_id)),
+            HashMap::new(),
+        )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add a new variant to the product
+    ///
+    pub fn create_variant(
+        &self,
+        param: EcommerceProductVariantParam,
+    ) -> MailchimpResult<EcommerceProductVariant> {
+        // POST /ecommerce/stores/{store_id}/products/{product_id}/variants
+        let endpoint = self.get_base_endpoint() + "/variants";
+        let mut data = self
+            ._api
+            .post::<EcommerceProductVariant, EcommerceProductVariantParam>(&endpoint, param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add or update a product variant
+    ///
+    pub fn upsert_variant(
+        &self,
+        variant_id: &str,
+        param: EcommerceProductVariantParam,
+    ) -> MailchimpResult<EcommerceProductVariant> {
+        // PUT /ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}
+        let endpoint = self.get_base_endpoint() + "/variants";
+        let mut data = self
+            ._api
+            .put::<EcommerceProductVariant, EcommerceProductVariantParam>(
+                &format!("{}/{}", endpoint, path_segment(variant_id)),
+                param,
+            )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Get information about a product’s images
+    ///
+    pub fn get_images(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<EcommerceProductImagesBuilder> {
+        // GET /ecommerce/stores/{store_id}/products/{product_id}/images
+        let endpoint = self.get_base_endpoint() + "/images";
+        MalchimpIter::from_endpoint(
+            EcommerceProductImagesBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific product image
+    ///
+    pub fn get_image(&self, image_id: &str) -> MailchimpResult<EcommerceProductImage> {
+        // GET /ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}
+        let endpoint = self.get_base_endpoint() + "/images";
+        let mut data = self._api.get::<EcommerceProductImage>(
+            &format!("{}/{}", endpoint, path_segment(image_id)),
+            HashMap::new(),
+        )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add a new image to the product
+    ///
+    pub fn create_image(
+        &self,
+        param: EcommerceProductImageParam,
+    ) -> MailchimpResult<EcommerceProductImage> {
+        // POST /ecommerce/stores/{store_id}/products/{product_id}/images
+        let endpoint = self.get_base_endpoint() + "/images";
+        let mut data = self
+            ._api
+            .post::<EcommerceProductImage, EcommerceProductImageParam>(&endpoint, param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        for item in self.variants.iter_mut() {
+            item.set_api(n_api.clone());
+        }
+        for item in self.images.iter_mut() {
+            item.set_api(n_api.clone());
+        }
+        self._api = n_api;
+    }
+
+    /// Set Endpoint
+    pub fn set_endpoint(&mut self, n_endpoint: &str) {
+        self._endpoint = n_endpoint.to_string();
+        let variant_endpoint = self.get_base_endpoint() + "/variants";
+        for item in self.variants.iter_mut() {
+            item.set_endpoint(&variant_endpoint);
+        }
+        let image_endpoint = self.get_base_endpoint() + "/images";
+        for item in self.images.iter_mut() {
+            item.set_endpoint(&image_endpoint);
+        }
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("{}/{}", self._endpoint, path_segment(&self.id))
+    }
+}
+
+impl EcommerceProductVariant {
+    ///
+    /// Update a product variant
+    ///
+    pub fn update(
+        &self,
+        param: EcommerceProductVariantParam,
+    ) -> MailchimpResult<EcommerceProductVariant> {
+        // PATCH /ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}
+        let mut data = self
+            ._api
+            .patch::<EcommerceProductVariant, EcommerceProductVariantParam>(
+                &self.get_base_endpoint(),
+                param,
+            )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&self._endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Delete a product variant
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    /// Set Endpoint
+    pub fn set_endpoint(&mut self, n_endpoint: &str) {
+        self._endpoint = n_endpoint.to_string();
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("{}/{}", self._endpoint, path_segment(&self.id))
+    }
+}
+
+impl EcommerceProductImage {
+    ///
+    /// Update a product image
+    ///
+    pub fn update(
+        &self,
+        param: EcommerceProductImageParam,
+    ) -> MailchimpResult<EcommerceProductImage> {
+        // PATCH /ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}
+        let mut data = self
+            ._api
+            .patch::<EcommerceProductImage, EcommerceProductImageParam>(
+                &self.get_base_endpoint(),
+                param,
+            )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&self._endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Delete a product image
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    /// Set Endpoint
+    pub fn set_endpoint(&mut self, n_endpoint: &str) {
+        self._endpoint = n_endpoint.to_string();
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("{}/{}", self._endpoint, path_segment(&self.id))
+    }
+}
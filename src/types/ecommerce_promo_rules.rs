@@ -0,0 +1,606 @@
+//! Implement E-commerce Promo Rules and Promo Codes Model Types
+
+use super::empty::EmptyType;
+use super::enums::{EcommercePromoRuleTarget, EcommercePromoRuleType};
+use super::link::LinkType;
+use super::timestamp::{MailchimpDateTime, Timestamp};
+use crate::api::MailchimpApi;
+use crate::internal::api::path_segment;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, SimpleFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Kind of discount offered by a promo rule, built from the amount, the type
+/// and the target sent by Mailchimp
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum EcommerceDiscount {
+    /// Fixed amount off, in the currency of the store.
+    Fixed {
+        /// Amount of the discount
+        amount: f64,
+        /// Where the discount is applied
+        target: EcommercePromoRuleTarget,
+    },
+    /// Percentage off, between 0.0 and 1.0.
+    Percentage {
+        /// Percentage of the discount
+        amount: f64,
+        /// Where the discount is applied
+        target: EcommercePromoRuleTarget,
+    },
+    /// The whole shipping is free.
+    FreeShipping,
+    /// The type of the rule is not known by this version of the library.
+    Unknown,
+}
+
+///
+/// Promo Rule of an E-commerce Store
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommercePromoRule {
+    /// A unique identifier for the promo rule.
+    #[serde(default)]
+    pub id: String,
+    /// The title that will show up in promotion campaign.
+    #[serde(default)]
+    pub title: String,
+    /// The description of a promotion restricted to UTF-8 characters.
+    #[serde(default)]
+    pub description: String,
+    /// The date and time when the promotion is in effect in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub starts_at: Timestamp,
+    /// The date and time when the promotion ends in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub ends_at: Timestamp,
+    /// The amount of the promo code discount. If ‘type’ is ‘fixed’, the amount
+    /// is treated as a monetary value. If ‘type’ is ‘percentage’, amount must
+    /// be a decimal value between 0.0 and 1.0, inclusive.
+    #[serde(default)]
+    pub amount: f64,
+    /// Type of discount.
+    #[serde(default, rename = "type")]
+    pub rule_type: EcommercePromoRuleType,
+    /// The target that the discount applies to.
+    #[serde(default)]
+    pub target: EcommercePromoRuleTarget,
+    /// Whether the promo rule is currently enabled.
+    #[serde(default)]
+    pub enabled: bool,
+    /// The date and time the promotion was created in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at_foreign: Timestamp,
+    /// The date and time the promotion was updated in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub updated_at_foreign: Timestamp,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+    /// Endpoint
+    #[serde(skip)]
+    _endpoint: String,
+}
+
+///
+/// Create/Update Promo Rule
+///
+/// ```
+/// use mailchimp::types::{EcommercePromoRuleParam, EcommercePromoRuleTarget};
+///
+/// let param = EcommercePromoRuleParam::percentage("summer", "Summer sale", 0.15)
+///     .with_target(EcommercePromoRuleTarget::PerItem);
+/// let param = EcommercePromoRuleParam::free_shipping("free-ship", "Free shipping");
+/// ```
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommercePromoRuleParam {
+    /// A unique identifier for the promo rule. Required to create a promo rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The title that will show up in promotion campaign.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The description of a promotion. Required to create a promo rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The date and time when the promotion is in effect in ISO 8601 format.
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub starts_at: Option<MailchimpDateTime>,
+    /// The date and time when the promotion ends in ISO 8601 format.
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ends_at: Option<MailchimpDateTime>,
+    /// The amount of the promo code discount. Required to create a promo rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+    /// Type of discount. Required to create a promo rule.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub rule_type: Option<EcommercePromoRuleType>,
+    /// The target that the discount applies to. Required to create a promo rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<EcommercePromoRuleTarget>,
+    /// Whether the promo rule is currently enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The date and time the promotion was created in ISO 8601 format.
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_at_foreign: Option<MailchimpDateTime>,
+    /// The date and time the promotion was updated in ISO 8601 format.
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at_foreign: Option<MailchimpDateTime>,
+}
+
+impl EcommercePromoRuleParam {
+    ///
+    /// Fixed amount off the total of the order
+    ///
+    pub fn fixed(id: &str, description: &str, amount: f64) -> Self {
+        EcommercePromoRuleParam::with_discount(
+            id,
+            description,
+            amount,
+            EcommercePromoRuleType::Fixed,
+            EcommercePromoRuleTarget::Total,
+        )
+    }
+
+    ///
+    /// Percentage off the total of the order, `amount` between 0.0 and 1.0
+    ///
+    pub fn percentage(id: &str, description: &str, amount: f64) -> Self {
+        EcommercePromoRuleParam::with_discount(
+            id,
+            description,
+            amount,
+            EcommercePromoRuleType::Percentage,
+            EcommercePromoRuleTarget::Total,
+        )
+    }
+
+    ///
+    /// Free shipping, i.e. 100% off the shipping of the order
+    ///
+    pub fn free_shipping(id: &str, description: &str) -> Self {
+        EcommercePromoRuleParam::with_discount(
+            id,
+            description,
+            1.0,
+            EcommercePromoRuleType::Percentage,
+            EcommercePromoRuleTarget::Shipping,
+        )
+    }
+
+    ///
+    /// Change the target of the discount
+    ///
+    pub fn with_target(mut self, target: EcommercePromoRuleTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    fn with_discount(
+        id: &str,
+        description: &str,
+        amount: f64,
+        rule_type: EcommercePromoRuleType,
+        target: EcommercePromoRuleTarget,
+    ) -> Self {
+        EcommercePromoRuleParam {
+            id: Some(id.to_string()),
+            description: Some(description.to_string()),
+            amount: Some(amount),
+            rule_type: Some(rule_type),
+            target: Some(target),
+            ..EcommercePromoRuleParam::default()
+        }
+    }
+}
+
+///
+/// Promo Code of a Promo Rule
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommercePromoCode {
+    /// A unique identifier for the promo code.
+    #[serde(default)]
+    pub id: String,
+    /// The discount code. Restricted to UTF-8 characters with max length 50.
+    #[serde(default)]
+    pub code: String,
+    /// The url that should be used in the promotion campaign restricted to
+    /// UTF-8 characters with max length 2000.
+    #[serde(default)]
+    pub redemption_url: String,
+    /// Number of times promo code has been used.
+    #[serde(default)]
+    pub usage_count: u64,
+    /// Whether the promo code is currently enabled.
+    #[serde(default)]
+    pub enabled: bool,
+    /// The date and time the promotion was created in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at_foreign: Timestamp,
+    /// The date and time the promotion was updated in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub updated_at_foreign: Timestamp,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+    /// Endpoint
+    #[serde(skip)]
+    _endpoint: String,
+}
+
+///
+/// Create/Update Promo Code
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommercePromoCodeParam {
+    /// A unique identifier for the promo code. Required to create a promo code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The discount code. Required to create a promo code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The url that should be used in the promotion campaign. Required to
+    /// create a promo code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redemption_url: Option<String>,
+    /// Number of times promo code has been used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_count: Option<u64>,
+    /// Whether the promo code is currently enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The date and time the promotion was created in ISO 8601 format.
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_at_foreign: Option<MailchimpDateTime>,
+    /// The date and time the promotion was updated in ISO 8601 format.
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at_foreign: Option<MailchimpDateTime>,
+}
+
+impl EcommercePromoCodeParam {
+    ///
+    /// Promo code with the code that customers enter and the url used in the
+    /// promotion campaign
+    ///
+    pub fn new(id: &str, code: &str, redemption_url: &str) -> Self {
+        EcommercePromoCodeParam {
+            id: Some(id.to_string()),
+            code: Some(code.to_string()),
+            redemption_url: Some(redemption_url.to_string()),
+            ..EcommercePromoCodeParam::default()
+        }
+    }
+}
+
+///
+/// Response for endpoint GET /ecommerce/stores/{store_id}/promo-rules
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEcommercePromoRules {
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// An array of objects, each representing promo rules defined for a store.
+    #[serde(default)]
+    pub promo_rules: Vec<EcommercePromoRule>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EcommercePromoRule> for CollectionEcommercePromoRules {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EcommercePromoRule> {
+        self.promo_rules.clone()
+    }
+}
+
+///
+/// Response for endpoint GET /ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEcommercePromoCodes {
+    /// The store id.
+    #[serde(default)]
+    pub store_id: String,
+    /// An array of objects, each representing promo codes defined for a promo rule.
+    #[serde(default)]
+    pub promo_codes: Vec<EcommercePromoCode>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EcommercePromoCode> for CollectionEcommercePromoCodes {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EcommercePromoCode> {
+        self.promo_codes.clone()
+    }
+}
+
+///
+/// EcommercePromoRulesBuilder
+///
+#[derive(Debug)]
+pub struct EcommercePromoRulesBuilder {
+    /// Resource Endpoint
+    pub endpoint: String,
+}
+
+impl BuildIter for EcommercePromoRulesBuilder {
+    type Item = EcommercePromoRule;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionEcommercePromoRules;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data.set_endpoint(&self.endpoint);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// EcommercePromoCodesBuilder
+///
+#[derive(Debug)]
+pub struct EcommercePromoCodesBuilder {
+    /// Resource Endpoint
+    pub endpoint: String,
+}
+
+impl BuildIter for EcommercePromoCodesBuilder {
+    type Item = EcommercePromoCode;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionEcommercePromoCodes;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data.set_endpoint(&self.endpoint);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+impl EcommercePromoRule {
+    ///
+    /// Kind of discount offered by the rule
+    ///
+    pub fn discount(&self) -> EcommerceDiscount {
+        match (&self.rule_type, &self.target) {
+            (EcommercePromoRuleType::Percentage, EcommercePromoRuleTarget::Shipping)
+                if self.amount >= 1.0 =>
+            {
+                EcommerceDiscount::FreeShipping
+            }
+            (EcommercePromoRuleType::Fixed, target) => EcommerceDiscount::Fixed {
+                amount: self.amount,
+                target: target.clone(),
+            },
+            (EcommercePromoRuleType::Percentage, target) => EcommerceDiscount::Percentage {
+                amount: self.amount,
+                target: target.clone(),
+            },
+            (EcommercePromoRuleType::Unknown(_), _) => EcommerceDiscount::Unknown,
+        }
+    }
+
+    ///
+    /// Update a promo rule
+    ///
+    pub fn update(&self, param: EcommercePromoRuleParam) -> MailchimpResult<EcommercePromoRule> {
+        // PATCH /ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}
+        let mut data = self
+            ._api
+            .patch::<EcommercePromoRule, EcommercePromoRuleParam>(
+                &self.get_base_endpoint(),
+                param,
+            )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&self._endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Delete a promo rule. The promo codes of the rule are deleted too.
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Get information about the promo codes of the rule
+    ///
+    pub fn get_promo_codes(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<EcommercePromoCodesBuilder> {
+        // GET /ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes
+        let endpoint = self.get_base_endpoint() + "/promo-codes";
+        MalchimpIter::from_endpoint(
+            EcommercePromoCodesBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific promo code
+    ///
+    pub fn get_promo_code(&self, promo_code_id: &str) -> MailchimpResult<EcommercePromoCode> {
+        // GET /ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}
+        let endpoint = self.get_base_endpoint() + "/promo-codes";
+        let mut data = self._api.get::<EcommercePromoCode>(
+            &format!("{}/{}", endpoint, path_segment(promo_code_id)),
+            HashMap::new(),
+        )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add a new promo code to the rule
+    ///
+    pub fn create_promo_code(
+        &self,
+        param: EcommercePromoCodeParam,
+    ) -> MailchimpResult<EcommercePromoCode> {
+        // POST /ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes
+        let endpoint = self.get_base_endpoint() + "/promo-codes";
+        let mut data = self
+            ._api
+            .post::<EcommercePromoCode, EcommercePromoCodeParam>(&endpoint, param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    /// Set Endpoint
+    pub fn set_endpoint(&mut self, n_endpoint: &str) {
+        self._endpoint = n_endpoint.to_string();
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("{}/{}", self._endpoint, path_segment(&self.id))
+    }
+}
+
+impl EcommercePromoCode {
+    ///
+    /// Update a promo code
+    ///
+    pub fn update(&self, param: EcommercePromoCodeParam) -> MailchimpResult<EcommercePromoCode> {
+        // PATCH /ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}
+        let mut data = self
+            ._api
+            .patch::<EcommercePromoCode, EcommercePromoCodeParam>(
+                &self.get_base_endpoint(),
+                param,
+            )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&self._endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Delete a promo code
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    /// Set Endpoint
+    pub fn set_endpoint(&mut self, n_endpoint: &str) {
+        self._endpoint = n_endpoint.to_string();
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("{}/{}", self._endpoint, path_segment(&self.id))
+    }
+}
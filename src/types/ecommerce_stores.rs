@@ -0,0 +1,550 @@
+//! Implement E-commerce Stores Model Types
+
+use super::ecommerce_carts::{EcommerceCart, EcommerceCartParam, EcommerceCartsBuilder};
+use super::ecommerce_customers::{
+    EcommerceCustomer, EcommerceCustomerParam, EcommerceCustomersBuilder,
+};
+use super::ecommerce_orders::{EcommerceOrder, EcommerceOrderParam, EcommerceOrdersBuilder};
+use super::ecommerce_products::{
+    EcommerceProduct, EcommerceProductParam, EcommerceProductsBuilder,
+};
+use super::ecommerce_promo_rules::{
+    EcommercePromoRule, EcommercePromoRuleParam, EcommercePromoRulesBuilder,
+};
+use super::empty::EmptyType;
+use super::link::LinkType;
+use super::timestamp::Timestamp;
+use crate::api::MailchimpApi;
+use crate::internal::api::path_segment;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, SimpleFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Address of a store, customer or order
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceAddress {
+    /// The name associated with an order’s address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The mailing address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    /// An additional field for the mailing address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    /// The city.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// The state name or normalized province.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub province: Option<String>,
+    /// The two-letter code for the province or state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub province_code: Option<String>,
+    /// The postal or zip code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    /// The country.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// The two-letter code for the country.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    /// The longitude of the location.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    /// The latitude of the location.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    /// The phone number for the order’s address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// The company associated with an order’s address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+}
+
+///
+/// E-commerce Store
+///
+/// Connect your E-commerce Store to Mailchimp to take advantage of powerful
+/// reporting and personalization features and to learn more about your
+/// customers.
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceStore {
+    /// The unique identifier for the store.
+    #[serde(default)]
+    pub id: String,
+    /// The unique identifier for the list that’s associated with the store.
+    #[serde(default)]
+    pub list_id: String,
+    /// The name of the store.
+    #[serde(default)]
+    pub name: String,
+    /// The e-commerce platform of the store.
+    #[serde(default)]
+    pub platform: String,
+    /// The store domain.
+    #[serde(default)]
+    pub domain: String,
+    /// Whether to disable automations because the store is currently syncing.
+    #[serde(default)]
+    pub is_syncing: bool,
+    /// The email address for the store.
+    #[serde(default)]
+    pub email_address: String,
+    /// The three-letter ISO 4217 code for the currency that the store accepts.
+    #[serde(default)]
+    pub currency_code: String,
+    /// The currency format for the store. For example: $, £, etc.
+    #[serde(default)]
+    pub money_format: String,
+    /// The primary locale for the store. For example: en, de, etc.
+    #[serde(default)]
+    pub primary_locale: String,
+    /// The timezone for the store.
+    #[serde(default)]
+    pub timezone: String,
+    /// The store phone number.
+    #[serde(default)]
+    pub phone: String,
+    /// The store address.
+    #[serde(default)]
+    pub address: EcommerceAddress,
+    /// The status of the list connected to the store, namely if it’s deleted or disabled.
+    #[serde(default)]
+    pub list_is_active: bool,
+    /// The date and time the store was created in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at: Timestamp,
+    /// The date and time the store was last updated in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub updated_at: Timestamp,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+}
+
+///
+/// Create/Update E-commerce Store
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EcommerceStoreParam {
+    /// The unique identifier for the store. Required to create a store.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The unique identifier for the list associated with the store. Required
+    /// to create a store, it cannot be updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_id: Option<String>,
+    /// The name of the store.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The e-commerce platform of the store.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    /// The store domain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// Whether to disable automations because the store is currently syncing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_syncing: Option<bool>,
+    /// The email address for the store.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    /// The three-letter ISO 4217 code for the currency that the store accepts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+    /// The currency format for the store. For example: $, £, etc.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub money_format: Option<String>,
+    /// The primary locale for the store. For example: en, de, etc.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_locale: Option<String>,
+    /// The timezone for the store.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// The store phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// The store address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<EcommerceAddress>,
+}
+
+///
+/// Response for endpoint GET /ecommerce/stores
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEcommerceStores {
+    /// An array of objects representing the stores.
+    #[serde(default)]
+    pub stores: Vec<EcommerceStore>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EcommerceStore> for CollectionEcommerceStores {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EcommerceStore> {
+        self.stores.clone()
+    }
+}
+
+///
+/// EcommerceStoresBuilder
+///
+#[derive(Debug)]
+pub struct EcommerceStoresBuilder {}
+
+impl BuildIter for EcommerceStoresBuilder {
+    type Item = EcommerceStore;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionEcommerceStores;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+impl EcommerceStore {
+    ///
+    /// Update a store
+    ///
+    pub fn update(&self, param: EcommerceStoreParam) -> MailchimpResult<EcommerceStore> {
+        // PATCH /ecommerce/stores/{store_id}
+        let mut store = self
+            ._api
+            .patch::<EcommerceStore, EcommerceStoreParam>(&self.get_base_endpoint(), param)?;
+        store.set_api(self._api.clone());
+        Ok(store)
+    }
+
+    ///
+    /// Delete a store. Deleting a store will also delete any associated
+    /// subresources, including Customers, Orders, Products, and Carts.
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /ecommerce/stores/{store_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    // ============== Customers ==============
+
+    ///
+    /// Get information about a store’s customers
+    ///
+    pub fn get_customers(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<EcommerceCustomersBuilder> {
+        // GET /ecommerce/stores/{store_id}/customers
+        let endpoint = self.get_base_endpoint() + "/customers";
+        MalchimpIter::from_endpoint(
+            EcommerceCustomersBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific customer
+    ///
+    pub fn get_customer(&self, customer_id: &str) -> MailchimpResult<EcommerceCustomer> {
+        // GET /ecommerce/stores/{store_id}/customers/{customer_id}
+        let endpoint = self.get_base_endpoint() + "/customers";
+        let mut data = self._api.get::<EcommerceCustomer>(
+            &format!("{}/{}", endpoint, path_segment(customer_id)),
+            HashMap::new(),
+        )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add a new customer to a store
+    ///
+    pub fn create_customer(
+        &self,
+        param: EcommerceCustomerParam,
+    ) -> MailchimpResult<EcommerceCustomer> {
+        // POST /ecommerce/stores/{store_id}/customers
+        let endpoint = self.get_base_endpoint() + "/customers";
+        let mut data = self
+            ._api
+            .post::<EcommerceCustomer, EcommerceCustomerParam>(&endpoint, param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add or update a customer
+    ///
+    pub fn upsert_customer(
+        &self,
+        customer_id: &str,
+        param: EcommerceCustomerParam,
+    ) -> MailchimpResult<EcommerceCustomer> {
+        // PUT /ecommerce/stores/{store_id}/customers/{customer_id}
+        let endpoint = self.get_base_endpoint() + "/customers";
+        let mut data = self._api.put::<EcommerceCustomer, EcommerceCustomerParam>(
+            &format!("{}/{}", endpoint, path_segment(customer_id)),
+            param,
+        )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    // ============== Products ==============
+
+    ///
+    /// Get information about a store’s products
+    ///
+    pub fn get_products(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<EcommerceProductsBuilder> {
+        // GET /ecommerce/stores/{store_id}/products
+        let endpoint = self.get_base_endpoint() + "/products";
+        MalchimpIter::from_endpoint(
+            EcommerceProductsBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific product
+    ///
+    pub fn get_product(&self, product_id: &str) -> MailchimpResult<EcommerceProduct> {
+        // GET /ecommerce/stores/{store_id}/products/{product_id}
+        let endpoint = self.get_base_endpoint() + "/products";
+        let mut data = self._api.get::<EcommerceProduct>(
+            &format!("{}/{}", endpoint, path_segment(product_id)),
+            HashMap::new(),
+        )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add a new product to a store
+    ///
+    pub fn create_product(
+        &self,
+        param: EcommerceProductParam,
+    ) -> MailchimpResult<EcommerceProduct> {
+        // POST /ecommerce/stores/{store_id}/products
+        let endpoint = self.get_base_endpoint() + "/products";
+        let mut data = self
+            ._api
+            .post::<EcommerceProduct, EcommerceProductParam>(&endpoint, param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    // ============== Orders ==============
+
+    ///
+    /// Get information about a store’s orders
+    ///
+    pub fn get_orders(&self, filter: Option<SimpleFilter>) -> MalchimpIter<EcommerceOrdersBuilder> {
+        // GET /ecommerce/stores/{store_id}/orders
+        let endpoint = self.get_base_endpoint() + "/orders";
+        MalchimpIter::from_endpoint(
+            EcommerceOrdersBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific order
+    ///
+    pub fn get_order(&self, order_id: &str) -> MailchimpResult<EcommerceOrder> {
+        // GET /ecommerce/stores/{store_id}/orders/{order_id}
+        let endpoint = self.get_base_endpoint() + "/orders";
+        let mut data = self._api.get::<EcommerceOrder>(
+            &format!("{}/{}", endpoint, path_segment(order_id)),
+            HashMap::new(),
+        )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add a new order to a store
+    ///
+    pub fn create_order(&self, param: EcommerceOrderParam) -> MailchimpResult<EcommerceOrder> {
+        // POST /ecommerce/stores/{store_id}/orders
+        let endpoint = self.get_base_endpoint() + "/orders";
+        let mut data = self
+            ._api
+            .post::<EcommerceOrder, EcommerceOrderParam>(&endpoint, param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    // ============== Carts ==============
+
+    ///
+    /// Get information about a store’s carts
+    ///
+    pub fn get_carts(&self, filter: Option<SimpleFilter>) -> MalchimpIter<EcommerceCartsBuilder> {
+        // GET /ecommerce/stores/{store_id}/carts
+        let endpoint = self.get_base_endpoint() + "/carts";
+        MalchimpIter::from_endpoint(
+            EcommerceCartsBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific cart
+    ///
+    pub fn get_cart(&self, cart_id: &str) -> MailchimpResult<EcommerceCart> {
+        // GET /ecommerce/stores/{store_id}/carts/{cart_id}
+        let endpoint = self.get_base_endpoint() + "/carts";
+        let mut data = self._api.get::<EcommerceCart>(
+            &format!("{}/{}", endpoint, path_segment(cart_id)),
+            HashMap::new(),
+        )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add a new cart to a store
+    ///
+    pub fn create_cart(&self, param: EcommerceCartParam) -> MailchimpResult<EcommerceCart> {
+        // POST /ecommerce/stores/{store_id}/carts
+        let endpoint = self.get_base_endpoint() + "/carts";
+        let mut data = self
+            ._api
+            .post::<EcommerceCart, EcommerceCartParam>(&endpoint, param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    // ============== Promo Rules ==============
+
+    ///
+    /// Get information about a store’s promo rules
+    ///
+    pub fn get_promo_rules(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<EcommercePromoRulesBuilder> {
+        // GET /ecommerce/stores/{store_id}/promo-rules
+        let endpoint = self.get_base_endpoint() + "/promo-rules";
+        MalchimpIter::from_endpoint(
+            EcommercePromoRulesBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific promo rule
+    ///
+    pub fn get_promo_rule(&self, promo_rule_id: &str) -> MailchimpResult<EcommercePromoRule> {
+        // GET /ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}
+        let endpoint = self.get_base_endpoint() + "/promo-rules";
+        let mut data = self._api.get::<EcommercePromoRule>(
+            &format!("{}/{}", endpoint, path_segment(promo_rule_id)),
+            HashMap::new(),
+        )?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Add a new promo rule to a store
+    ///
+    pub fn create_promo_rule(
+        &self,
+        param: EcommercePromoRuleParam,
+    ) -> MailchimpResult<EcommercePromoRule> {
+        // POST /ecommerce/stores/{store_id}/promo-rules
+        let endpoint = self.get_base_endpoint() + "/promo-rules";
+        let mut data = self
+            ._api
+            .post::<EcommercePromoRule, EcommercePromoRuleParam>(&endpoint, param)?;
+        data.set_api(self._api.clone());
+        data.set_endpoint(&endpoint);
+        Ok(data)
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("ecommerce/stores/{}", path_segment(&self.id))
+    }
+}
@@ -0,0 +1,234 @@
+//! Implement the Enumerated Values used by the Model Types
+//!
+//! Every enum keeps an `Unknown` variant with the raw value, so a value added
+//! by Mailchimp after this release is still deserialized.
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+///
+/// Declara un enum que se serializa como el string usado por la API
+///
+macro_rules! api_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$vmeta:meta])* $variant:ident => $value:expr,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$vmeta])* $variant,)+
+            /// A value not known by this version of the library
+            Unknown(String),
+        }
+
+        impl $name {
+            ///
+            /// Value used by the Mailchimp API
+            ///
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)+
+                    $name::Unknown(v) => v,
+                }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name::Unknown(String::new())
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(v: &str) -> Self {
+                match v {
+                    $($value => $name::$variant,)+
+                    _ => $name::Unknown(v.to_string()),
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let v = String::deserialize(deserializer)?;
+                Ok($name::from(v.as_str()))
+            }
+        }
+    };
+}
+
+api_enum! {
+    ///
+    /// Subscriber’s status in a list
+    ///
+    MemberStatus {
+        /// subscribed
+        Subscribed => "subscribed",
+        /// unsubscribed
+        Unsubscribed => "unsubscribed",
+        /// cleaned
+        Cleaned => "cleaned",
+        /// pending
+        Pending => "pending",
+        /// transactional
+        Transactional => "transactional",
+        /// archived
+        Archived => "archived",
+    }
+}
+
+api_enum! {
+    ///
+    /// Type of email a member asked to get
+    ///
+    EmailType {
+        /// html
+        Html => "html",
+        /// text
+        Text => "text",
+    }
+}
+
+api_enum! {
+    ///
+    /// Current status of a campaign
+    ///
+    CampaignStatus {
+        /// save
+        Save => "save",
+        /// paused
+        Paused => "paused",
+        /// schedule
+        Schedule => "schedule",
+        /// sending
+        Sending => "sending",
+        /// sent
+        Sent => "sent",
+        /// canceled
+        Canceled => "canceled",
+        /// canceling
+        Canceling => "canceling",
+        /// archived
+        Archived => "archived",
+    }
+}
+
+api_enum! {
+    ///
+    /// Type of a campaign
+    ///
+    CampaignKind {
+        /// regular
+        Regular => "regular",
+        /// plaintext
+        Plaintext => "plaintext",
+        /// absplit
+        Absplit => "absplit",
+        /// rss
+        Rss => "rss",
+        /// variate
+        Variate => "variate",
+    }
+}
+
+api_enum! {
+    ///
+    /// How the content of a campaign is put together
+    ///
+    CampaignContentKind {
+        /// template
+        Template => "template",
+        /// drag_and_drop
+        DragAndDrop => "drag_and_drop",
+        /// html
+        Html => "html",
+        /// url
+        Url => "url",
+        /// multichannel
+        Multichannel => "multichannel",
+    }
+}
+
+api_enum! {
+    ///
+    /// Current state of a campaign delivery
+    ///
+    DeliveryStatus {
+        /// delivering
+        Delivering => "delivering",
+        /// delivered
+        Delivered => "delivered",
+        /// canceling
+        Canceling => "canceling",
+        /// canceled
+        Canceled => "canceled",
+    }
+}
+
+api_enum! {
+    ///
+    /// Current status of an Automation
+    ///
+    AutomationStatus {
+        /// save
+        Save => "save",
+        /// paused
+        Paused => "paused",
+        /// sending
+        Sending => "sending",
+    }
+}
+
+api_enum! {
+    ///
+    /// Type of discount of a promo rule
+    ///
+    EcommercePromoRuleType {
+        /// The amount is a fixed value in the currency of the store.
+        Fixed => "fixed",
+        /// The amount is a percentage between 0.0 and 1.0.
+        Percentage => "percentage",
+    }
+}
+
+api_enum! {
+    ///
+    /// Target of the discount of a promo rule
+    ///
+    EcommercePromoRuleTarget {
+        /// The discount is applied to each item of the order.
+        PerItem => "per_item",
+        /// The discount is applied to the total of the order.
+        Total => "total",
+        /// The discount is applied to the shipping of the order.
+        Shipping => "shipping",
+    }
+}
+
+api_enum! {
+    ///
+    /// Order direction for sorted results
+    ///
+    SortDir {
+        /// ASC
+        Asc => "ASC",
+        /// DESC
+        Desc => "DESC",
+    }
+}
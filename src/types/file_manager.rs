@@ -0,0 +1,300 @@
+//! Implement File Manager Model Types
+
+use super::empty::EmptyType;
+use super::link::LinkType;
+use super::timestamp::Timestamp;
+use crate::api::MailchimpApi;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// File or image of the File Manager
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FileManagerFileType {
+    /// The unique id of the file.
+    #[serde(default)]
+    pub id: u64,
+    /// The id of the folder, 0 if the file is not in a folder.
+    #[serde(default)]
+    pub folder_id: u64,
+    /// The type of file in the File Manager.
+    /// Possible Values: image file
+    #[serde(default, rename = "type")]
+    pub file_type: String,
+    /// The name of the file.
+    #[serde(default)]
+    pub name: String,
+    /// The url of the full-size file.
+    #[serde(default)]
+    pub full_size_url: String,
+    /// The url of the thumbnail preview.
+    #[serde(default)]
+    pub thumbnail_url: String,
+    /// The size of the file in bytes.
+    #[serde(default)]
+    pub size: u64,
+    /// The date and time a file was added to the File Manager in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at: Timestamp,
+    /// The username of the profile that uploaded the file.
+    #[serde(default)]
+    pub created_by: String,
+    /// The width of the image.
+    #[serde(default)]
+    pub width: u64,
+    /// The height of an image.
+    #[serde(default)]
+    pub height: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+}
+
+///
+/// Upload/Update File
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FileManagerFileParam {
+    /// The id of the folder.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<u64>,
+    /// The name of the file. Required to upload a file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The base64-encoded contents of the file. Required to upload a file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_data: Option<String>,
+}
+
+///
+/// Response for endpoint GET /file-manager/files
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionFileManagerFiles {
+    /// A list of files and images in an account.
+    #[serde(default)]
+    pub files: Vec<FileManagerFileType>,
+    /// The total size of all File Manager files in bytes.
+    #[serde(default)]
+    pub total_file_size: u64,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<FileManagerFileType> for CollectionFileManagerFiles {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<FileManagerFileType> {
+        self.files.clone()
+    }
+}
+
+impl FileManagerFileType {
+    ///
+    /// Update a file in the File Manager
+    ///
+    pub fn update(&self, param: FileManagerFileParam) -> MailchimpResult<FileManagerFileType> {
+        // PATCH /file-manager/files/{file_id}
+        let mut data = self
+            ._api
+            .patch::<FileManagerFileType, FileManagerFileParam>(&self.get_base_endpoint(), param)?;
+        data.set_api(self._api.clone());
+        Ok(data)
+    }
+
+    ///
+    /// Rename the file
+    ///
+    pub fn rename(&self, name: &str) -> MailchimpResult<FileManagerFileType> {
+        self.update(FileManagerFileParam {
+            name: Some(name.to_string()),
+            ..FileManagerFileParam::default()
+        })
+    }
+
+    ///
+    /// Move the file into a folder, use 0 to remove the file from its folder
+    ///
+    pub fn move_to_folder(&self, folder_id: u64) -> MailchimpResult<FileManagerFileType> {
+        self.update(FileManagerFileParam {
+            folder_id: Some(folder_id),
+            ..FileManagerFileParam::default()
+        })
+    }
+
+    ///
+    /// Remove a specific file from the File Manager
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /file-manager/files/{file_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("file-manager/files/{}", self.id)
+    }
+}
+
+///
+/// Folder of the File Manager
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FileManagerFolderType {
+    /// The unique id for the folder.
+    #[serde(default)]
+    pub id: u64,
+    /// The name of the folder.
+    #[serde(default)]
+    pub name: String,
+    /// The number of files in the folder.
+    #[serde(default)]
+    pub file_count: u64,
+    /// The date and time a file was added to the File Manager in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at: Timestamp,
+    /// The username of the profile that created the folder.
+    #[serde(default)]
+    pub created_by: String,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+}
+
+///
+/// Create/Update File Manager Folder
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FileManagerFolderParam {
+    /// The name of the folder.
+    #[serde(default)]
+    pub name: String,
+}
+
+///
+/// Response for endpoint GET /file-manager/folders
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionFileManagerFolders {
+    /// A list of all folders in the File Manager.
+    #[serde(default)]
+    pub folders: Vec<FileManagerFolderType>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<FileManagerFolderType> for CollectionFileManagerFolders {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<FileManagerFolderType> {
+        self.folders.clone()
+    }
+}
+
+///
+/// FileManagerFoldersBuilder
+///
+#[derive(Debug)]
+pub struct FileManagerFoldersBuilder {}
+
+impl BuildIter for FileManagerFoldersBuilder {
+    type Item = FileManagerFolderType;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionFileManagerFolders;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+impl FileManagerFolderType {
+    ///
+    /// Update a specific File Manager folder
+    ///
+    pub fn update(&self, param: FileManagerFolderParam) -> MailchimpResult<FileManagerFolderType> {
+        // PATCH /file-manager/folders/{folder_id}
+        let mut data = self
+            ._api
+            .patch::<FileManagerFolderType, FileManagerFolderParam>(
+                &self.get_base_endpoint(),
+                param,
+            )?;
+        data.set_api(self._api.clone());
+        Ok(data)
+    }
+
+    ///
+    /// Delete a specific folder in the File Manager
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /file-manager/folders/{folder_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("file-manager/folders/{}", self.id)
+    }
+}
@@ -1,7 +1,8 @@
 use super::empty::EmptyType;
 use super::link::LinkType;
+use super::timestamp::Timestamp;
 use crate::api::MailchimpApi;
-use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::error_type::MailchimpError;
 use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
 use serde::{Deserialize, Serialize};
 
@@ -11,7 +12,7 @@ use serde::{Deserialize, Serialize};
 /// Manage your Landing Pages, including publishing and unpublishing.
 ///
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Manage your Landing Pages, including publishing and unpublishing.
@@ -46,24 +47,24 @@ pub struct LandingPage {
     #[serde(default)]
     pub url: String,
     /// The time this landing page was created.
-    #[serde(default)]
-    pub created_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at: Timestamp,
     /// The time this landing page was published.
-    #[serde(default)]
-    pub published_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub published_at: Timestamp,
     /// The time this landing page was unpublished.
-    #[serde(default)]
-    pub unpublished_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub unpublished_at: Timestamp,
     /// The time this landing page was updated at.
-    #[serde(default)]
-    pub updated_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub updated_at: Timestamp,
     /// A list of link types and descriptions for the API schema documents.
     #[serde(default)]
     pub _links: Vec<LinkType>,
 
     /// Mailchimp API
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
 
     /// Endpoint
     #[serde(skip)]
@@ -124,7 +125,7 @@ impl BuildIter for LandingPageBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         // in_data.set_endpoint(&self.endpoint);
@@ -146,7 +147,7 @@ impl LandingPage {
     ///
     /// Delete a specific webhook in a list.
     ///
-    pub fn delete(&self) -> Option<MailchimpErrorType> {
+    pub fn delete(&self) -> Option<MailchimpError> {
         // DELETE /landing-pages/{page_id}
         let endpoint = self.get_base_endpoint();
         match self._api.delete::<EmptyType>(&endpoint, HashMap::new()) {
@@ -158,7 +159,7 @@ impl LandingPage {
     ///
     /// Set API
     ///
-    pub fn set_api(&mut self, api: Rc<MailchimpApi>) {
+    pub fn set_api(&mut self, api: Arc<MailchimpApi>) {
         self._api = api;
     }
     ///
@@ -1,45 +1,40 @@
 use super::contact::ContactType;
 use super::empty::EmptyType;
+use super::enums::SortDir;
 use super::link::LinkType;
 
-use super::list_abuse_report::{
-    CollectionListAbuseReport, ListAbuseReportBuilder, ListAbuseReportType,
-};
-use super::list_activity::{CollectionListActivity, ListActivityBuilder};
+use super::list_abuse_report::{ListAbuseReportBuilder, ListAbuseReportType};
+use super::list_activity::ListActivityBuilder;
 use super::list_batch_members::{ListBatchParam, ListBatchResponse};
-use super::list_clients::{CollectionListClients, ListClientsBuilder};
+use super::list_clients::ListClientsBuilder;
 use super::list_growth_history::{
-    CollectionListGrowthHistory, ListGrowthHistoryBuilder, ListGrowthHistoryFilter,
-    ListGrowthHistoryType,
+    ListGrowthHistoryBuilder, ListGrowthHistoryFilter, ListGrowthHistoryType,
 };
 use super::list_interest_categories::{
-    CollectionListInterestCategories, InterestCategoryParam, ListInterestCategory,
-    ListInterestCategoryBuilder, ListInterestCategoryFilter,
+    InterestCategoryParam, ListInterestCategory, ListInterestCategoryBuilder,
+    ListInterestCategoryFilter,
 };
-use super::list_locations::{CollectionListLocations, ListLocationsBuilder};
+use super::list_locations::ListLocationsBuilder;
+use super::list_member_tags::{ListMemberTagParam, ListMemberTagType};
 use super::list_members::{
-    CollectionListMembers, ListMember, ListMemberParams, ListMembersBuilder, ListMembersFilter,
+    subscriber_hash, ListMember, ListMemberParams, ListMembersBuilder, ListMembersFilter,
 };
 use super::list_merge_fields::{
     CollectionListMergeField, ListMergeField, ListMergeFieldBuilder, ListMergeFieldFilter,
     ListMergeFieldParam,
 };
-use super::list_segments::{
-    CollectionListSegment, ListSegment, ListSegmentBuilder, ListSegmentFilter,
-};
-use super::list_signup_forms::{CollectionListSignupForm, ListSignupForm, ListSignupFormBuilder};
-use super::list_webhooks::{
-    CollectionListWebhooks, ListWebhooks, ListWebhooksBuilder, ListWebhooksParam,
-};
+use super::list_segments::{ListSegment, ListSegmentBuilder, ListSegmentFilter};
+use super::list_signup_forms::{ListSignupForm, ListSignupFormBuilder};
+use super::list_webhooks::{ListWebhooks, ListWebhooksBuilder, ListWebhooksParam};
+use super::timestamp::MailchimpDateTime;
 use crate::api::{MailchimpApi, MailchimpApiUpdate};
-use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::error_type::MailchimpError;
 use crate::internal::request::MailchimpResult;
 use crate::iter::MailchimpCollection;
 use crate::iter::{MalchimpIter, ResourceFilter, SimpleFilter};
-use log::error;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Campaign Defaults Type
@@ -99,8 +94,12 @@ pub struct StatisticsType {
     pub campaign_count: Option<u64>,
     /// The date and time the last campaign was sent to this list in ISO 8601 format.
     ///  This is updated when a campaign is sent to 10 or more recipients.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub campaign_last_sent: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub campaign_last_sent: Option<MailchimpDateTime>,
     /// The number of merge vars for this list (not EMAIL, which is required).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub merge_field_count: Option<u64>,
@@ -125,11 +124,19 @@ pub struct StatisticsType {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub click_rate: Option<f32>,
     /// The date and time of the last time someone subscribed to this list in ISO 8601 format.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub last_sub_date: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_sub_date: Option<MailchimpDateTime>,
     /// The date and time of the last time someone unsubscribed from this list in ISO 8601 format.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub last_unsub_date: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_unsub_date: Option<MailchimpDateTime>,
 }
 
 impl Default for StatisticsType {
@@ -192,8 +199,12 @@ pub struct ListType {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub notify_on_unsubscribe: Option<String>,
     /// The date and time that this list was created in ISO 8601 format.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub date_created: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date_created: Option<MailchimpDateTime>,
     /// An auto-generated activity score for the list (0-5).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub list_rating: Option<u64>,
@@ -236,7 +247,7 @@ pub struct ListType {
     pub _links: Option<Vec<LinkType>>,
 
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
 }
 
 ///
@@ -246,7 +257,7 @@ impl MailchimpApiUpdate for ListType {
     /**
      * Update API
      */
-    fn set_api(&mut self, n_api: Rc<MailchimpApi>) {
+    fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
         self._api = n_api
     }
 }
@@ -328,32 +339,12 @@ impl ListType {
             filter_params.exclude_fields = Some(ex);
         }
 
-        match self
-            ._api
-            .get::<CollectionListActivity>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListActivityBuilder {},
-                data: collection.activity,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: endpoint.clone(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Activities: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListActivityBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            ListActivityBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -371,36 +362,14 @@ impl ListType {
             filter_params = f;
         }
 
-        match self
-            ._api
-            .get::<CollectionListMergeField>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListMergeFieldBuilder {
-                    endpoint: endpoint.clone(),
-                },
-                data: collection.merge_fields,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
+        MalchimpIter::from_endpoint(
+            ListMergeFieldBuilder {
                 endpoint: endpoint.clone(),
             },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Activities: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListMergeFieldBuilder {
-                        endpoint: endpoint.clone(),
-                    },
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -462,36 +431,14 @@ impl ListType {
             filter_params = f;
         }
 
-        match self
-            ._api
-            .get::<CollectionListWebhooks>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListWebhooksBuilder {
-                    endpoint: endpoint.clone(),
-                },
-                data: collection.webhooks,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
+        MalchimpIter::from_endpoint(
+            ListWebhooksBuilder {
                 endpoint: endpoint.clone(),
             },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Activities: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListWebhooksBuilder {
-                        endpoint: endpoint.clone(),
-                    },
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -560,32 +507,12 @@ impl ListType {
             filter_params.exclude_fields = Some(ex);
         }
 
-        match self
-            ._api
-            .get::<CollectionListClients>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListClientsBuilder {},
-                data: collection.clients,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: endpoint.clone(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Clients: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListClientsBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            ListClientsBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -615,32 +542,12 @@ impl ListType {
             filter_params.exclude_fields = Some(ex);
         }
 
-        match self
-            ._api
-            .get::<CollectionListLocations>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListLocationsBuilder {},
-                data: collection.locations,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: endpoint.clone(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Locations: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListLocationsBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            ListLocationsBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -676,32 +583,12 @@ impl ListType {
             filter_params.offset = Some(ofs);
         }
 
-        match self
-            ._api
-            .get::<CollectionListAbuseReport>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListAbuseReportBuilder {},
-                data: collection.abuse_reports,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: endpoint.clone(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Abuse Reports: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListAbuseReportBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            ListAbuseReportBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -736,7 +623,7 @@ impl ListType {
         &self,
         fields: Option<String>,
         exclude_fields: Option<String>,
-        sort_dir: Option<String>,
+        sort_dir: Option<SortDir>,
     ) -> MalchimpIter<ListGrowthHistoryBuilder> {
         // GET /lists/{list_id}/growth-history
         let endpoint = self.get_base_endpoint() + "/growth-history";
@@ -753,32 +640,12 @@ impl ListType {
             filter_params.sort_dir = Some(ofs);
         }
 
-        match self
-            ._api
-            .get::<CollectionListGrowthHistory>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListGrowthHistoryBuilder {},
-                data: collection.history,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: endpoint.clone(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Grow History: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListGrowthHistoryBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            ListGrowthHistoryBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -817,36 +684,14 @@ impl ListType {
             ListMembersFilter::default()
         };
 
-        match self
-            ._api
-            .get::<CollectionListMembers>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListMembersBuilder {
-                    endpoint: endpoint.clone(),
-                },
-                data: collection.members,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
+        MalchimpIter::from_endpoint(
+            ListMembersBuilder {
                 endpoint: endpoint.clone(),
             },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get List Members: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListMembersBuilder {
-                        endpoint: endpoint.clone(),
-                    },
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -897,6 +742,145 @@ impl ListType {
             .put::<ListMember, ListMemberParams>(&endpoint, param)
     }
 
+    ///
+    /// Check the merge fields of a member against the merge fields of the
+    /// list, without sending the member.
+    ///
+    /// Arguments:
+    ///     param: Member fields to check
+    ///
+    pub fn validate_member(&self, param: &ListMemberParams) -> MailchimpResult<()> {
+        // GET /lists/{list_id}/merge-fields
+        let endpoint = self.get_base_endpoint() + "/merge-fields";
+        let filter = ListMergeFieldFilter {
+            count: Some(1000),
+            ..ListMergeFieldFilter::default()
+        };
+        let collection = self
+            ._api
+            .get::<CollectionListMergeField>(&endpoint, filter.build_payload())?;
+        param.validate_merge_fields(&collection.merge_fields)
+    }
+
+    ///
+    /// Add a new member to the list after checking the merge fields against
+    /// the merge fields of the list. Nothing is sent if a value is not valid.
+    ///
+    /// Arguments:
+    ///     param: New member fields
+    ///
+    pub fn add_new_member_checked(&self, param: ListMemberParams) -> MailchimpResult<ListMember> {
+        self.validate_member(&param)?;
+        self.add_new_member(param)
+    }
+
+    ///
+    /// Get information about a specific list member by the email address.
+    ///
+    /// Arguments:
+    ///     email: Email address of the member, it is normalised and hashed
+    ///
+    pub fn get_member_by_email(&self, email: &str) -> MailchimpResult<ListMember> {
+        // GET /lists/{list_id}/members/{subscriber_hash}
+        let endpoint = self.get_base_endpoint() + "/members";
+        let mut member = self.get_member_info(&subscriber_hash(email))?;
+        member.set_api(self._api.clone());
+        member.set_endpoint(&endpoint);
+        Ok(member)
+    }
+
+    ///
+    /// Add or update a list member by the email address. The email address
+    /// of the param is set to `email` if it is missing or blank.
+    ///
+    /// Arguments:
+    ///     email: Email address of the member, it is normalised and hashed
+    ///     param: Member fields to update or create
+    ///
+    pub fn upsert_member_by_email(
+        &self,
+        email: &str,
+        param: ListMemberParams,
+    ) -> MailchimpResult<ListMember> {
+        // PUT /lists/{list_id}/members/{subscriber_hash}
+        let mut param = param;
+        let missing = match &param.email_address {
+            Some(v) => v.trim().is_empty(),
+            None => true,
+        };
+        if missing {
+            param.email_address = Some(email.trim().to_string());
+        }
+        let endpoint = self.get_base_endpoint() + "/members";
+        let mut member = self.add_update_member(&subscriber_hash(email), param)?;
+        member.set_api(self._api.clone());
+        member.set_endpoint(&endpoint);
+        Ok(member)
+    }
+
+    ///
+    /// Archive a list member by the email address.
+    ///
+    /// Arguments:
+    ///     email: Email address of the member, it is normalised and hashed
+    ///
+    pub fn archive_member_by_email(&self, email: &str) -> Option<MailchimpError> {
+        // DELETE /lists/{list_id}/members/{subscriber_hash}
+        let endpoint = self.get_member_endpoint(email);
+        self._api
+            .delete::<EmptyType>(&endpoint, HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Delete all personally identifiable information related to a list
+    /// member, and remove them from a list. This will make it impossible
+    /// to re-import the list member.
+    ///
+    /// Arguments:
+    ///     email: Email address of the member, it is normalised and hashed
+    ///
+    pub fn delete_member_by_email(&self, email: &str) -> Option<MailchimpError> {
+        // POST /lists/{list_id}/members/{subscriber_hash}/actions/delete-permanent
+        let endpoint = self.get_member_endpoint(email) + "/actions/delete-permanent";
+        self._api
+            .post::<EmptyType, HashMap<String, String>>(&endpoint, HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Add or remove tags from a list member by the email address. If a tag
+    /// that does not exist is passed in and set as ‘active’, a new tag will
+    /// be created.
+    ///
+    /// Arguments:
+    ///     email: Email address of the member, it is normalised and hashed
+    ///     tags: Tags to add (‘active’) or remove (‘inactive’)
+    ///
+    pub fn tag_member_by_email(
+        &self,
+        email: &str,
+        tags: Vec<ListMemberTagType>,
+    ) -> Option<MailchimpError> {
+        // POST /lists/{list_id}/members/{subscriber_hash}/tags
+        let endpoint = self.get_member_endpoint(email) + "/tags";
+        let param = ListMemberTagParam { tags };
+        self._api
+            .post::<EmptyType, ListMemberTagParam>(&endpoint, param)
+            .err()
+    }
+
+    ///
+    /// Endpoint del miembro de la lista con el email pasado por parámetro
+    ///
+    fn get_member_endpoint(&self, email: &str) -> String {
+        format!(
+            "{}/members/{}",
+            self.get_base_endpoint(),
+            subscriber_hash(email)
+        )
+    }
+
     ///
     /// Get information about a list’s interest categories.
     ///
@@ -917,36 +901,14 @@ impl ListType {
             ListInterestCategoryFilter::default()
         };
 
-        match self
-            ._api
-            .get::<CollectionListInterestCategories>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListInterestCategoryBuilder {
-                    endpoint: endpoint.clone(),
-                },
-                data: collection.categories,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
+        MalchimpIter::from_endpoint(
+            ListInterestCategoryBuilder {
                 endpoint: endpoint.clone(),
             },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get List Members: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListInterestCategoryBuilder {
-                        endpoint: endpoint.clone(),
-                    },
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -1019,32 +981,12 @@ impl ListType {
             SimpleFilter::default()
         };
 
-        match self
-            ._api
-            .get::<CollectionListSignupForm>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListSignupFormBuilder {},
-                data: collection.signup_forms,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: endpoint.clone(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get List Members: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListSignupFormBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            ListSignupFormBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -1064,36 +1006,14 @@ impl ListType {
             ListSegmentFilter::default()
         };
 
-        match self
-            ._api
-            .get::<CollectionListSegment>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListSegmentBuilder {
-                    endpoint: endpoint.clone(),
-                },
-                data: collection.segments,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
+        MalchimpIter::from_endpoint(
+            ListSegmentBuilder {
                 endpoint: endpoint.clone(),
             },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get List Segments: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListSegmentBuilder {
-                        endpoint: endpoint.clone(),
-                    },
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -1124,7 +1044,7 @@ impl ListType {
     /// complaints, and bounces. You’ll also lose subscribers’ email addresses,
     /// unless you exported and backed up your list.
     ///
-    pub fn delete(&self) -> Option<MailchimpErrorType> {
+    pub fn delete(&self) -> Option<MailchimpError> {
         // DELETE /lists/{list_id}
         let endpoint = self.get_base_endpoint();
         match self
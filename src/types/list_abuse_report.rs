@@ -1,9 +1,11 @@
 use super::link::LinkType;
+use super::list_merge_fields::MergeValue;
+use super::timestamp::Timestamp;
 use crate::api::MailchimpApi;
 use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Abuse Reports
@@ -27,13 +29,13 @@ pub struct ListAbuseReportType {
     pub email_address: String,
     /// An individual merge var and value for a member.
     #[serde(default)]
-    pub merge_fields: HashMap<String, String>,
+    pub merge_fields: HashMap<String, MergeValue>,
     /// VIP status for subscriber.
     #[serde(default)]
     pub vip: bool,
     /// Date for the abuse report
-    #[serde(default)]
-    pub date: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub date: Timestamp,
     /// A list of link types and descriptions for the API schema documents.
     #[serde(default)]
     pub _links: Vec<LinkType>,
@@ -95,7 +97,7 @@ impl BuildIter for ListAbuseReportBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
         let in_data = data.clone();
         in_data
     }
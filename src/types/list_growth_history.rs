@@ -1,9 +1,10 @@
+use super::enums::SortDir;
 use super::link::LinkType;
 use crate::api::MailchimpApi;
 use crate::iter::{BuildIter, MailchimpCollection, ResourceFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// List Growth History Type
@@ -75,7 +76,7 @@ impl Default for CollectionListGrowthHistory {
 ///
 /// List Growth History Filter
 ///
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ListGrowthHistoryFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -91,7 +92,7 @@ pub struct ListGrowthHistoryFilter {
     /// Returns files sorted by the specified field. Posible Values month
     pub sort_field: Option<String>,
     /// Determines the order direction for sorted results. Possible Values: ASC/DESC
-    pub sort_dir: Option<String>,
+    pub sort_dir: Option<SortDir>,
 }
 
 impl Default for ListGrowthHistoryFilter {
@@ -162,7 +163,7 @@ impl BuildIter for ListGrowthHistoryBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
         let in_data = data.clone();
         in_data
     }
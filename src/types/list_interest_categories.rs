@@ -1,16 +1,13 @@
 use super::empty::EmptyType;
 use super::link::LinkType;
-use super::list_interests::{
-    CollectionListInterest, InterestParam, ListInterest, ListInterestBuilder,
-};
+use super::list_interests::{InterestParam, ListInterest, ListInterestBuilder};
 use crate::api::MailchimpApi;
-use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::error_type::MailchimpError;
 use crate::internal::request::MailchimpResult;
 use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, ResourceFilter, SimpleFilter};
-use log::error;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Interest Categories
@@ -45,7 +42,7 @@ pub struct ListInterestCategory {
 
     /// Mailchimp API
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
     /// Edpoint
     #[serde(default)]
     _endpoint: String,
@@ -92,7 +89,7 @@ impl ListInterestCategory {
     ///
     /// Update the api instance
     ///
-    pub fn set_api(&mut self, n_api: Rc<MailchimpApi>) {
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
         self._api = n_api
     }
     ///
@@ -105,7 +102,7 @@ impl ListInterestCategory {
     ///
     /// Delete a specific note for a specific list member.
     ///
-    pub fn delete(&self) -> Option<MailchimpErrorType> {
+    pub fn delete(&self) -> Option<MailchimpError> {
         // DELETE /lists/{list_id}/interest-categories/{interest_category_id}
         let endpoint = self.get_base_endpoint();
         match self._api.delete::<EmptyType>(&endpoint, HashMap::new()) {
@@ -147,36 +144,14 @@ impl ListInterestCategory {
             SimpleFilter::default()
         };
 
-        match self
-            ._api
-            .get::<CollectionListInterest>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListInterestBuilder {
-                    endpoint: endpoint.clone(),
-                },
-                data: collection.interests,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
+        MalchimpIter::from_endpoint(
+            ListInterestBuilder {
                 endpoint: endpoint.clone(),
             },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get List Members: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListInterestBuilder {
-                        endpoint: endpoint.clone(),
-                    },
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -268,7 +243,7 @@ impl Default for CollectionListInterestCategories {
 
 /// ================================= ITER =====================
 
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ListInterestCategoryFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -349,7 +324,7 @@ impl BuildIter for ListInterestCategoryBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data.set_endpoint(&self.endpoint);
@@ -1,12 +1,12 @@
 use super::empty::EmptyType;
 use super::link::LinkType;
 use crate::api::MailchimpApi;
-use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::error_type::MailchimpError;
 use crate::internal::request::MailchimpResult;
 use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Interests
@@ -41,7 +41,7 @@ pub struct ListInterest {
 
     /// Mailchimp API
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
     /// Edpoint
     #[serde(default)]
     _endpoint: String,
@@ -80,7 +80,7 @@ impl ListInterest {
     ///
     /// Update the api instance
     ///
-    pub fn set_api(&mut self, n_api: Rc<MailchimpApi>) {
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
         self._api = n_api
     }
     ///
@@ -93,7 +93,7 @@ impl ListInterest {
     ///
     /// Delete interests or group names in a specific category.
     ///
-    pub fn delete(&self) -> Option<MailchimpErrorType> {
+    pub fn delete(&self) -> Option<MailchimpError> {
         // DELETE /lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}
         let endpoint = self.get_base_endpoint();
         match self._api.delete::<EmptyType>(&endpoint, HashMap::new()) {
@@ -189,7 +189,7 @@ impl BuildIter for ListInterestBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data.set_endpoint(&self.endpoint);
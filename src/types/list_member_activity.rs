@@ -1,8 +1,9 @@
 use super::link::LinkType;
+use super::timestamp::Timestamp;
 use crate::api::MailchimpApi;
 use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
 use serde::{Deserialize, Serialize};
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// List Member Activity
@@ -13,8 +14,8 @@ pub struct ListMemberActivity {
     #[serde(default)]
     pub action: String,
     /// The date and time recorded for the action.
-    #[serde(default)]
-    pub timestamp: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub timestamp: Timestamp,
     /// For clicks, the URL the subscriber clicked on.
     #[serde(default)]
     pub url: String,
@@ -94,7 +95,7 @@ impl BuildIter for ListMemberActivityBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
         let in_data = data.clone();
         in_data
     }
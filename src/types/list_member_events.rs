@@ -0,0 +1,143 @@
+//! Implement List Member Events Model Types
+
+use super::link::LinkType;
+use super::timestamp::{MailchimpDateTime, Timestamp};
+use crate::api::MailchimpApi;
+use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Member Events
+///
+/// A custom event of a list member, e.g. a product-usage event.
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ListMemberEventType {
+    /// The name for this type of event (‘purchased’, ‘visited’, etc).
+    #[serde(default)]
+    pub name: String,
+    /// The date and time the event occurred in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub occurred_at: Timestamp,
+    /// The properties of the event.
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+}
+
+///
+/// Add an event for a list member
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ListMemberEventParam {
+    /// The name for this type of event (‘purchased’, ‘visited’, etc). Must be 2-30
+    /// characters in length.
+    #[serde(default)]
+    pub name: String,
+    /// An optional list of properties.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub properties: HashMap<String, String>,
+    /// Events created with the is_syncing value set to true will not trigger automations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_syncing: Option<bool>,
+    /// The date and time the event occurred in ISO 8601 format. If not provided
+    /// the current time is used.
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub occurred_at: Option<MailchimpDateTime>,
+}
+
+impl ListMemberEventParam {
+    ///
+    /// Event without properties that occurs now
+    ///
+    pub fn new(name: &str) -> Self {
+        ListMemberEventParam {
+            name: name.to_string(),
+            ..ListMemberEventParam::default()
+        }
+    }
+
+    ///
+    /// Add a property to the event
+    ///
+    pub fn with_property(mut self, key: &str, value: &str) -> Self {
+        self.properties.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    ///
+    /// Set the date and time the event occurred
+    ///
+    pub fn with_occurred_at(mut self, occurred_at: MailchimpDateTime) -> Self {
+        self.occurred_at = Some(occurred_at);
+        self
+    }
+
+    ///
+    /// Flag the event as synced from another system, it will not trigger automations
+    ///
+    pub fn with_is_syncing(mut self, is_syncing: bool) -> Self {
+        self.is_syncing = Some(is_syncing);
+        self
+    }
+}
+
+///
+/// Response for endpoint GET /lists/{list_id}/members/{subscriber_hash}/events
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionListMemberEvents {
+    /// An array of objects, each representing an event of the member.
+    #[serde(default)]
+    pub events: Vec<ListMemberEventType>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<ListMemberEventType> for CollectionListMemberEvents {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+
+    /// Data
+    fn get_values(&self) -> Vec<ListMemberEventType> {
+        self.events.clone()
+    }
+}
+
+///
+/// ListMemberEventBuilder
+///
+#[derive(Debug)]
+pub struct ListMemberEventBuilder {}
+
+impl BuildIter for ListMemberEventBuilder {
+    type Item = ListMemberEventType;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionListMemberEvents;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
+        data.clone()
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
@@ -1,8 +1,9 @@
 use super::link::LinkType;
+use super::timestamp::Timestamp;
 use crate::api::MailchimpApi;
 use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
 use serde::{Deserialize, Serialize};
-use std::rc::Rc;
+use std::sync::Arc;
 ///
 /// List Member Goal
 ///
@@ -15,8 +16,8 @@ pub struct ListMemberGoal {
     #[serde(default)]
     pub event: String,
     /// The date and time the user last triggered the Goal event in ISO 8601 format.
-    #[serde(default)]
-    pub last_visited_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_visited_at: Timestamp,
     /// Any extra data passed with the Goal event.
     #[serde(default)]
     pub data: String,
@@ -84,7 +85,7 @@ impl BuildIter for ListMemberGoalBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
         let in_data = data.clone();
         in_data
     }
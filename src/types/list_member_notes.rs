@@ -1,12 +1,13 @@
 use super::empty::EmptyType;
 use super::link::LinkType;
+use super::timestamp::Timestamp;
 use crate::api::MailchimpApi;
-use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::error_type::MailchimpError;
 use crate::internal::request::MailchimpResult;
 use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Member Notes
@@ -19,14 +20,14 @@ pub struct ListMemberNote {
     #[serde(default)]
     pub id: u64,
     /// The date and time the note was created in ISO 8601 format.
-    #[serde(default)]
-    pub created_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at: Timestamp,
     /// The author of the note.
     #[serde(default)]
     pub created_by: String,
     /// The date and time the note was last updated in ISO 8601 format.
-    #[serde(default)]
-    pub updated_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub updated_at: Timestamp,
     /// The content of the note.
     #[serde(default)]
     pub note: String,
@@ -42,7 +43,7 @@ pub struct ListMemberNote {
 
     /// Mailchimp API
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
     /// Edpoint
     #[serde(default)]
     _endpoint: String,
@@ -52,7 +53,7 @@ impl ListMemberNote {
     ///
     /// Update the api instance
     ///
-    pub fn set_api(&mut self, n_api: Rc<MailchimpApi>) {
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
         self._api = n_api
     }
     ///
@@ -65,7 +66,7 @@ impl ListMemberNote {
     ///
     /// Delete a specific note for a specific list member.
     ///
-    pub fn delete(&self) -> Option<MailchimpErrorType> {
+    pub fn delete(&self) -> Option<MailchimpError> {
         // DELETE /lists/{list_id}/members/{subscriber_hash}/notes/{note_id}
         let endpoint = self.get_base_endpoint();
         match self._api.delete::<EmptyType>(&endpoint, HashMap::new()) {
@@ -161,7 +162,7 @@ impl BuildIter for ListMemberNoteBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data.set_endpoint(&self.endpoint);
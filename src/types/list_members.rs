@@ -1,20 +1,31 @@
 use super::empty::EmptyType;
+use super::enums::{EmailType, MemberStatus, SortDir};
 use super::link::LinkType;
-use super::list_member_activity::{CollectionListMemberActivity, ListMemberActivityBuilder};
-use super::list_member_goals::{CollectionListMemberGoal, ListMemberGoalBuilder};
-use super::list_member_notes::{CollectionListMemberNote, ListMemberNote, ListMemberNoteBuilder};
-use super::list_member_tags::{
-    CollectionListMemberTag, ListMemberTagBuilder, ListMemberTagParam, ListMemberTagType,
-};
+use super::list_member_activity::ListMemberActivityBuilder;
+use super::list_member_events::{ListMemberEventBuilder, ListMemberEventParam};
+use super::list_member_goals::ListMemberGoalBuilder;
+use super::list_member_notes::{ListMemberNote, ListMemberNoteBuilder};
+use super::list_member_tags::{ListMemberTagBuilder, ListMemberTagParam, ListMemberTagType};
+use super::list_merge_fields::{validate_merge_fields, ListMergeField, MergeValue};
+use super::timestamp::{format_datetime, MailchimpDateTime, Timestamp};
 use crate::api::MailchimpApi;
-use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::error_type::MailchimpError;
 use crate::internal::request::MailchimpResult;
 use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, ResourceFilter, SimpleFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
-use log::error;
+///
+/// Compute the subscriber hash of an email address: the MD5 hash of the
+/// lowercase version of the address, used to identify the list members.
+///
+/// Arguments:
+///     email: Email address of the member
+///
+pub fn subscriber_hash(email: &str) -> String {
+    format!("{:x}", md5::compute(email.trim().to_lowercase()))
+}
 
 ///
 /// The most recent Note added about this member.
@@ -25,8 +36,8 @@ pub struct ListNote {
     #[serde(default)]
     pub note_id: u64,
     /// The date and time the note was created in ISO 8601 format.
-    #[serde(default)]
-    pub created_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at: Timestamp,
     /// The author of the note.
     #[serde(default)]
     pub created_by: String,
@@ -39,7 +50,7 @@ impl Default for ListNote {
     fn default() -> Self {
         ListNote {
             note_id: 0,
-            created_at: "".to_string(),
+            created_at: Timestamp::default(),
             created_by: "".to_string(),
             note: "".to_string(),
         }
@@ -161,16 +172,16 @@ pub struct ListMember {
     pub unique_email_id: String,
     /// Type of email this member asked to get (‘html’ or ‘text’).
     #[serde(default)]
-    pub email_type: String,
+    pub email_type: EmailType,
     /// Subscriber’s current status.
     #[serde(default)]
-    pub status: String,
+    pub status: MemberStatus,
     /// A subscriber’s reason for unsubscribing.
     #[serde(default)]
     pub unsubscribe_reason: String,
     /// An individual merge var and value for a member.
     #[serde(default)]
-    pub merge_fields: HashMap<String, String>,
+    pub merge_fields: HashMap<String, MergeValue>,
     /// The key of this object’s properties is the ID of the interest in question.
     #[serde(default)]
     pub interests: HashMap<String, String>,
@@ -178,20 +189,20 @@ pub struct ListMember {
     #[serde(default)]
     pub stats: SubscriberStats,
     /// The date and time the subscriber signed up for the list in ISO 8601 format.
-    #[serde(default)]
-    pub timestamp_signup: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub timestamp_signup: Timestamp,
     /// The IP address the subscriber used to confirm their opt-in status.
     #[serde(default)]
     pub ip_opt: String,
     /// The date and time the subscribe confirmed their opt-in status in ISO 8601 format.
-    #[serde(default)]
-    pub timestamp_opt: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub timestamp_opt: Timestamp,
     /// Star rating for this member, between 1 and 5.
     #[serde(default)]
     pub member_rating: u64,
     /// The date and time the member’s info was last changed in ISO 8601 format.
-    #[serde(default)]
-    pub last_changed: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_changed: Timestamp,
     /// If set/detected, the subscriber’s language.
     #[serde(default)]
     pub language: String,
@@ -228,7 +239,7 @@ pub struct ListMember {
 
     /// Mailchimp API
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
     /// Endpoint Base for the instance
     #[serde(skip)]
     _endpoint: String,
@@ -243,7 +254,7 @@ impl ListMember {
     /// remove them from a list. This will make it impossible to re-import the list member.
     ///
     ///
-    pub fn permanently_delete(&self) -> Option<MailchimpErrorType> {
+    pub fn permanently_delete(&self) -> Option<MailchimpError> {
         // POST /lists/{list_id}/members/{subscriber_hash}/actions/delete-permanent
         let mut b_endpoint = self.build_list_endpoint();
         b_endpoint.push_str("/actions/delete-permanent");
@@ -259,7 +270,7 @@ impl ListMember {
     ///
     /// Delete a member from a list/segment
     ///
-    pub fn archive(&self) -> Option<MailchimpErrorType> {
+    pub fn archive(&self) -> Option<MailchimpError> {
         // DELETE /lists/{list_id}/members/{subscriber_hash}
         let b_endpoint = self.build_list_endpoint();
         match self
@@ -274,7 +285,7 @@ impl ListMember {
     ///
     /// Delete a member from a list/segment
     ///
-    pub fn delete(&self) -> Option<MailchimpErrorType> {
+    pub fn delete(&self) -> Option<MailchimpError> {
         // DELETE /lists/{list_id}/members/{subscriber_hash}
         let b_endpoint = self._endpoint.clone();
         match self
@@ -289,7 +300,7 @@ impl ListMember {
     /**
      * Update API
      */
-    pub fn set_api(&mut self, n_api: Rc<MailchimpApi>) {
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
         self._api = n_api
     }
 
@@ -326,32 +337,12 @@ impl ListMember {
         endpoint.push_str("/activity");
         let filter_params = SimpleFilter::default();
 
-        match self
-            ._api
-            .get::<CollectionListMemberActivity>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListMemberActivityBuilder {},
-                data: collection.activity,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: endpoint.clone(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get List Members: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListMemberActivityBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            ListMemberActivityBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -367,32 +358,12 @@ impl ListMember {
         endpoint.push_str("/goals");
         let filter_params = SimpleFilter::default();
 
-        match self
-            ._api
-            .get::<CollectionListMemberGoal>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListMemberGoalBuilder {},
-                data: collection.goals,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: endpoint.clone(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get List Members: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListMemberGoalBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            ListMemberGoalBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
     ///
     /// Get the tags on a list member.
@@ -403,32 +374,12 @@ impl ListMember {
         endpoint.push_str("/tags");
         let filter_params = SimpleFilter::default();
 
-        match self
-            ._api
-            .get::<CollectionListMemberTag>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListMemberTagBuilder {},
-                data: collection.tags,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: endpoint.clone(),
-            },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get List Members: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListMemberTagBuilder {},
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+        MalchimpIter::from_endpoint(
+            ListMemberTagBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -437,7 +388,7 @@ impl ListMember {
     /// Add or remove tags from a list member. If a tag that does not exist is passed in
     /// and set as ‘active’, a new tag will be created.
     ///
-    pub fn post_tag(&self, tags: Vec<ListMemberTagType>) -> Option<MailchimpErrorType> {
+    pub fn post_tag(&self, tags: Vec<ListMemberTagType>) -> Option<MailchimpError> {
         // POST /lists/{list_id}/members/{subscriber_hash}/tags
         let mut endpoint = self.get_base_endpoint();
         endpoint.push_str("/tags");
@@ -452,6 +403,39 @@ impl ListMember {
         }
     }
 
+    ///
+    /// Get events for a contact
+    ///
+    /// Arguments:
+    ///     filters
+    ///
+    pub fn get_events(
+        &self,
+        filters: Option<SimpleFilter>,
+    ) -> MalchimpIter<ListMemberEventBuilder> {
+        // GET /lists/{list_id}/members/{subscriber_hash}/events
+        let endpoint = self.get_base_endpoint() + "/events";
+        MalchimpIter::from_endpoint(
+            ListMemberEventBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filters.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Add an event for a list member
+    ///
+    /// Events with `is_syncing` set to true will not trigger automations.
+    ///
+    pub fn create_event(&self, param: ListMemberEventParam) -> Option<MailchimpError> {
+        // POST /lists/{list_id}/members/{subscriber_hash}/events
+        let endpoint = self.get_base_endpoint() + "/events";
+        self._api
+            .post::<EmptyType, ListMemberEventParam>(&endpoint, param)
+            .err()
+    }
+
     ///
     /// Get recent notes for a specific list member
     ///
@@ -469,36 +453,14 @@ impl ListMember {
             SimpleFilter::default()
         };
 
-        match self
-            ._api
-            .get::<CollectionListMemberNote>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListMemberNoteBuilder {
-                    endpoint: endpoint.clone(),
-                },
-                data: collection.notes,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
+        MalchimpIter::from_endpoint(
+            ListMemberNoteBuilder {
                 endpoint: endpoint.clone(),
             },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get List Members: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListMemberNoteBuilder {
-                        endpoint: endpoint.clone(),
-                    },
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -578,7 +540,7 @@ impl Default for CollectionListMembers {
 ///
 /// List Members Filter
 ///
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ListMembersFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -592,21 +554,21 @@ pub struct ListMembersFilter {
     /// large collections with this parameter can be slow. Default value is 0..
     pub offset: Option<u64>,
     /// The email type.
-    pub email_type: Option<String>,
+    pub email_type: Option<EmailType>,
     /// The subscriber’s status.
-    pub status: Option<String>,
+    pub status: Option<MemberStatus>,
     /// Restrict results to subscribers who opted-in after the set timeframe.
     /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub since_timestamp_opt: Option<String>,
+    pub since_timestamp_opt: Option<MailchimpDateTime>,
     /// Restrict results to subscribers who opted-in before the set timeframe.
     /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub before_timestamp_opt: Option<String>,
+    pub before_timestamp_opt: Option<MailchimpDateTime>,
     /// Restrict results to subscribers whose information changed after the set timeframe.
     /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub since_last_changed: Option<String>,
+    pub since_last_changed: Option<MailchimpDateTime>,
     /// Restrict results to subscribers whose information changed before the set timeframe.
     /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub before_last_changed: Option<String>,
+    pub before_last_changed: Option<MailchimpDateTime>,
     /// A unique identifier for the email address across all Mailchimp lists.
     /// This parameter can be found in any links with Ecommerce Tracking enabled.
     pub unique_email_id: Option<String>,
@@ -627,13 +589,13 @@ pub struct ListMembersFilter {
     /// Returns files sorted by the specified field. [timestamp_opt, timestamp_signup]
     pub sort_field: Option<String>,
     /// Determines the order direction for sorted results.
-    pub sort_dir: Option<String>,
+    pub sort_dir: Option<SortDir>,
     /// Filter subscribers by those subscribed/unsubscribed/pending/cleaned since
     /// last email campaign send. Member status is required to use this filter.
     pub since_last_campaign: Option<bool>,
     /// Filter subscribers by those unsubscribed since a specific date. Using any status
     /// other than unsubscribed with this filter will result in an error.
-    pub unsubscribed_since: Option<String>,
+    pub unsubscribed_since: Option<MailchimpDateTime>,
 }
 
 impl Default for ListMembersFilter {
@@ -699,29 +661,17 @@ impl ResourceFilter for ListMembersFilter {
                 format!("{:}", self.status.as_ref().unwrap().clone()),
             );
         }
-        if self.since_timestamp_opt.is_some() {
-            payload.insert(
-                "since_timestamp_opt".to_string(),
-                format!("{:}", self.since_timestamp_opt.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.since_timestamp_opt {
+            payload.insert("since_timestamp_opt".to_string(), format_datetime(v));
         }
-        if self.before_timestamp_opt.is_some() {
-            payload.insert(
-                "before_timestamp_opt".to_string(),
-                format!("{:}", self.before_timestamp_opt.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.before_timestamp_opt {
+            payload.insert("before_timestamp_opt".to_string(), format_datetime(v));
         }
-        if self.since_last_changed.is_some() {
-            payload.insert(
-                "since_last_changed".to_string(),
-                format!("{:}", self.since_last_changed.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.since_last_changed {
+            payload.insert("since_last_changed".to_string(), format_datetime(v));
         }
-        if self.before_last_changed.is_some() {
-            payload.insert(
-                "before_last_changed".to_string(),
-                format!("{:}", self.before_last_changed.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.before_last_changed {
+            payload.insert("before_last_changed".to_string(), format_datetime(v));
         }
         if self.unique_email_id.is_some() {
             payload.insert(
@@ -771,11 +721,8 @@ impl ResourceFilter for ListMembersFilter {
                 format!("{:}", self.since_last_campaign.as_ref().unwrap().clone()),
             );
         }
-        if self.unsubscribed_since.is_some() {
-            payload.insert(
-                "unsubscribed_since".to_string(),
-                format!("{:}", self.unsubscribed_since.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.unsubscribed_since {
+            payload.insert("unsubscribed_since".to_string(), format_datetime(v));
         }
         payload
     }
@@ -798,7 +745,7 @@ impl BuildIter for ListMembersBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data.set_endpoint(&self.endpoint);
@@ -831,16 +778,16 @@ pub struct ListMemberParams {
     ///     * pending
     ///     * transactional
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status_if_new: Option<String>,
+    pub status_if_new: Option<MemberStatus>,
     /// Type of email this member asked to get (‘html’ or ‘text’).
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub email_type: Option<String>,
+    pub email_type: Option<EmailType>,
     /// Subscriber’s current status.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MemberStatus>,
     /// An individual merge var and value for a member.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub merge_fields: Option<HashMap<String, String>>,
+    pub merge_fields: Option<HashMap<String, MergeValue>>,
     /// The key of this object’s properties is the ID of the interest in question.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub interests: Option<HashMap<String, String>>,
@@ -866,12 +813,36 @@ pub struct ListMemberParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ip_signup: Option<String>,
     /// The date and time the subscriber signed up for the list in ISO 8601 format.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub timestamp_signup: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timestamp_signup: Option<MailchimpDateTime>,
     /// The IP address the subscriber used to confirm their opt-in status.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ip_opt: Option<String>,
     /// The date and time the subscribe confirmed their opt-in status in ISO 8601 format.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub timestamp_opt: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timestamp_opt: Option<MailchimpDateTime>,
+}
+
+impl ListMemberParams {
+    ///
+    /// Check the merge fields against the merge fields of the list before
+    /// sending them, the required fields must have a value
+    ///
+    /// Arguments:
+    ///     fields: Merge fields of the list
+    ///
+    pub fn validate_merge_fields(&self, fields: &[ListMergeField]) -> Result<(), MailchimpError> {
+        match &self.merge_fields {
+            Some(values) => validate_merge_fields(fields, values),
+            None => validate_merge_fields(fields, &HashMap::new()),
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use super::empty::EmptyType;
 use super::link::LinkType;
 use crate::api::MailchimpApi;
-use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::error_type::MailchimpError;
 use crate::internal::request::MailchimpResult;
 use crate::iter::{BuildIter, MailchimpCollection, ResourceFilter};
 ///
@@ -9,9 +9,11 @@ use crate::iter::{BuildIter, MailchimpCollection, ResourceFilter};
 ///
 /// Manage merge fields (formerly merge vars) for a specific list. Learn more about merge field limits in Mailchimp.
 ///
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// Merge Field Options
@@ -93,7 +95,7 @@ pub struct ListMergeField {
 
     /// Mailchimp API
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
 
     /// Endpoint
     #[serde(skip)]
@@ -176,7 +178,7 @@ impl Default for CollectionListMergeField {
 
 /// ================================= ITER =====================
 
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ListMergeFieldFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -266,7 +268,7 @@ impl BuildIter for ListMergeFieldBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data.set_endpoint(&self.endpoint);
@@ -296,7 +298,7 @@ impl ListMergeField {
     ///
     /// Delete a specific merge field in a list.
     ///
-    pub fn delete(&self) -> Option<MailchimpErrorType> {
+    pub fn delete(&self) -> Option<MailchimpError> {
         // DELETE /lists/{list_id}/merge-fields/{merge_id}
         let endpoint = self.get_base_endpoint();
         match self._api.delete::<EmptyType>(&endpoint, HashMap::new()) {
@@ -307,7 +309,7 @@ impl ListMergeField {
     ///
     /// Set API
     ///
-    pub fn set_api(&mut self, api: Rc<MailchimpApi>) {
+    pub fn set_api(&mut self, api: Arc<MailchimpApi>) {
         self._api = api;
     }
     ///
@@ -325,3 +327,301 @@ impl ListMergeField {
         endpoint
     }
 }
+
+///
+/// Tipos de merge field que se validan, el resto los valida Mailchimp
+///
+const MERGE_FIELD_TYPES: [&str; 10] = [
+    "text", "number", "address", "phone", "date", "birthday", "url", "imageurl", "dropdown",
+    "radio",
+];
+
+impl ListMergeField {
+    ///
+    /// Convert a value to the variant of this merge field type, the values
+    /// returned by Mailchimp are deserialized as `Text`, `Number` or `Address`
+    ///
+    /// Arguments:
+    ///     value: Value of the merge field
+    ///
+    pub fn typed_value(&self, value: &MergeValue) -> MergeValue {
+        let text = match value {
+            MergeValue::Text(v) => v.clone(),
+            _ => return value.clone(),
+        };
+        match self.mf_type.as_str() {
+            "number" => match text.trim().parse::<f64>() {
+                Ok(n) => MergeValue::Number(n),
+                Err(_) => MergeValue::Text(text),
+            },
+            "phone" => MergeValue::Phone(text),
+            "date" => MergeValue::Date(text),
+            "birthday" => MergeValue::Birthday(text),
+            "url" => MergeValue::Url(text),
+            "imageurl" => MergeValue::ImageUrl(text),
+            "dropdown" => MergeValue::Dropdown(text),
+            "radio" => MergeValue::Radio(text),
+            _ => MergeValue::Text(text),
+        }
+    }
+
+    ///
+    /// Check a value against the type and the options of this merge field
+    ///
+    /// Arguments:
+    ///     value: Value of the merge field, `None` if it is not sent
+    ///
+    pub fn validate_value(&self, value: Option<&MergeValue>) -> Result<(), MailchimpError> {
+        let value = match value {
+            Some(v) if !v.is_empty() => self.typed_value(v),
+            _ => {
+                if self.required {
+                    return Err(self.invalid("the field is required"));
+                }
+                return Ok(());
+            }
+        };
+
+        let known_type = MERGE_FIELD_TYPES.contains(&self.mf_type.as_str());
+        if known_type && value.field_type() != self.mf_type {
+            return Err(self.invalid(&format!(
+                "expected a {} value, found {:?}",
+                self.mf_type, value
+            )));
+        }
+
+        if let MergeValue::Dropdown(v) | MergeValue::Radio(v) = &value {
+            let choices = &self.options.choices;
+            if !choices.is_empty() && !choices.contains(v) {
+                return Err(self.invalid(&format!("{:?} is not one of {:?}", v, choices)));
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Crea el error de validación de este campo
+    ///
+    fn invalid(&self, reason: &str) -> MailchimpError {
+        MailchimpError::InvalidMergeField {
+            tag: self.tag.clone(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+///
+/// Check the merge fields of a member against the definitions of the list
+///
+/// Every value must belong to a merge field of the list and match its type,
+/// and every required merge field must have a value.
+///
+/// Arguments:
+///     fields: Merge fields of the list
+///     values: Merge fields of the member
+///
+pub fn validate_merge_fields(
+    fields: &[ListMergeField],
+    values: &HashMap<String, MergeValue>,
+) -> Result<(), MailchimpError> {
+    for tag in values.keys() {
+        if !fields.iter().any(|f| &f.tag == tag) {
+            return Err(MailchimpError::InvalidMergeField {
+                tag: tag.clone(),
+                reason: "the list does not have this merge field".to_string(),
+            });
+        }
+    }
+    for field in fields {
+        field.validate_value(values.get(&field.tag))?;
+    }
+    Ok(())
+}
+
+///
+/// Value of an `address` merge field
+///
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct MergeAddress {
+    /// First line of the address.
+    #[serde(default)]
+    pub addr1: String,
+    /// Second line of the address.
+    #[serde(default)]
+    pub addr2: String,
+    /// The city.
+    #[serde(default)]
+    pub city: String,
+    /// The state or the province.
+    #[serde(default)]
+    pub state: String,
+    /// The postal code.
+    #[serde(default)]
+    pub zip: String,
+    /// The two letter country code.
+    #[serde(default)]
+    pub country: String,
+}
+
+///
+/// Value of a merge field of a member
+///
+/// Mailchimp sends every value as a string except numbers and addresses, so
+/// the responses are deserialized as `Text`, `Number` or `Address`. Use
+/// `ListMergeField::typed_value` to get the variant of the field type.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum MergeValue {
+    /// text
+    Text(String),
+    /// number
+    Number(f64),
+    /// address
+    Address(MergeAddress),
+    /// phone
+    Phone(String),
+    /// date
+    Date(String),
+    /// birthday
+    Birthday(String),
+    /// url
+    Url(String),
+    /// imageurl
+    ImageUrl(String),
+    /// dropdown
+    Dropdown(String),
+    /// radio
+    Radio(String),
+}
+
+impl MergeValue {
+    ///
+    /// Merge field type of the variant
+    ///
+    pub fn field_type(&self) -> &'static str {
+        match self {
+            MergeValue::Text(_) => "text",
+            MergeValue::Number(_) => "number",
+            MergeValue::Address(_) => "address",
+            MergeValue::Phone(_) => "phone",
+            MergeValue::Date(_) => "date",
+            MergeValue::Birthday(_) => "birthday",
+            MergeValue::Url(_) => "url",
+            MergeValue::ImageUrl(_) => "imageurl",
+            MergeValue::Dropdown(_) => "dropdown",
+            MergeValue::Radio(_) => "radio",
+        }
+    }
+
+    ///
+    /// Value as a string, `None` for numbers and addresses
+    ///
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            MergeValue::Text(v)
+            | MergeValue::Phone(v)
+            | MergeValue::Date(v)
+            | MergeValue::Birthday(v)
+            | MergeValue::Url(v)
+            | MergeValue::ImageUrl(v)
+            | MergeValue::Dropdown(v)
+            | MergeValue::Radio(v) => Some(v),
+            MergeValue::Number(_) | MergeValue::Address(_) => None,
+        }
+    }
+
+    ///
+    /// Value as a number, `None` for the other variants
+    ///
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            MergeValue::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Value as an address, `None` for the other variants
+    ///
+    pub fn as_address(&self) -> Option<&MergeAddress> {
+        match self {
+            MergeValue::Address(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns true for the empty strings used by Mailchimp when the member
+    /// does not have a value
+    ///
+    pub fn is_empty(&self) -> bool {
+        match self.as_str() {
+            Some(v) => v.is_empty(),
+            None => false,
+        }
+    }
+}
+
+impl Default for MergeValue {
+    fn default() -> Self {
+        MergeValue::Text(String::new())
+    }
+}
+
+impl From<&str> for MergeValue {
+    fn from(v: &str) -> Self {
+        MergeValue::Text(v.to_string())
+    }
+}
+
+impl From<String> for MergeValue {
+    fn from(v: String) -> Self {
+        MergeValue::Text(v)
+    }
+}
+
+impl From<f64> for MergeValue {
+    fn from(v: f64) -> Self {
+        MergeValue::Number(v)
+    }
+}
+
+impl From<i64> for MergeValue {
+    fn from(v: i64) -> Self {
+        MergeValue::Number(v as f64)
+    }
+}
+
+impl From<MergeAddress> for MergeValue {
+    fn from(v: MergeAddress) -> Self {
+        MergeValue::Address(v)
+    }
+}
+
+impl Serialize for MergeValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            // Los enteros se envían sin decimales
+            MergeValue::Number(n) if n.fract() == 0.0 && n.abs() < 1e15 => {
+                serializer.serialize_i64(*n as i64)
+            }
+            MergeValue::Number(n) => serializer.serialize_f64(*n),
+            MergeValue::Address(a) => a.serialize(serializer),
+            _ => serializer.serialize_str(self.as_str().unwrap_or_default()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MergeValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Value::deserialize(deserializer)? {
+            Value::Null => Ok(MergeValue::default()),
+            Value::String(v) => Ok(MergeValue::Text(v)),
+            Value::Number(n) => Ok(MergeValue::Number(n.as_f64().unwrap_or_default())),
+            Value::Object(o) => serde_json::from_value::<MergeAddress>(Value::Object(o))
+                .map(MergeValue::Address)
+                .map_err(D::Error::custom),
+            other => Ok(MergeValue::Text(other.to_string())),
+        }
+    }
+}
@@ -3,16 +3,16 @@ use serde::{Deserialize, Serialize};
 
 use super::link::LinkType;
 use super::list_batch_members::ListBatchErrors;
-use super::list_members::ListMember;
-use super::list_segment_members::{CollectionListSegmentMembers, ListSegmentMembersBuilder};
+use super::list_members::{subscriber_hash, ListMember};
+use super::list_segment_members::ListSegmentMembersBuilder;
 use super::list_segment_options::SegmentOptionsType;
+use super::timestamp::{format_datetime, MailchimpDateTime, Timestamp};
 use crate::api::MailchimpApi;
-use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::error_type::MailchimpError;
 use crate::internal::request::MailchimpResult;
 use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, ResourceFilter, SimpleFilter};
-use log::error;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// List Segment
@@ -38,11 +38,11 @@ pub struct ListSegment {
     #[serde(default, rename = "type")]
     pub s_type: String,
     /// The date and time the segment was created in ISO 8601 format.
-    #[serde(default)]
-    pub created_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub created_at: Timestamp,
     /// The date and time the segment was last updated in ISO 8601 format.
-    #[serde(default)]
-    pub updated_at: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub updated_at: Timestamp,
     /// The conditions of the segment. Static segments (tags) and fuzzy segments don’t have conditions.
     #[serde(default)]
     pub options: SegmentOptionsType,
@@ -55,7 +55,7 @@ pub struct ListSegment {
 
     /// Mailchimp API
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
     /// Endpoint
     #[serde(skip)]
     _endpoint: String,
@@ -156,7 +156,7 @@ impl Default for CollectionListSegment {
 
 /// ================================= ITER =====================
 
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ListSegmentFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -177,22 +177,22 @@ pub struct ListSegmentFilter {
     /// Restrict results to segments created after the set time.
     ///  We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
     ///
-    pub since_created_at: Option<String>,
+    pub since_created_at: Option<MailchimpDateTime>,
     ///
     /// Restrict results to segments created before the set time.
     /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
     ///
-    pub before_created_at: Option<String>,
+    pub before_created_at: Option<MailchimpDateTime>,
     ///
     /// Restrict results to segments update after the set time.
     /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
     ///
-    pub since_updated_at: Option<String>,
+    pub since_updated_at: Option<MailchimpDateTime>,
     ///
     /// Restrict results to segments update before the set time.
     /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
     ///
-    pub before_updated_at: Option<String>,
+    pub before_updated_at: Option<MailchimpDateTime>,
 }
 
 impl Default for ListSegmentFilter {
@@ -242,29 +242,17 @@ impl ResourceFilter for ListSegmentFilter {
                 format!("{:}", self.s_type.as_ref().unwrap().clone()),
             );
         }
-        if self.since_created_at.is_some() {
-            payload.insert(
-                "since_created_at".to_string(),
-                format!("{:}", self.since_created_at.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.since_created_at {
+            payload.insert("since_created_at".to_string(), format_datetime(v));
         }
-        if self.before_created_at.is_some() {
-            payload.insert(
-                "before_created_at".to_string(),
-                format!("{:}", self.before_created_at.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.before_created_at {
+            payload.insert("before_created_at".to_string(), format_datetime(v));
         }
-        if self.since_updated_at.is_some() {
-            payload.insert(
-                "since_updated_at".to_string(),
-                format!("{:}", self.since_updated_at.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.since_updated_at {
+            payload.insert("since_updated_at".to_string(), format_datetime(v));
         }
-        if self.before_updated_at.is_some() {
-            payload.insert(
-                "before_updated_at".to_string(),
-                format!("{:}", self.before_updated_at.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.before_updated_at {
+            payload.insert("before_updated_at".to_string(), format_datetime(v));
         }
         payload
     }
@@ -287,7 +275,7 @@ impl BuildIter for ListSegmentBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data.set_endpoint(&self.endpoint);
@@ -343,7 +331,7 @@ impl ListSegment {
     ///
     /// Delete a specific segment in a list.
     ///
-    pub fn delete(&self) -> Option<MailchimpErrorType> {
+    pub fn delete(&self) -> Option<MailchimpError> {
         // DELETE /lists/{list_id}/segments/{segment_id}
         let endpoint = self.get_base_endpoint();
         match self._api.delete::<EmptyType>(&endpoint, HashMap::new()) {
@@ -369,36 +357,14 @@ impl ListSegment {
             SimpleFilter::default()
         };
 
-        match self
-            ._api
-            .get::<CollectionListSegmentMembers>(&endpoint, filter_params.build_payload())
-        {
-            Ok(collection) => MalchimpIter {
-                builder: ListSegmentMembersBuilder {
-                    endpoint: endpoint.clone(),
-                },
-                data: collection.members,
-                cur_filters: filter_params.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
+        MalchimpIter::from_endpoint(
+            ListSegmentMembersBuilder {
                 endpoint: endpoint.clone(),
             },
-            Err(e) => {
-                error!( target: "mailchimp",  "Get Information_members: Response Error details: {:?}", e);
-                MalchimpIter {
-                    builder: ListSegmentMembersBuilder {
-                        endpoint: endpoint.clone(),
-                    },
-                    data: Vec::new(),
-                    cur_filters: filter_params.clone(),
-                    cur_it: 0,
-                    total_items: 0,
-                    api: self._api.clone(),
-                    endpoint: endpoint.clone(),
-                }
-            }
-        }
+            self._api.clone(),
+            &endpoint,
+            filter_params,
+        )
     }
 
     ///
@@ -407,7 +373,7 @@ impl ListSegment {
     /// Arguments:
     ///    subscriber_hash: The MD5 hash of the lowercase version of the list member’s email address.
     ///
-    pub fn remove_member<'a>(&self, subscriber_hash: &'a str) -> Option<MailchimpErrorType> {
+    pub fn remove_member(&self, subscriber_hash: &str) -> Option<MailchimpError> {
         // DELETE /lists/{list_id}/segments/{segment_id}/members/{subscriber_hash}
         let mut endpoint = self.get_base_endpoint();
         endpoint.push_str("/members/");
@@ -419,6 +385,16 @@ impl ListSegment {
         }
     }
 
+    ///
+    /// Remove a member from the specified static segment by the email address
+    ///
+    /// Arguments:
+    ///    email_address: Email address for a subscriber, it is normalised and hashed
+    ///
+    pub fn remove_member_by_email(&self, email_address: &str) -> Option<MailchimpError> {
+        self.remove_member(&subscriber_hash(email_address))
+    }
+
     ///
     /// Add a member to a static segment.
     ///
@@ -449,7 +425,7 @@ impl ListSegment {
     ///
     /// Set API
     ///
-    pub fn set_api(&mut self, n_api: Rc<MailchimpApi>) {
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
         self._api = n_api;
     }
 
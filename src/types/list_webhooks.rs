@@ -6,12 +6,12 @@
 use super::empty::EmptyType;
 use super::link::LinkType;
 use crate::api::MailchimpApi;
-use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::error_type::MailchimpError;
 use crate::internal::request::MailchimpResult;
 use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// The events that can trigger the webhook and whether they are enabled.
@@ -104,7 +104,7 @@ pub struct ListWebhooks {
 
     /// Mailchimp API
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
 
     /// Endpoint
     #[serde(skip)]
@@ -184,7 +184,7 @@ impl BuildIter for ListWebhooksBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, api: Rc<MailchimpApi>) -> Self::Item {
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
         let mut in_data = data.clone();
         in_data.set_api(api);
         in_data.set_endpoint(&self.endpoint);
@@ -216,7 +216,7 @@ impl ListWebhooks {
     ///
     /// Delete a specific webhook in a list.
     ///
-    pub fn delete(&self) -> Option<MailchimpErrorType> {
+    pub fn delete(&self) -> Option<MailchimpError> {
         // DELETE /lists/{list_id}/webhooks/{webhook_id}
         let endpoint = self.get_base_endpoint();
         match self._api.delete::<EmptyType>(&endpoint, HashMap::new()) {
@@ -228,7 +228,7 @@ impl ListWebhooks {
     ///
     /// Set API
     ///
-    pub fn set_api(&mut self, api: Rc<MailchimpApi>) {
+    pub fn set_api(&mut self, api: Arc<MailchimpApi>) {
         self._api = api;
     }
     ///
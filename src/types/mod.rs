@@ -7,15 +7,25 @@ mod authorized_apps;
 mod automation_campaign;
 mod automation_email_queue;
 mod automation_subscriber;
+mod batch;
 mod campaign;
 mod campaign_content;
 mod campaign_feedback;
+mod campaign_folders;
 mod campaign_send_checklist;
 mod contact;
 mod conversation_messages;
 mod conversations;
 mod ecommerce;
+mod ecommerce_carts;
+mod ecommerce_customers;
+mod ecommerce_orders;
+mod ecommerce_products;
+mod ecommerce_promo_rules;
+mod ecommerce_stores;
 mod empty;
+mod enums;
+mod file_manager;
 mod industry_stats;
 mod link;
 mod list;
@@ -28,6 +38,7 @@ mod list_interest_categories;
 mod list_interests;
 mod list_locations;
 mod list_member_activity;
+mod list_member_events;
 mod list_member_goals;
 mod list_member_notes;
 mod list_member_tags;
@@ -38,8 +49,14 @@ mod list_segment_options;
 mod list_segments;
 mod list_signup_forms;
 mod list_webhooks;
+mod oauth;
 mod ping;
 mod report;
+mod report_details;
+mod search;
+mod template;
+mod template_folders;
+mod timestamp;
 mod workflow_email;
 mod landing_pages;
 
@@ -48,15 +65,25 @@ pub use self::authorized_apps::{AuthorizedAppType, AuthorizedAppsType, CreatedAu
 pub use self::automation_campaign::*;
 pub use self::automation_email_queue::*;
 pub use self::automation_subscriber::*;
+pub use self::batch::*;
 pub use self::campaign::*;
 pub use self::campaign_content::*;
 pub use self::campaign_feedback::*;
+pub use self::campaign_folders::*;
 pub use self::campaign_send_checklist::*;
 pub use self::contact::ContactType;
 pub use self::conversation_messages::*;
 pub use self::conversations::*;
 pub use self::ecommerce::*;
+pub use self::ecommerce_carts::*;
+pub use self::ecommerce_customers::*;
+pub use self::ecommerce_orders::*;
+pub use self::ecommerce_products::*;
+pub use self::ecommerce_promo_rules::*;
+pub use self::ecommerce_stores::*;
 pub use self::empty::*;
+pub use self::enums::*;
+pub use self::file_manager::*;
 pub use self::industry_stats::*;
 pub use self::link::LinkType;
 pub use self::list::*;
@@ -69,6 +96,7 @@ pub use self::list_interest_categories::*;
 pub use self::list_interests::*;
 pub use self::list_locations::*;
 pub use self::list_member_activity::*;
+pub use self::list_member_events::*;
 pub use self::list_member_goals::*;
 pub use self::list_member_notes::*;
 pub use self::list_member_tags::*;
@@ -79,7 +107,13 @@ pub use self::list_segment_options::*;
 pub use self::list_segments::*;
 pub use self::list_signup_forms::*;
 pub use self::list_webhooks::*;
+pub use self::oauth::*;
 pub use self::ping::*;
 pub use self::report::*;
+pub use self::report_details::*;
+pub use self::search::*;
+pub use self::template::*;
+pub use self::template_folders::*;
+pub use self::timestamp::{format_datetime, MailchimpDateTime, Timestamp};
 pub use self::workflow_email::*;
 pub use self::landing_pages::*;
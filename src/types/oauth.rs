@@ -0,0 +1,69 @@
+//! Implement OAuth2 Model Types
+
+use serde::{Deserialize, Serialize};
+
+///
+/// Access token returned by the OAuth2 token exchange
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OAuthTokenType {
+    /// Access token of the account, Mailchimp tokens never expire.
+    #[serde(default)]
+    pub access_token: String,
+    /// Lifetime of the token in seconds, 0 means that it does not expire.
+    #[serde(default)]
+    pub expires_in: u64,
+    /// Scope granted to the token.
+    #[serde(default)]
+    pub scope: Option<String>,
+}
+
+///
+/// Login of the user that authorized the application
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OAuthLoginType {
+    /// The login ID.
+    #[serde(default)]
+    pub login_id: u64,
+    /// The login name.
+    #[serde(default)]
+    pub login_name: String,
+    /// The login email address.
+    #[serde(default)]
+    pub login_email: String,
+    /// URL of the avatar of the login.
+    #[serde(default)]
+    pub avatar: Option<String>,
+    /// The role of the login in the account.
+    #[serde(default)]
+    pub role: String,
+}
+
+///
+/// Metadata of the account linked to an OAuth2 access token
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OAuthMetadataType {
+    /// Datacenter of the account, e.g. `us6`.
+    #[serde(default)]
+    pub dc: String,
+    /// The user role for the account.
+    #[serde(default)]
+    pub role: String,
+    /// The name of the account.
+    #[serde(default)]
+    pub accountname: String,
+    /// The ID of the user that owns the account.
+    #[serde(default)]
+    pub user_id: u64,
+    /// Login of the user that authorized the application.
+    #[serde(default)]
+    pub login: OAuthLoginType,
+    /// URL to login into the account.
+    #[serde(default)]
+    pub login_url: String,
+    /// Base URL of the API for the account, e.g. `https://us6.api.mailchimp.com`.
+    #[serde(default)]
+    pub api_endpoint: String,
+}
@@ -1,12 +1,21 @@
 use super::campaign::CampaignDeliveryStatusType;
 use super::ecommerce::ECommerceReportType;
+use super::enums::CampaignKind;
 use super::industry_stats::IndustryStatsType;
 use super::link::LinkType;
+use super::report_details::{
+    ClickDetailType, ClickDetailsBuilder, CollectionCampaignAdvice, CollectionSubReports,
+    DomainPerformanceReportType, EepurlReportType, EmailActivityBuilder, OpenDetailsBuilder,
+    OpenLocationsBuilder, ReportActivityFilter, SentToBuilder, SentToType, UnsubscribedBuilder,
+    UnsubscribedType,
+};
+use super::timestamp::{format_datetime, MailchimpDateTime, Timestamp};
 use crate::api::MailchimpApi;
-use crate::iter::{BuildIter, MailchimpCollection, ResourceFilter};
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, ResourceFilter, SimpleFilter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// An object describing the bounce summary for the campaign.
@@ -71,8 +80,8 @@ pub struct OpensType {
     #[serde(default)]
     pub open_rate: f32,
     /// The date and time of the last recorded open in ISO 8601 format.
-    #[serde(default)]
-    pub last_open: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_open: Timestamp,
 }
 
 impl Default for OpensType {
@@ -81,7 +90,7 @@ impl Default for OpensType {
             opens_total: 0,
             unique_opens: 0,
             open_rate: 0.0,
-            last_open: String::new(),
+            last_open: Timestamp::default(),
         }
     }
 }
@@ -104,8 +113,8 @@ pub struct ClicksType {
     #[serde(default)]
     pub click_rate: f32,
     /// The date and time of the last recorded click for the campaign in ISO 8601 format.
-    #[serde(default)]
-    pub last_click: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_click: Timestamp,
 }
 
 impl Default for ClicksType {
@@ -115,7 +124,7 @@ impl Default for ClicksType {
             unique_clicks: 0,
             unique_subscriber_clicks: 0,
             click_rate: 0.0,
-            last_click: String::new(),
+            last_click: Timestamp::default(),
         }
     }
 }
@@ -206,8 +215,8 @@ pub struct AbStatsType {
     #[serde(default)]
     pub opens: u64,
     /// The last open for Campaign A / B.
-    #[serde(default)]
-    pub last_open: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_open: Timestamp,
     /// Unique opens for Campaign A / B.
     #[serde(default)]
     pub unique_opens: u64,
@@ -223,7 +232,7 @@ impl Default for AbStatsType {
             forwards: 0,
             forwards_opens: 0,
             opens: 0,
-            last_open: String::new(),
+            last_open: Timestamp::default(),
             unique_opens: 0,
         }
     }
@@ -265,8 +274,8 @@ pub struct TimewarpStatsType {
     #[serde(default)]
     pub opens: u64,
     /// The date and time of the last open in ISO 8601 format.
-    #[serde(default)]
-    pub last_open: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_open: Timestamp,
     /// The number of unique opens.
     #[serde(default)]
     pub unique_opens: u64,
@@ -274,8 +283,8 @@ pub struct TimewarpStatsType {
     #[serde(default)]
     pub clicks: u64,
     /// The date and time of the last click in ISO 8601 format.
-    #[serde(default)]
-    pub last_click: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_click: Timestamp,
     /// The number of unique clicks.
     #[serde(default)]
     pub unique_clicks: u64,
@@ -289,10 +298,10 @@ impl Default for TimewarpStatsType {
         Self {
             gmt_offset: 0,
             opens: 0,
-            last_open: String::new(),
+            last_open: Timestamp::default(),
             unique_opens: 0,
             clicks: 0,
-            last_click: String::new(),
+            last_click: Timestamp::default(),
             unique_clicks: 0,
             bounces: 0,
         }
@@ -305,8 +314,8 @@ impl Default for TimewarpStatsType {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct TimeSerieType {
     /// The date and time for the series in ISO 8601 format.
-    #[serde(default)]
-    pub timestamp: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub timestamp: Timestamp,
     /// The number of emails sent in the timeseries.
     #[serde(default)]
     pub emails_sent: u64,
@@ -321,7 +330,7 @@ pub struct TimeSerieType {
 impl Default for TimeSerieType {
     fn default() -> Self {
         Self {
-            timestamp: String::new(),
+            timestamp: Timestamp::default(),
             emails_sent: 0,
             unique_opens: 0,
             recipients_clicks: 0,
@@ -364,7 +373,7 @@ pub struct ReportType {
     pub campaign_title: String,
     /// The type of campaign (regular, plain-text, ab_split, rss, automation, variate, or auto).
     #[serde(default, rename = "type")]
-    pub report_type: String,
+    pub report_type: CampaignKind,
     /// The unique list id.
     #[serde(default)]
     pub list_id: String,
@@ -390,11 +399,11 @@ pub struct ReportType {
     #[serde(default)]
     pub unsubscribed: u64,
     /// The date and time a campaign was sent in ISO 8601 format.
-    #[serde(default)]
-    pub send_time: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub send_time: Timestamp,
     /// For RSS campaigns, the date and time of the last send in ISO 8601 format.
-    #[serde(default)]
-    pub rss_last_send: String,
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub rss_last_send: Timestamp,
     /// An object describing the bounce summary for the campaign.
     #[serde(default)]
     pub bounces: HardBouncesType,
@@ -440,6 +449,222 @@ pub struct ReportType {
     /// A list of link types and descriptions for the API schema documents.
     #[serde(default)]
     pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+}
+
+impl ReportType {
+    // ============== Click Details ==============
+
+    ///
+    /// Get information about clicks on specific links in the campaign
+    ///
+    pub fn get_click_details(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<ClickDetailsBuilder> {
+        // GET /reports/{campaign_id}/click-details
+        let endpoint = self.get_base_endpoint() + "/click-details";
+        MalchimpIter::from_endpoint(
+            ClickDetailsBuilder {
+                endpoint: endpoint.clone(),
+            },
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get click details for a specific link in the campaign
+    ///
+    pub fn get_click_detail(&self, link_id: &str) -> MailchimpResult<ClickDetailType> {
+        // GET /reports/{campaign_id}/click-details/{link_id}
+        let endpoint = self.get_base_endpoint() + "/click-details";
+        let mut detail = self
+            ._api
+            .get::<ClickDetailType>(&format!("{}/{}", endpoint, link_id), HashMap::new())?;
+        detail.set_api(self._api.clone());
+        detail.set_endpoint(&endpoint);
+        Ok(detail)
+    }
+
+    // ============== Open Details ==============
+
+    ///
+    /// Get detailed information about any campaign emails that were opened by a list member
+    ///
+    pub fn get_open_details(
+        &self,
+        filter: Option<ReportActivityFilter>,
+    ) -> MalchimpIter<OpenDetailsBuilder> {
+        // GET /reports/{campaign_id}/open-details
+        let endpoint = self.get_base_endpoint() + "/open-details";
+        MalchimpIter::from_endpoint(
+            OpenDetailsBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    // ============== Email Activity ==============
+
+    ///
+    /// Get a list of member's subscriber activity in the campaign
+    ///
+    pub fn get_email_activity(
+        &self,
+        filter: Option<ReportActivityFilter>,
+    ) -> MalchimpIter<EmailActivityBuilder> {
+        // GET /reports/{campaign_id}/email-activity
+        let endpoint = self.get_base_endpoint() + "/email-activity";
+        MalchimpIter::from_endpoint(
+            EmailActivityBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    // ============== Recipients ==============
+
+    ///
+    /// Get information about campaign recipients
+    ///
+    pub fn get_sent_to(&self, filter: Option<SimpleFilter>) -> MalchimpIter<SentToBuilder> {
+        // GET /reports/{campaign_id}/sent-to
+        let endpoint = self.get_base_endpoint() + "/sent-to";
+        MalchimpIter::from_endpoint(
+            SentToBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific campaign recipient
+    ///
+    pub fn get_sent_to_member(&self, subscriber_hash: &str) -> MailchimpResult<SentToType> {
+        // GET /reports/{campaign_id}/sent-to/{subscriber_hash}
+        let endpoint = format!("{}/sent-to/{}", self.get_base_endpoint(), subscriber_hash);
+        self._api.get::<SentToType>(&endpoint, HashMap::new())
+    }
+
+    // ============== Unsubscribes ==============
+
+    ///
+    /// Get information about members who have unsubscribed from the campaign
+    ///
+    pub fn get_unsubscribed(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<UnsubscribedBuilder> {
+        // GET /reports/{campaign_id}/unsubscribed
+        let endpoint = self.get_base_endpoint() + "/unsubscribed";
+        MalchimpIter::from_endpoint(
+            UnsubscribedBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific list member who unsubscribed from the campaign
+    ///
+    pub fn get_unsubscribed_member(
+        &self,
+        subscriber_hash: &str,
+    ) -> MailchimpResult<UnsubscribedType> {
+        // GET /reports/{campaign_id}/unsubscribed/{subscriber_hash}
+        let endpoint = format!(
+            "{}/unsubscribed/{}",
+            self.get_base_endpoint(),
+            subscriber_hash
+        );
+        self._api.get::<UnsubscribedType>(&endpoint, HashMap::new())
+    }
+
+    // ============== Other Reports ==============
+
+    ///
+    /// Get statistics for the top-performing email domains in the campaign
+    ///
+    pub fn get_domain_performance(&self) -> MailchimpResult<DomainPerformanceReportType> {
+        // GET /reports/{campaign_id}/domain-performance
+        let endpoint = self.get_base_endpoint() + "/domain-performance";
+        self._api
+            .get::<DomainPerformanceReportType>(&endpoint, HashMap::new())
+    }
+
+    ///
+    /// Get top open locations for the campaign
+    ///
+    pub fn get_locations(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<OpenLocationsBuilder> {
+        // GET /reports/{campaign_id}/locations
+        let endpoint = self.get_base_endpoint() + "/locations";
+        MalchimpIter::from_endpoint(
+            OpenLocationsBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get a summary of social activity for the campaign, tracked by EepURL
+    ///
+    pub fn get_eepurl(&self) -> MailchimpResult<EepurlReportType> {
+        // GET /reports/{campaign_id}/eepurl
+        let endpoint = self.get_base_endpoint() + "/eepurl";
+        self._api.get::<EepurlReportType>(&endpoint, HashMap::new())
+    }
+
+    ///
+    /// Get feedback based on the campaign's statistics
+    ///
+    pub fn get_advice(&self) -> MailchimpResult<CollectionCampaignAdvice> {
+        // GET /reports/{campaign_id}/advice
+        let endpoint = self.get_base_endpoint() + "/advice";
+        self._api
+            .get::<CollectionCampaignAdvice>(&endpoint, HashMap::new())
+    }
+
+    ///
+    /// Get a list of reports with child campaigns for the campaign
+    ///
+    pub fn get_sub_reports(&self) -> MailchimpResult<CollectionSubReports> {
+        // GET /reports/{campaign_id}/sub-reports
+        let endpoint = self.get_base_endpoint() + "/sub-reports";
+        let mut collection = self
+            ._api
+            .get::<CollectionSubReports>(&endpoint, HashMap::new())?;
+        for report in collection.reports.iter_mut() {
+            report.set_api(self._api.clone());
+        }
+        Ok(collection)
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("reports/{}", self.id)
+    }
 }
 
 ///
@@ -483,7 +708,7 @@ impl Default for CollectionReports {
 ///
 /// Reports Filter
 ///
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ReportsFilter {
     /// A comma-separated list of fields to return. Reference
     /// parameters of sub-objects with dot notation.
@@ -499,13 +724,13 @@ pub struct ReportsFilter {
     /// The campaign type
     /// Possible Values:
     ///     regular - plaintext - absplit - rss - variate
-    pub campaign_type: Option<String>,
+    pub campaign_type: Option<CampaignKind>,
     /// Restrict the response to campaigns sent before the set time.
     /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub before_send_time: Option<String>,
+    pub before_send_time: Option<MailchimpDateTime>,
     /// Restrict the response to campaigns sent after the set time.
     /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-    pub since_send_time: Option<String>,
+    pub since_send_time: Option<MailchimpDateTime>,
 }
 
 impl Default for ReportsFilter {
@@ -553,17 +778,11 @@ impl ResourceFilter for ReportsFilter {
                 format!("{:}", self.campaign_type.as_ref().unwrap().clone()),
             );
         }
-        if self.before_send_time.is_some() {
-            payload.insert(
-                "before_send_time".to_string(),
-                format!("{:}", self.before_send_time.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.before_send_time {
+            payload.insert("before_send_time".to_string(), format_datetime(v));
         }
-        if self.since_send_time.is_some() {
-            payload.insert(
-                "since_send_time".to_string(),
-                format!("{:}", self.since_send_time.as_ref().unwrap().clone()),
-            );
+        if let Some(v) = &self.since_send_time {
+            payload.insert("since_send_time".to_string(), format_datetime(v));
         }
         payload
     }
@@ -583,8 +802,9 @@ impl BuildIter for ReportsBuilder {
     ///
     /// Crea un recurso a partir del dato pasado por parámetro
     ///
-    fn update_item(&self, data: &Self::Item, _: Rc<MailchimpApi>) -> Self::Item {
-        let in_data = data.clone();
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
         in_data
     }
     ///
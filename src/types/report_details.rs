@@ -0,0 +1,1103 @@
+//! Implement the Campaign Report Sub-Resources Model Types
+//!
+//! Click details, open details, email activity, recipients, unsubscribes,
+//! domain performance, locations, eepurl activity and advice of a campaign report.
+
+use super::link::LinkType;
+use super::list_merge_fields::MergeValue;
+use super::report::ReportType;
+use super::timestamp::{format_datetime, MailchimpDateTime, Timestamp};
+use crate::api::MailchimpApi;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, MalchimpIter, ResourceFilter, SimpleFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// ============ Filter ==============
+
+///
+/// Filter for the open details and the email activity of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReportActivityFilter {
+    /// A comma-separated list of fields to return. Reference
+    /// parameters of sub-objects with dot notation.
+    pub fields: Option<String>,
+    /// A comma-separated list of fields to exclude. Reference
+    /// parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<String>,
+    /// The number of records to return. Default value is 10.
+    pub count: Option<u64>,
+    /// The number of records from a collection to skip. Iterating over
+    /// large collections with this parameter can be slow. Default value is 0..
+    pub offset: Option<u64>,
+    /// Restrict results to activity that occurred after a specific time.
+    /// We recommend ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since: Option<MailchimpDateTime>,
+}
+
+impl Default for ReportActivityFilter {
+    fn default() -> Self {
+        Self {
+            fields: None,
+            exclude_fields: None,
+            count: Some(50),
+            offset: Some(0),
+            since: None,
+        }
+    }
+}
+
+impl ResourceFilter for ReportActivityFilter {
+    fn build_payload(&self) -> HashMap<String, String> {
+        let mut payload = HashMap::new();
+
+        if let Some(v) = &self.fields {
+            payload.insert("fields".to_string(), v.clone());
+        }
+        if let Some(v) = &self.exclude_fields {
+            payload.insert("exclude_fields".to_string(), v.clone());
+        }
+        if let Some(v) = self.count {
+            payload.insert("count".to_string(), format!("{:}", v));
+        }
+        if let Some(v) = self.offset {
+            payload.insert("offset".to_string(), format!("{:}", v));
+        }
+        if let Some(v) = &self.since {
+            payload.insert("since".to_string(), format_datetime(v));
+        }
+        payload
+    }
+}
+
+// ============ Click Details ==============
+
+///
+/// Clicks of each group of an A/B Split campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ClickAbSplitGroupType {
+    /// The total number of clicks for the group.
+    #[serde(default)]
+    pub click_percentage: f64,
+    /// The number of unique clicks for the group.
+    #[serde(default)]
+    pub unique_clicks: u64,
+    /// The percentage of unique clicks for the group.
+    #[serde(default)]
+    pub unique_click_percentage: f64,
+}
+
+///
+/// A/B Split stats of a link
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ClickAbSplitType {
+    /// Stats for Campaign A.
+    #[serde(default)]
+    pub a: ClickAbSplitGroupType,
+    /// Stats for Campaign B.
+    #[serde(default)]
+    pub b: ClickAbSplitGroupType,
+}
+
+///
+/// Click activity of a link of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ClickDetailType {
+    /// The unique id for the link.
+    #[serde(default)]
+    pub id: String,
+    /// The URL for the link in the campaign.
+    #[serde(default)]
+    pub url: String,
+    /// The number of total clicks for a link.
+    #[serde(default)]
+    pub total_clicks: u64,
+    /// The percentage of total clicks for a link out of the number of
+    /// recipients for the campaign.
+    #[serde(default)]
+    pub click_percentage: f64,
+    /// The number of unique clicks for a link.
+    #[serde(default)]
+    pub unique_clicks: u64,
+    /// The percentage of unique clicks for a link out of the number of
+    /// unique clicks for the campaign.
+    #[serde(default)]
+    pub unique_click_percentage: f64,
+    /// The date and time for the last recorded click for the link in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_click: Timestamp,
+    /// A/B Split stats of the link.
+    #[serde(default)]
+    pub ab_split: ClickAbSplitType,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+    /// Endpoint
+    #[serde(skip)]
+    _endpoint: String,
+}
+
+///
+/// Response for endpoint GET /reports/{campaign_id}/click-details
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionClickDetails {
+    /// An array of objects, each representing a specific link that was clicked.
+    #[serde(default)]
+    pub urls_clicked: Vec<ClickDetailType>,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<ClickDetailType> for CollectionClickDetails {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<ClickDetailType> {
+        self.urls_clicked.clone()
+    }
+}
+
+///
+/// Member who clicked a link of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ClickDetailMemberType {
+    /// The MD5 hash of the lowercase version of the list member’s email address.
+    #[serde(default)]
+    pub email_id: String,
+    /// Email address for a subscriber.
+    #[serde(default)]
+    pub email_address: String,
+    /// A dictionary of merge fields where the keys are the merge tags.
+    #[serde(default)]
+    pub merge_fields: HashMap<String, MergeValue>,
+    /// VIP status for subscriber.
+    #[serde(default)]
+    pub vip: bool,
+    /// The total number of times the subscriber clicked on the link.
+    #[serde(default)]
+    pub clicks: u64,
+    /// The status of the member, namely if they are subscribed, unsubscribed,
+    /// deleted, non-subscribed, transactional, pending, or need reconfirmation.
+    #[serde(default)]
+    pub contact_status: String,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The id for the tracked URL in the campaign.
+    #[serde(default)]
+    pub url_id: String,
+    /// The list id.
+    #[serde(default)]
+    pub list_id: String,
+    /// The status of the list used, namely if it’s deleted or disabled.
+    #[serde(default)]
+    pub list_is_active: bool,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+///
+/// Response for endpoint GET /reports/{campaign_id}/click-details/{link_id}/members
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionClickDetailMembers {
+    /// An array of objects, each representing a member who clicked a specific link.
+    #[serde(default)]
+    pub members: Vec<ClickDetailMemberType>,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<ClickDetailMemberType> for CollectionClickDetailMembers {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<ClickDetailMemberType> {
+        self.members.clone()
+    }
+}
+
+impl ClickDetailType {
+    ///
+    /// Get information about list members who clicked on the link
+    ///
+    pub fn get_members(
+        &self,
+        filter: Option<SimpleFilter>,
+    ) -> MalchimpIter<ClickDetailMembersBuilder> {
+        // GET /reports/{campaign_id}/click-details/{link_id}/members
+        let endpoint = self.get_base_endpoint() + "/members";
+        MalchimpIter::from_endpoint(
+            ClickDetailMembersBuilder {},
+            self._api.clone(),
+            &endpoint,
+            filter.unwrap_or_default(),
+        )
+    }
+
+    ///
+    /// Get information about a specific subscriber who clicked the link
+    ///
+    pub fn get_member(&self, subscriber_hash: &str) -> MailchimpResult<ClickDetailMemberType> {
+        // GET /reports/{campaign_id}/click-details/{link_id}/members/{subscriber_hash}
+        let endpoint = format!("{}/members/{}", self.get_base_endpoint(), subscriber_hash);
+        self._api
+            .get::<ClickDetailMemberType>(&endpoint, HashMap::new())
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    /// Set Endpoint
+    pub fn set_endpoint(&mut self, n_endpoint: &str) {
+        self._endpoint = n_endpoint.to_string();
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("{}/{}", self._endpoint, self.id)
+    }
+}
+
+// ============ Open Details ==============
+
+///
+/// Open of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OpenActivityType {
+    /// The date and time recorded for the action in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub timestamp: Timestamp,
+}
+
+///
+/// Member who opened a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OpenDetailMemberType {
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The list id.
+    #[serde(default)]
+    pub list_id: String,
+    /// The status of the list used, namely if it’s deleted or disabled.
+    #[serde(default)]
+    pub list_is_active: bool,
+    /// The status of the member, namely if they are subscribed, unsubscribed,
+    /// deleted, non-subscribed, transactional, pending, or need reconfirmation.
+    #[serde(default)]
+    pub contact_status: String,
+    /// The MD5 hash of the lowercase version of the list member’s email address.
+    #[serde(default)]
+    pub email_id: String,
+    /// Email address for a subscriber.
+    #[serde(default)]
+    pub email_address: String,
+    /// A dictionary of merge fields where the keys are the merge tags.
+    #[serde(default)]
+    pub merge_fields: HashMap<String, MergeValue>,
+    /// VIP status for subscriber.
+    #[serde(default)]
+    pub vip: bool,
+    /// The total number of times the this campaign was opened by the list member.
+    #[serde(default)]
+    pub opens_count: u64,
+    /// An array of objects containing the timestamp of each open.
+    #[serde(default)]
+    pub opens: Vec<OpenActivityType>,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+///
+/// Response for endpoint GET /reports/{campaign_id}/open-details
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionOpenDetails {
+    /// An array of objects, each representing a list member who opened a campaign email.
+    #[serde(default)]
+    pub members: Vec<OpenDetailMemberType>,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The total number of opens, including repeated opens from the same member.
+    #[serde(default)]
+    pub total_opens: u64,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<OpenDetailMemberType> for CollectionOpenDetails {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<OpenDetailMemberType> {
+        self.members.clone()
+    }
+}
+
+// ============ Email Activity ==============
+
+///
+/// Action of a subscriber on a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EmailActionType {
+    /// One of the following actions: ‘open’, ‘click’, or ‘bounce’
+    #[serde(default)]
+    pub action: String,
+    /// If the action is a ‘bounce’, the type of bounce received: ‘hard’, ‘soft’.
+    #[serde(default, rename = "type")]
+    pub bounce_type: String,
+    /// The date and time recorded for the action in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub timestamp: Timestamp,
+    /// If the action is a ‘click’, the URL on which the member clicked.
+    #[serde(default)]
+    pub url: String,
+    /// The IP address recorded for the action.
+    #[serde(default)]
+    pub ip: String,
+}
+
+///
+/// Activity of a subscriber on a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EmailActivityType {
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The list id.
+    #[serde(default)]
+    pub list_id: String,
+    /// The status of the list used, namely if it’s deleted or disabled.
+    #[serde(default)]
+    pub list_is_active: bool,
+    /// The MD5 hash of the lowercase version of the list member’s email address.
+    #[serde(default)]
+    pub email_id: String,
+    /// Email address for a subscriber.
+    #[serde(default)]
+    pub email_address: String,
+    /// An array of objects, each showing an interaction with the email.
+    #[serde(default)]
+    pub activity: Vec<EmailActionType>,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+///
+/// Response for endpoint GET /reports/{campaign_id}/email-activity
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionEmailActivity {
+    /// An array of members that were sent the campaign.
+    #[serde(default)]
+    pub emails: Vec<EmailActivityType>,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<EmailActivityType> for CollectionEmailActivity {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<EmailActivityType> {
+        self.emails.clone()
+    }
+}
+
+// ============ Sent To ==============
+
+///
+/// Recipient of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SentToType {
+    /// The MD5 hash of the lowercase version of the list member’s email address.
+    #[serde(default)]
+    pub email_id: String,
+    /// Email address for a subscriber.
+    #[serde(default)]
+    pub email_address: String,
+    /// A dictionary of merge fields where the keys are the merge tags.
+    #[serde(default)]
+    pub merge_fields: HashMap<String, MergeValue>,
+    /// VIP status for subscriber.
+    #[serde(default)]
+    pub vip: bool,
+    /// The status of the member (‘sent’, ‘hard’ for hard bounce, or ‘soft’ for soft bounce).
+    #[serde(default)]
+    pub status: String,
+    /// The number of times a campaign was opened by this member.
+    #[serde(default)]
+    pub open_count: u64,
+    /// The date and time of the last open for this member in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_open: Timestamp,
+    /// For A/B Split Campaigns, the group the member was apart of (‘a’, ‘b’, or ‘winner’).
+    #[serde(default)]
+    pub absplit_group: String,
+    /// For campaigns sent with timewarp, the time zone group the member is apart of.
+    #[serde(default)]
+    pub gmt_offset: i64,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The list id.
+    #[serde(default)]
+    pub list_id: String,
+    /// The status of the list used, namely if it’s deleted or disabled.
+    #[serde(default)]
+    pub list_is_active: bool,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+///
+/// Response for endpoint GET /reports/{campaign_id}/sent-to
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionSentTo {
+    /// An array of objects, each representing a campaign recipient.
+    #[serde(default)]
+    pub sent_to: Vec<SentToType>,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<SentToType> for CollectionSentTo {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<SentToType> {
+        self.sent_to.clone()
+    }
+}
+
+// ============ Unsubscribed ==============
+
+///
+/// Member who unsubscribed from a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UnsubscribedType {
+    /// The MD5 hash of the lowercase version of the list member’s email address.
+    #[serde(default)]
+    pub email_id: String,
+    /// Email address for a subscriber.
+    #[serde(default)]
+    pub email_address: String,
+    /// A dictionary of merge fields where the keys are the merge tags.
+    #[serde(default)]
+    pub merge_fields: HashMap<String, MergeValue>,
+    /// VIP status for subscriber.
+    #[serde(default)]
+    pub vip: bool,
+    /// The date and time the member opted-out in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub timestamp: Timestamp,
+    /// If available, the reason listed by the member for unsubscribing.
+    #[serde(default)]
+    pub reason: String,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The list id.
+    #[serde(default)]
+    pub list_id: String,
+    /// The status of the list used, namely if it’s deleted or disabled.
+    #[serde(default)]
+    pub list_is_active: bool,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+///
+/// Response for endpoint GET /reports/{campaign_id}/unsubscribed
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionUnsubscribed {
+    /// An array of objects, each representing a member who unsubscribed from a campaign.
+    #[serde(default)]
+    pub unsubscribes: Vec<UnsubscribedType>,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<UnsubscribedType> for CollectionUnsubscribed {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<UnsubscribedType> {
+        self.unsubscribes.clone()
+    }
+}
+
+// ============ Domain Performance ==============
+
+///
+/// Performance of a campaign on an email domain
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomainPerformanceType {
+    /// The name of the domain (gmail.com, hotmail.com, yahoo.com).
+    #[serde(default)]
+    pub domain: String,
+    /// The number of emails sent to that specific domain.
+    #[serde(default)]
+    pub emails_sent: u64,
+    /// The number of bounces at a domain.
+    #[serde(default)]
+    pub bounces: u64,
+    /// The number of opens for a domain.
+    #[serde(default)]
+    pub opens: u64,
+    /// The number of clicks for a domain.
+    #[serde(default)]
+    pub clicks: u64,
+    /// The total number of unsubscribes for a domain.
+    #[serde(default)]
+    pub unsubs: u64,
+    /// The number of successful deliveries for a domain.
+    #[serde(default)]
+    pub delivered: u64,
+    /// The percentage of total emails that went to this domain.
+    #[serde(default)]
+    pub emails_pct: f64,
+    /// The percentage of total bounces from this domain.
+    #[serde(default)]
+    pub bounces_pct: f64,
+    /// The percentage of total opens from this domain.
+    #[serde(default)]
+    pub opens_pct: f64,
+    /// The percentage of total clicks from this domain.
+    #[serde(default)]
+    pub clicks_pct: f64,
+    /// The percentage of total unsubscribes from this domain.
+    #[serde(default)]
+    pub unsubs_pct: f64,
+}
+
+///
+/// Response for endpoint GET /reports/{campaign_id}/domain-performance
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DomainPerformanceReportType {
+    /// The top 5 email domains based on total delivered emails.
+    #[serde(default)]
+    pub domains: Vec<DomainPerformanceType>,
+    /// The total number of emails sent for the campaign.
+    #[serde(default)]
+    pub total_sent: u64,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+// ============ Locations ==============
+
+///
+/// Opens of a campaign from a location
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OpenLocationType {
+    /// The ISO 3166 2 digit country code.
+    #[serde(default)]
+    pub country_code: String,
+    /// A region within a country.
+    #[serde(default)]
+    pub region: String,
+    /// The name of the region, if we have one. For blank “region” values, this is “Rest of Country”.
+    #[serde(default)]
+    pub region_name: String,
+    /// The number of unique campaign opens for a region.
+    #[serde(default)]
+    pub opens: u64,
+}
+
+///
+/// Response for endpoint GET /reports/{campaign_id}/locations
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionOpenLocations {
+    /// An array of objects, each representing a location of the opens.
+    #[serde(default)]
+    pub locations: Vec<OpenLocationType>,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<OpenLocationType> for CollectionOpenLocations {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<OpenLocationType> {
+        self.locations.clone()
+    }
+}
+
+// ============ EepURL ==============
+
+///
+/// Twitter status that mentions the eepurl of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EepurlTwitterStatusType {
+    /// The body of the tweet.
+    #[serde(default)]
+    pub status: String,
+    /// The Twitter handle for the author of the tweet.
+    #[serde(default)]
+    pub screen_name: String,
+    /// The tweet id.
+    #[serde(default)]
+    pub status_id: String,
+    /// The date and time of the tweet in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub datetime: Timestamp,
+    /// Whether the tweet is a retweet.
+    #[serde(default)]
+    pub is_retweet: bool,
+}
+
+///
+/// Twitter activity of the eepurl of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EepurlTwitterType {
+    /// The number of tweets including a campaign’s eepurl.
+    #[serde(default)]
+    pub tweets: u64,
+    /// The day and time of the first recorded tweet with a campaign’s eepurl.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub first_tweet: Timestamp,
+    /// The day and time of the last recorded tweet with a campaign’s eepurl.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_tweet: Timestamp,
+    /// The number of retweets that include a campaign’s eepurl.
+    #[serde(default)]
+    pub retweets: u64,
+    /// A summary of twitter activity for a campaign.
+    #[serde(default)]
+    pub statuses: Vec<EepurlTwitterStatusType>,
+}
+
+///
+/// Location of the clicks of the eepurl of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EepurlClickLocationType {
+    /// The country where the clicks were recorded.
+    #[serde(default)]
+    pub country: String,
+    /// The region where the clicks were recorded.
+    #[serde(default)]
+    pub region: String,
+}
+
+///
+/// Clicks of the eepurl of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EepurlClicksType {
+    /// The total number of clicks to a campaign’s eepurl.
+    #[serde(default)]
+    pub clicks: u64,
+    /// The date and time of the first click to a campaign’s eepurl in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub first_click: Timestamp,
+    /// The date and time of the last click to a campaign’s eepurl in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_click: Timestamp,
+    /// A summary of the click locations for the campaign.
+    #[serde(default)]
+    pub locations: Vec<EepurlClickLocationType>,
+}
+
+///
+/// Referrer of the clicks of the eepurl of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EepurlReferrerType {
+    /// A referrer (truncated to 100 bytes).
+    #[serde(default)]
+    pub referrer: String,
+    /// The number of clicks a single referrer generated.
+    #[serde(default)]
+    pub clicks: u64,
+    /// The timestamp for the first click from this referrer.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub first_click: Timestamp,
+    /// The timestamp for the last click from this referrer.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub last_click: Timestamp,
+}
+
+///
+/// Response for endpoint GET /reports/{campaign_id}/eepurl
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EepurlReportType {
+    /// A summary of Twitter activity for a campaign.
+    #[serde(default)]
+    pub twitter: EepurlTwitterType,
+    /// A summary of the click activity for the campaign.
+    #[serde(default)]
+    pub clicks: EepurlClicksType,
+    /// A summary of the referrers for the campaign.
+    #[serde(default)]
+    pub referrers: Vec<EepurlReferrerType>,
+    /// The shortened eepurl of the campaign.
+    #[serde(default)]
+    pub eepurl: String,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+// ============ Advice ==============
+
+///
+/// Feedback based on the performance of a campaign
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CampaignAdviceType {
+    /// The ‘type’ of message: negative, positive, neutral.
+    #[serde(default, rename = "type")]
+    pub advice_type: String,
+    /// The advice message.
+    #[serde(default)]
+    pub message: String,
+}
+
+///
+/// Response for endpoint GET /reports/{campaign_id}/advice
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionCampaignAdvice {
+    /// An array of objects, each representing a specific piece of advice.
+    #[serde(default)]
+    pub advice: Vec<CampaignAdviceType>,
+    /// The campaign id.
+    #[serde(default)]
+    pub campaign_id: String,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+// ============ Sub-Reports ==============
+
+///
+/// Response for endpoint GET /reports/{campaign_id}/sub-reports
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionSubReports {
+    /// An array of objects, each representing a report of a child campaign.
+    #[serde(default)]
+    pub reports: Vec<ReportType>,
+    /// The id of the parent campaign.
+    #[serde(default)]
+    pub parent_campaign_id: String,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+// ============ Builders ==============
+
+///
+/// ClickDetailsBuilder
+///
+#[derive(Debug)]
+pub struct ClickDetailsBuilder {
+    /// Resource Endpoint
+    pub endpoint: String,
+}
+
+impl BuildIter for ClickDetailsBuilder {
+    type Item = ClickDetailType;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionClickDetails;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data.set_endpoint(&self.endpoint);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// ClickDetailMembersBuilder
+///
+#[derive(Debug)]
+pub struct ClickDetailMembersBuilder {}
+
+impl BuildIter for ClickDetailMembersBuilder {
+    type Item = ClickDetailMemberType;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionClickDetailMembers;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
+        data.clone()
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// OpenDetailsBuilder
+///
+#[derive(Debug)]
+pub struct OpenDetailsBuilder {}
+
+impl BuildIter for OpenDetailsBuilder {
+    type Item = OpenDetailMemberType;
+    type FilterItem = ReportActivityFilter;
+    type Collection = CollectionOpenDetails;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
+        data.clone()
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// EmailActivityBuilder
+///
+#[derive(Debug)]
+pub struct EmailActivityBuilder {}
+
+impl BuildIter for EmailActivityBuilder {
+    type Item = EmailActivityType;
+    type FilterItem = ReportActivityFilter;
+    type Collection = CollectionEmailActivity;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
+        data.clone()
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// SentToBuilder
+///
+#[derive(Debug)]
+pub struct SentToBuilder {}
+
+impl BuildIter for SentToBuilder {
+    type Item = SentToType;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionSentTo;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
+        data.clone()
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// UnsubscribedBuilder
+///
+#[derive(Debug)]
+pub struct UnsubscribedBuilder {}
+
+impl BuildIter for UnsubscribedBuilder {
+    type Item = UnsubscribedType;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionUnsubscribed;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
+        data.clone()
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+///
+/// OpenLocationsBuilder
+///
+#[derive(Debug)]
+pub struct OpenLocationsBuilder {}
+
+impl BuildIter for OpenLocationsBuilder {
+    type Item = OpenLocationType;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionOpenLocations;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, _: Arc<MailchimpApi>) -> Self::Item {
+        data.clone()
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
@@ -0,0 +1,64 @@
+//! Implement Search Members and Search Campaigns Model Types
+
+use super::campaign::CampaignType;
+use super::link::LinkType;
+use super::list_members::ListMember;
+use serde::{Deserialize, Serialize};
+
+///
+/// Members matching a search
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MemberMatchesType {
+    /// An array of objects, each representing a specific list member.
+    #[serde(default)]
+    pub members: Vec<ListMember>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+}
+
+///
+/// Response for endpoint GET /search-members
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SearchMembersType {
+    /// Exact matches of the provided search query.
+    #[serde(default)]
+    pub exact_matches: MemberMatchesType,
+    /// Partial matches of the provided search query.
+    #[serde(default)]
+    pub full_search: MemberMatchesType,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+///
+/// Campaign matching a search
+///
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CampaignSearchResultType {
+    /// The campaign that matches the search.
+    #[serde(default)]
+    pub campaign: CampaignType,
+    /// The snippet of the campaign content that matches the search.
+    #[serde(default)]
+    pub snippet: String,
+}
+
+///
+/// Response for endpoint GET /search-campaigns
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SearchCampaignsType {
+    /// An array of campaigns matching the search query.
+    #[serde(default)]
+    pub results: Vec<CampaignSearchResultType>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
@@ -0,0 +1,179 @@
+//! Implement Templates Model Types
+
+use super::empty::EmptyType;
+use super::link::LinkType;
+use super::timestamp::Timestamp;
+use crate::api::MailchimpApi;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::MailchimpResult;
+use crate::iter::MailchimpCollection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Template
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TemplateType {
+    /// The individual id for the template.
+    #[serde(default)]
+    pub id: u64,
+    /// The type of template (user, base, or gallery).
+    #[serde(default, rename = "type")]
+    pub template_type: String,
+    /// The name of the template.
+    #[serde(default)]
+    pub name: String,
+    /// Whether the template uses the drag and drop editor.
+    #[serde(default)]
+    pub drag_and_drop: bool,
+    /// Whether the template contains media queries to make it responsive.
+    #[serde(default)]
+    pub responsive: bool,
+    /// If available, the category the template is listed in.
+    #[serde(default)]
+    pub category: String,
+    /// The date and time the template was created in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub date_created: Timestamp,
+    /// The date and time the template was edited in ISO 8601 format.
+    #[serde(default, with = "super::timestamp::serde_timestamp")]
+    pub date_edited: Timestamp,
+    /// The login name for template’s creator.
+    #[serde(default)]
+    pub created_by: String,
+    /// The login name who last edited the template.
+    #[serde(default)]
+    pub edited_by: String,
+    /// User templates are not ‘deleted,’ but rather marked as ‘inactive.’
+    #[serde(default)]
+    pub active: bool,
+    /// The id of the folder the template is currently in.
+    #[serde(default)]
+    pub folder_id: String,
+    /// If available, the URL for a thumbnail of the template.
+    #[serde(default)]
+    pub thumbnail: String,
+    /// The URL used for template sharing.
+    #[serde(default)]
+    pub share_url: String,
+    /// How the template’s content is put together.
+    /// Possible Values: template multichannel html
+    #[serde(default)]
+    pub content_type: String,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+}
+
+///
+/// Create/Update Template
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TemplateParam {
+    /// The name of the template. Required to create a template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The id of the folder the template is currently in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<String>,
+    /// The raw HTML for the template. We support the Mailchimp Template
+    /// Language in any HTML code passed via the API. Required to create a template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+}
+
+///
+/// Default content of a template, e.g. GET /templates/{template_id}/default-content
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TemplateDefaultContentType {
+    /// The sections of the template, each key is the unique mc:edit area name
+    /// and the value is the default content of the section.
+    #[serde(default)]
+    pub sections: HashMap<String, String>,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+///
+/// Response for endpoint GET /templates
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionTemplates {
+    /// An array of objects, each representing a template.
+    #[serde(default)]
+    pub templates: Vec<TemplateType>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<TemplateType> for CollectionTemplates {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<TemplateType> {
+        self.templates.clone()
+    }
+}
+
+impl TemplateType {
+    ///
+    /// Update the name, HTML, or folder_id of an existing template
+    ///
+    pub fn update(&self, param: TemplateParam) -> MailchimpResult<TemplateType> {
+        // PATCH /templates/{template_id}
+        let mut data = self
+            ._api
+            .patch::<TemplateType, TemplateParam>(&self.get_base_endpoint(), param)?;
+        data.set_api(self._api.clone());
+        Ok(data)
+    }
+
+    ///
+    /// Delete a specific template
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /templates/{template_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Get the sections that you can edit in a template, including each
+    /// section’s default content
+    ///
+    pub fn get_default_content(&self) -> MailchimpResult<TemplateDefaultContentType> {
+        // GET /templates/{template_id}/default-content
+        let endpoint = self.get_base_endpoint() + "/default-content";
+        self._api
+            .get::<TemplateDefaultContentType>(&endpoint, HashMap::new())
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("templates/{}", self.id)
+    }
+}
@@ -0,0 +1,139 @@
+//! Implement Template Folders Model Types
+
+use super::empty::EmptyType;
+use super::link::LinkType;
+use crate::api::MailchimpApi;
+use crate::internal::error_type::MailchimpError;
+use crate::internal::request::MailchimpResult;
+use crate::iter::{BuildIter, MailchimpCollection, SimpleFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// Folder used to organize templates
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TemplateFolderType {
+    /// The name of the folder.
+    #[serde(default)]
+    pub name: String,
+    /// A string that uniquely identifies this template folder.
+    #[serde(default)]
+    pub id: String,
+    /// The number of templates in the folder.
+    #[serde(default)]
+    pub count: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+
+    /// Mailchimp API
+    #[serde(skip)]
+    _api: Arc<MailchimpApi>,
+}
+
+///
+/// Create/Update Template Folder
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TemplateFolderParam {
+    /// The name of the folder.
+    #[serde(default)]
+    pub name: String,
+}
+
+///
+/// Response for endpoint GET /template-folders
+///
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CollectionTemplateFolders {
+    /// An array of objects representing template folders.
+    #[serde(default)]
+    pub folders: Vec<TemplateFolderType>,
+    /// The total number of items matching the query regardless of pagination.
+    #[serde(default)]
+    pub total_items: u64,
+    /// A list of link types and descriptions for the API schema documents.
+    #[serde(default)]
+    pub _links: Vec<LinkType>,
+}
+
+impl MailchimpCollection<TemplateFolderType> for CollectionTemplateFolders {
+    /// Total Items
+    fn get_total_items(&self) -> u64 {
+        self.total_items
+    }
+    /// Data
+    fn get_values(&self) -> Vec<TemplateFolderType> {
+        self.folders.clone()
+    }
+}
+
+///
+/// TemplateFoldersBuilder
+///
+#[derive(Debug)]
+pub struct TemplateFoldersBuilder {}
+
+impl BuildIter for TemplateFoldersBuilder {
+    type Item = TemplateFolderType;
+    type FilterItem = SimpleFilter;
+    type Collection = CollectionTemplateFolders;
+
+    ///
+    /// Crea un recurso a partir del dato pasado por parámetro
+    ///
+    fn update_item(&self, data: &Self::Item, api: Arc<MailchimpApi>) -> Self::Item {
+        let mut in_data = data.clone();
+        in_data.set_api(api);
+        in_data
+    }
+    ///
+    /// Actualiza el offset
+    ///
+    fn update_filter_offset(&self, filter: &Self::FilterItem) -> Self::FilterItem {
+        let mut f = filter.clone();
+        f.offset = Some(f.count.unwrap() + f.offset.unwrap());
+        f
+    }
+}
+
+impl TemplateFolderType {
+    ///
+    /// Update a specific folder used to organize templates
+    ///
+    pub fn update(&self, param: TemplateFolderParam) -> MailchimpResult<TemplateFolderType> {
+        // PATCH /template-folders/{folder_id}
+        let mut data = self
+            ._api
+            .patch::<TemplateFolderType, TemplateFolderParam>(&self.get_base_endpoint(), param)?;
+        data.set_api(self._api.clone());
+        Ok(data)
+    }
+
+    ///
+    /// Delete a specific template folder, and mark all the templates in the
+    /// folder as ‘unfiled’
+    ///
+    pub fn delete(&self) -> Option<MailchimpError> {
+        // DELETE /template-folders/{folder_id}
+        self._api
+            .delete::<EmptyType>(&self.get_base_endpoint(), HashMap::new())
+            .err()
+    }
+
+    ///
+    /// Set API
+    ///
+    pub fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
+        self._api = n_api;
+    }
+
+    ///
+    /// Return de base endpoint for the resource
+    ///
+    fn get_base_endpoint(&self) -> String {
+        format!("template-folders/{}", self.id)
+    }
+}
@@ -0,0 +1,170 @@
+//! Implement the Date and Time Values used by the Model Types
+//!
+//! Without the `datetime` feature the dates are the ISO 8601 strings returned
+//! by Mailchimp. With the feature they are parsed into `chrono::DateTime<Utc>`
+//! and the empty strings used by Mailchimp for "never" become `None`.
+
+#[cfg(feature = "datetime")]
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+
+///
+/// Date and time of the Mailchimp API
+///
+#[cfg(feature = "datetime")]
+pub type MailchimpDateTime = DateTime<Utc>;
+
+///
+/// Date and time of the Mailchimp API
+///
+#[cfg(not(feature = "datetime"))]
+pub type MailchimpDateTime = String;
+
+///
+/// Date and time of a resource, `None` when Mailchimp returns an empty value
+///
+#[cfg(feature = "datetime")]
+pub type Timestamp = Option<MailchimpDateTime>;
+
+///
+/// Date and time of a resource, empty when Mailchimp does not have a value
+///
+#[cfg(not(feature = "datetime"))]
+pub type Timestamp = String;
+
+///
+/// Format a date and time as the ISO 8601 value expected by the filters
+///
+#[cfg(feature = "datetime")]
+pub fn format_datetime(value: &MailchimpDateTime) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+///
+/// Format a date and time as the ISO 8601 value expected by the filters
+///
+#[cfg(not(feature = "datetime"))]
+pub fn format_datetime(value: &MailchimpDateTime) -> String {
+    value.clone()
+}
+
+///
+/// Parse the dates returned by Mailchimp, `Ok(None)` for an empty value
+///
+/// Accepted formats:
+///     2015-10-21T15:41:36+00:00
+///     2015-10-21 15:41:36 (UTC)
+///     2015-10-21 (UTC midnight)
+///
+#[cfg(feature = "datetime")]
+pub fn parse_datetime(value: &str) -> Result<Option<MailchimpDateTime>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Ok(Some(dt.with_timezone(&Utc)));
+    }
+    for format in &["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(value, format) {
+            return Ok(Some(Utc.from_utc_datetime(&dt)));
+        }
+    }
+    if let Ok(d) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        if let Some(dt) = d.and_hms_opt(0, 0, 0) {
+            return Ok(Some(Utc.from_utc_datetime(&dt)));
+        }
+    }
+    Err(format!("invalid date `{}`", value))
+}
+
+///
+/// Serializa y deserializa los campos `Timestamp`
+///
+#[cfg(feature = "datetime")]
+pub(crate) mod serde_timestamp {
+    use super::{format_datetime, parse_datetime, Timestamp};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(dt) => serializer.serialize_str(&format_datetime(dt)),
+            None => serializer.serialize_str(""),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(v) => parse_datetime(&v).map_err(D::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
+
+///
+/// Serializa y deserializa los campos `Timestamp`
+///
+#[cfg(not(feature = "datetime"))]
+pub(crate) mod serde_timestamp {
+    use super::Timestamp;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        String::deserialize(deserializer)
+    }
+}
+
+///
+/// Serializa y deserializa los campos `Option<MailchimpDateTime>`
+///
+#[cfg(feature = "datetime")]
+pub(crate) mod serde_opt_timestamp {
+    use super::{format_datetime, parse_datetime, MailchimpDateTime};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<MailchimpDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(dt) => serializer.serialize_str(&format_datetime(dt)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<MailchimpDateTime>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(v) => parse_datetime(&v).map_err(D::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
+
+///
+/// Serializa y deserializa los campos `Option<MailchimpDateTime>`
+///
+#[cfg(not(feature = "datetime"))]
+pub(crate) mod serde_opt_timestamp {
+    use super::MailchimpDateTime;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<MailchimpDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<MailchimpDateTime>, D::Error> {
+        Option::<String>::deserialize(deserializer)
+    }
+}
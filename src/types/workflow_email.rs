@@ -4,17 +4,17 @@ use super::automation_campaign::{
 };
 use super::automation_email_queue::{
     AutomationEmailQueueBuilder, AutomationEmailQueueFilter, AutomationEmailQueueType,
-    CollectionAutomationEmailQueue,
 };
 use super::empty::EmptyType;
 use super::link::LinkType;
+use super::timestamp::MailchimpDateTime;
 use crate::api::{MailchimpApi, MailchimpApiUpdate};
-use crate::internal::error_type::MailchimpErrorType;
+use crate::internal::error_type::MailchimpError;
 use crate::internal::request::MailchimpResult;
-use crate::iter::{MalchimpIter, ResourceFilter};
+use crate::iter::MalchimpIter;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /// Workflow Email
 ///
@@ -39,11 +39,19 @@ pub struct WorkflowEmailType {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delay: Option<AutomationDelayType>,
     /// The date and time the campaign was created in ISO 8601 format.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub create_time: Option<MailchimpDateTime>,
     /// The date and time the campaign was started in ISO 8601 format.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_time: Option<MailchimpDateTime>,
     /// The link to the campaign’s archive version in ISO 8601 format.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archive_url: Option<String>,
@@ -51,8 +59,12 @@ pub struct WorkflowEmailType {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub emails_sent: Option<u64>,
     /// The date and time a campaign was sent in ISO 8601 format
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub send_time: Option<String>,
+    #[serde(
+        default,
+        with = "super::timestamp::serde_opt_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub send_time: Option<MailchimpDateTime>,
     /// How the campaign’s content is put together (‘template’, ‘drag_and_drop’, ‘html’, ‘url’).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
@@ -86,7 +98,7 @@ pub struct WorkflowEmailType {
 
     /// Mailchimp APi
     #[serde(skip)]
-    _api: Rc<MailchimpApi>,
+    _api: Arc<MailchimpApi>,
     // Endpoint del Recurso
     #[serde(skip)]
     _endpoint: String,
@@ -96,7 +108,7 @@ impl MailchimpApiUpdate for WorkflowEmailType {
     /**
      * Update API
      */
-    fn set_api(&mut self, n_api: Rc<MailchimpApi>) {
+    fn set_api(&mut self, n_api: Arc<MailchimpApi>) {
         self._api = n_api
     }
 }
@@ -109,7 +121,7 @@ impl WorkflowEmailType {
     /// En caso de ser satisfactoria la ejecución, devuelve None,
     /// en caso contrario devuelve el error, con su respectiva descripción
     ///
-    pub fn pause_all_emails(&self) -> Option<MailchimpErrorType> {
+    pub fn pause_all_emails(&self) -> Option<MailchimpError> {
         let mut b_endpoint = self._endpoint.clone();
         b_endpoint.push_str("/actions/pause");
         match self
@@ -127,7 +139,7 @@ impl WorkflowEmailType {
     /// En caso de ser satisfactoria la ejecución, devuelve None,
     /// en caso contrario devuelve el error, con su respectiva descripción
     ///
-    pub fn start_all_emails(&self) -> Option<MailchimpErrorType> {
+    pub fn start_all_emails(&self) -> Option<MailchimpError> {
         let mut b_endpoint = self._endpoint.clone();
         b_endpoint.push_str("/actions/start");
         match self
@@ -147,7 +159,7 @@ impl WorkflowEmailType {
     /// Argumentos:
     ///     workflow_email_id: The unique id for the Automation workflow email.
     ///
-    pub fn delete<'a>(&self) -> Option<MailchimpErrorType> {
+    pub fn delete(&self) -> Option<MailchimpError> {
         let endpoint = self.get_endpoint();
         let response = self
             ._api
@@ -170,30 +182,12 @@ impl WorkflowEmailType {
         let mut queue_endpoint = self._endpoint.clone();
         queue_endpoint.push_str("/queue");
         let filters = AutomationEmailQueueFilter::default();
-        let payload = filters.build_payload();
-        let response = self
-            ._api
-            .get::<CollectionAutomationEmailQueue>(&queue_endpoint, payload);
-        match response {
-            Ok(collection) => MalchimpIter {
-                builder: AutomationEmailQueueBuilder {},
-                data: collection.queue,
-                cur_filters: filters.clone(),
-                cur_it: 0,
-                total_items: collection.total_items,
-                api: self._api.clone(),
-                endpoint: queue_endpoint.clone(),
-            },
-            Err(_) => MalchimpIter {
-                builder: AutomationEmailQueueBuilder {},
-                data: Vec::new(),
-                cur_filters: filters.clone(),
-                cur_it: 0,
-                total_items: 0,
-                api: self._api.clone(),
-                endpoint: queue_endpoint.clone(),
-            },
-        }
+        MalchimpIter::from_endpoint(
+            AutomationEmailQueueBuilder {},
+            self._api.clone(),
+            &queue_endpoint,
+            filters,
+        )
     }
 
     ///